@@ -0,0 +1,27 @@
+use std::fs::File;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use mmolb_parsing::{process_game, Game};
+
+fn parse_games(c: &mut Criterion) {
+    let games: Vec<(String, Game)> = std::fs::read_dir("test_data/raw/game")
+        .unwrap()
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let game_id = path.file_stem().unwrap().to_str().unwrap().to_string();
+            let game = serde_json::from_reader(File::open(&path).unwrap()).unwrap();
+            (game_id, game)
+        })
+        .collect();
+
+    c.bench_function("process_game", |b| {
+        b.iter(|| {
+            for (game_id, game) in &games {
+                std::hint::black_box(process_game(game, game_id));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, parse_games);
+criterion_main!(benches);
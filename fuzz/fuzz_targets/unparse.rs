@@ -0,0 +1,52 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mmolb_parsing::{
+    enums::Day,
+    nom_parsing::{parse_event_str, ParsingContext},
+    parsed_event::{EmojiTeam, MapString},
+    ParsedEventMessage, UnparsingContext,
+};
+
+fuzz_target!(
+    |input: (ParsedEventMessage<String>, u32, u16, Option<u16>)| {
+        let (message, season, day, event_index) = input;
+        let away_emoji_team = EmojiTeam {
+            emoji: "🛸",
+            name: "Roswell Weather Balloons",
+        };
+        let home_emoji_team = EmojiTeam {
+            emoji: "🧹",
+            name: "Boston Street Sweepers",
+        };
+        let day = Some(Day::Day(day));
+        let context = UnparsingContext {
+            season,
+            day,
+            away_emoji_team,
+            home_emoji_team,
+        };
+
+        let unparsed = message.unparse(context, event_index);
+
+        // Only re-parse messages whose strings could be single words in a real message. Anything
+        // else (empty emojis, names with spaces or punctuation) can't be split back out reliably.
+        let mut plausible = true;
+        let message = message.map_string(|s: String| {
+            plausible &= !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric());
+            s
+        });
+        let Some(event_type) = message.event_type().filter(|_| plausible) else {
+            return;
+        };
+
+        // Even then, names can be ambiguous with what follows them (see the README), so only the
+        // re-parsed message's text is compared, not the message itself.
+        let parsing_context =
+            ParsingContext::for_test(home_emoji_team, away_emoji_team, season, day);
+        let reparsed = parse_event_str(event_type, &unparsed, &parsing_context);
+        if !matches!(reparsed, ParsedEventMessage::ParseError { .. }) {
+            assert_eq!(reparsed.unparse(context, event_index), unparsed);
+        }
+    }
+);
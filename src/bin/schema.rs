@@ -0,0 +1,16 @@
+use std::{fs::File, path::PathBuf};
+
+/// Writes the JSON schemas of the parser's output to the given directory (by default, the current directory).
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_default();
+
+    for (name, schema) in mmolb_parsing::parsing::json_schemas() {
+        let path = dir.join(format!("{name}.schema.json"));
+        serde_json::to_writer_pretty(File::create(&path)?, &schema)?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,464 @@
+//! Serde support for binary formats like postcard and bincode.
+//!
+//! [`ParsedEventMessage`] is internally tagged (and [`BatterStat`] adjacently tagged) so that its JSON is easy to read,
+//! but formats that aren't self-describing can't deserialize those representations. This module serializes them
+//! externally tagged instead, without changing their JSON.
+//!
+//! Use [`BinaryEvent`] to wrap messages, or `#[serde(with = "mmolb_parsing::binary_serde")]` on a field.
+//!
+//! ```
+//! use mmolb_parsing::{binary_serde::BinaryEvent, ParsedEventMessage};
+//!
+//! let events = vec![BinaryEvent(ParsedEventMessage::<String>::PlayBall)];
+//! let bytes = postcard::to_allocvec(&events).unwrap();
+//! let read_back: Vec<BinaryEvent<String>> = postcard::from_bytes(&bytes).unwrap();
+//! assert_eq!(read_back, events);
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    enums::{
+        Attribute, BatterStat, Distance, FairBallDestination, FairBallType, FieldingErrorType,
+        FoulType, GameOverMessage, HomeAway, MoundVisitType, NowBattingStats, Place, StrikeType,
+        TopBottom,
+    },
+    parsed_event::{
+        BaseSteal, Cheer, ContainResult, Delivery, DoorPrize, Efflorescence, Ejection, EmojiTeam,
+        FallingStarOutcome, FieldingAttempt, GameEventParseError, KnownBug, PartyDurabilityLoss,
+        PlacedPlayer, RunnerAdvance, RunnerOut, SnappedPhotos, StartOfInningPitcher,
+        WeatherConsumptionEvents, WitherResult, WitherStruggle,
+    },
+    ParsedEventMessage,
+};
+
+/// A [`ParsedEventMessage`] that (de)serializes in the binary-friendly representation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryEvent<S>(pub ParsedEventMessage<S>);
+
+impl<S: Serialize> Serialize for BinaryEvent<S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, S: Deserialize<'de>> Deserialize<'de> for BinaryEvent<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(BinaryEvent)
+    }
+}
+
+pub fn serialize<S: Serialize, Ser: Serializer>(
+    event: &ParsedEventMessage<S>,
+    serializer: Ser,
+) -> Result<Ser::Ok, Ser::Error> {
+    ParsedEventMessageDef::serialize(event, serializer)
+}
+
+pub fn deserialize<'de, S: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ParsedEventMessage<S>, D::Error> {
+    ParsedEventMessageDef::deserialize(deserializer)
+}
+
+// Mirrors of the types whose serde representation needs to change. serde checks that these match the real types,
+// so adding a variant or field there without adding it here is a compile error.
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "ParsedEventMessage")]
+enum ParsedEventMessageDef<S> {
+    ParseError {
+        error: GameEventParseError,
+        message: S,
+    },
+    KnownBug {
+        bug: KnownBug<S>,
+    },
+    // Season 0
+    LiveNow {
+        away_team: EmojiTeam<S>,
+        home_team: EmojiTeam<S>,
+        stadium: Option<S>,
+    },
+    PitchingMatchup {
+        away_team: EmojiTeam<S>,
+        home_team: EmojiTeam<S>,
+        home_pitcher: S,
+        away_pitcher: S,
+    },
+    Lineup {
+        side: HomeAway,
+        players: Vec<PlacedPlayer<S>>,
+    },
+    PlayBall,
+    GameOver {
+        message: GameOverMessage,
+    },
+    Recordkeeping {
+        winning_team: EmojiTeam<S>,
+        losing_team: EmojiTeam<S>,
+        winning_score: u8,
+        losing_score: u8,
+    },
+    InningStart {
+        number: u8,
+        side: TopBottom,
+        batting_team: EmojiTeam<S>,
+        automatic_runner: Option<S>,
+        pitcher_status: Option<StartOfInningPitcher<S>>,
+    },
+    NowBatting {
+        batter: S,
+        #[serde(with = "NowBattingStatsDef")]
+        stats: NowBattingStats,
+    },
+    InningEnd {
+        number: u8,
+        side: TopBottom,
+    },
+
+    // Mound visits
+    MoundVisit {
+        team: EmojiTeam<S>,
+        mound_visit_type: MoundVisitType,
+    },
+    PitcherRemains {
+        remaining_pitcher: PlacedPlayer<S>,
+    },
+    PitcherSwap {
+        leaving_pitcher_emoji: Option<S>,
+        leaving_pitcher: PlacedPlayer<S>,
+        arriving_pitcher_emoji: Option<S>,
+        arriving_pitcher_place: Option<Place>,
+        arriving_pitcher_name: S,
+    },
+
+    // Pitch
+    Ball {
+        steals: Vec<BaseSteal<S>>,
+        count: (u8, u8),
+        cheer: Option<Cheer>,
+        aurora_photos: Option<SnappedPhotos<S>>,
+        ejection: Option<Ejection<S>>,
+        door_prizes: Vec<DoorPrize<S>>,
+        wither: Option<WitherStruggle<S>>,
+        efflorescence: Vec<Efflorescence<S>>,
+    },
+    Strike {
+        strike: StrikeType,
+        steals: Vec<BaseSteal<S>>,
+        count: (u8, u8),
+        cheer: Option<Cheer>,
+        aurora_photos: Option<SnappedPhotos<S>>,
+        ejection: Option<Ejection<S>>,
+        door_prizes: Vec<DoorPrize<S>>,
+        wither: Option<WitherStruggle<S>>,
+        efflorescence: Vec<Efflorescence<S>>,
+    },
+    Foul {
+        foul: FoulType,
+        steals: Vec<BaseSteal<S>>,
+        count: (u8, u8),
+        cheer: Option<Cheer>,
+        aurora_photos: Option<SnappedPhotos<S>>,
+        door_prizes: Vec<DoorPrize<S>>,
+        wither: Option<WitherStruggle<S>>,
+        efflorescence: Vec<Efflorescence<S>>,
+    },
+    Walk {
+        batter: S,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        cheer: Option<Cheer>,
+        aurora_photos: Option<SnappedPhotos<S>>,
+        ejection: Option<Ejection<S>>,
+        wither: Option<WitherStruggle<S>>,
+    },
+    HitByPitch {
+        batter: S,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        cheer: Option<Cheer>,
+        aurora_photos: Option<SnappedPhotos<S>>,
+        ejection: Option<Ejection<S>>,
+        door_prizes: Vec<DoorPrize<S>>,
+        wither: Option<WitherStruggle<S>>,
+        efflorescence: Vec<Efflorescence<S>>,
+    },
+    FairBall {
+        batter: S,
+        fair_ball_type: FairBallType,
+        destination: FairBallDestination,
+        cheer: Option<Cheer>,
+        aurora_photos: Option<SnappedPhotos<S>>,
+        door_prizes: Vec<DoorPrize<S>>,
+        efflorescence: Vec<Efflorescence<S>>,
+    },
+    StrikeOut {
+        foul: Option<FoulType>,
+        batter: S,
+        strike: StrikeType,
+        steals: Vec<BaseSteal<S>>,
+        cheer: Option<Cheer>,
+        aurora_photos: Option<SnappedPhotos<S>>,
+        ejection: Option<Ejection<S>>,
+        wither: Option<WitherStruggle<S>>,
+    },
+
+    // Field
+    BatterToBase {
+        batter: S,
+        distance: Distance,
+        fair_ball_type: FairBallType,
+        fielder: PlacedPlayer<S>,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        ejection: Option<Ejection<S>>,
+    },
+    HomeRun {
+        batter: S,
+        fair_ball_type: FairBallType,
+        destination: FairBallDestination,
+        scores: Vec<S>,
+        grand_slam: bool,
+        ejection: Option<Ejection<S>>,
+    },
+    CaughtOut {
+        batter: S,
+        fair_ball_type: FairBallType,
+        caught_by: PlacedPlayer<S>,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        sacrifice: bool,
+        perfect: bool,
+        ejection: Option<Ejection<S>>,
+    },
+    GroundedOut {
+        batter: S,
+        fielders: Vec<PlacedPlayer<S>>,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        amazing: bool,
+        ejection: Option<Ejection<S>>,
+    },
+    ForceOut {
+        batter: S,
+        fielders: Vec<PlacedPlayer<S>>,
+        fair_ball_type: FairBallType,
+        out: RunnerOut<S>,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        ejection: Option<Ejection<S>>,
+    },
+    ReachOnFieldersChoice {
+        batter: S,
+        fielders: Vec<PlacedPlayer<S>>,
+        result: FieldingAttempt<S>,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        ejection: Option<Ejection<S>>,
+    },
+    DoublePlayGrounded {
+        batter: S,
+        fielders: Vec<PlacedPlayer<S>>,
+        out_one: RunnerOut<S>,
+        out_two: RunnerOut<S>,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        sacrifice: bool,
+        ejection: Option<Ejection<S>>,
+    },
+    DoublePlayCaught {
+        batter: S,
+        fair_ball_type: FairBallType,
+        fielders: Vec<PlacedPlayer<S>>,
+        out_two: RunnerOut<S>,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        ejection: Option<Ejection<S>>,
+    },
+    ReachOnFieldingError {
+        batter: S,
+        fielder: PlacedPlayer<S>,
+        error: FieldingErrorType,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+        ejection: Option<Ejection<S>>,
+    },
+
+    // Season 1
+    WeatherDelivery {
+        delivery: Delivery<S>,
+    },
+    FallingStar {
+        player_name: S,
+    },
+    FallingStarOutcome {
+        deflection: Option<S>,
+        player_name: S,
+        outcome: FallingStarOutcome<S>,
+    },
+
+    // Season 2
+    WeatherShipment {
+        deliveries: Vec<Delivery<S>>,
+    },
+    WeatherSpecialDelivery {
+        delivery: Delivery<S>,
+    },
+    Balk {
+        pitcher: S,
+        scores: Vec<S>,
+        advances: Vec<RunnerAdvance<S>>,
+    },
+
+    // Season 3,
+    WeatherProsperity {
+        home_income: u32,
+        away_income: u32,
+    },
+
+    // Season 4
+    PhotoContest {
+        winning_team: EmojiTeam<S>,
+        winning_tokens: u32,
+        winning_player: S,
+        winning_score: u16,
+        losing_team: EmojiTeam<S>,
+        losing_tokens: u32,
+        losing_player: S,
+        losing_score: u16,
+    },
+
+    // Season 5
+    Party {
+        pitcher_name: S,
+        pitcher_amount_gained: u8,
+        pitcher_attribute: Attribute,
+        batter_name: S,
+        batter_amount_gained: u8,
+        batter_attribute: Attribute,
+        durability_loss: PartyDurabilityLoss<S>,
+    },
+    WeatherReflection {
+        team: EmojiTeam<S>,
+    },
+    WeatherWither {
+        team_emoji: S,
+        player: PlacedPlayer<S>,
+        corrupted: WitherResult,
+        contained: ContainResult<S>,
+    },
+
+    // Season 6
+    LinealBeltTransfer {
+        claimed_by: EmojiTeam<S>,
+        claimed_from: EmojiTeam<S>,
+    },
+
+    // Season 9
+    WeatherConsumption(WeatherConsumptionEvents<S>),
+
+    // Season 10
+    WeatherSimulacrum {
+        real_team: EmojiTeam<S>,
+        simulacrum_team: EmojiTeam<S>,
+        tokens_earnt: u32,
+    },
+    WeatherSimulacrumOffseason,
+}
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "NowBattingStats")]
+enum NowBattingStatsDef {
+    FirstPA,
+    Stats(#[serde(with = "batter_stats")] Vec<BatterStat>),
+    NoStats,
+}
+
+mod batter_stats {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::BatterStatDef;
+    use crate::enums::BatterStat;
+
+    #[derive(Serialize)]
+    struct Ref<'a>(#[serde(with = "BatterStatDef")] &'a BatterStat);
+
+    #[derive(Deserialize)]
+    struct Owned(#[serde(with = "BatterStatDef")] BatterStat);
+
+    pub(super) fn serialize<S: Serializer>(
+        stats: &[BatterStat],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(stats.iter().map(Ref))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<BatterStat>, D::Error> {
+        let stats = Vec::<Owned>::deserialize(deserializer)?;
+        Ok(stats.into_iter().map(|Owned(stat)| stat).collect())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "BatterStat")]
+enum BatterStatDef {
+    // Season 0
+    HitsForAtBats { hits: u8, at_bats: u8 },
+    FirstBases(u8),
+    SecondBases(u8),
+    ThirdBases(u8),
+    HomeRuns(u8),
+    SacrificeFlies(u8),
+    PopOuts(u8),
+    LineOuts(u8),
+    StrikeOuts(u8),
+    ForceOuts(u8),
+    BaseOnBalls(u8),
+    HitByPitchs(u8),
+    GroundIntoDoublePlays(u8),
+    CaughtDoublePlays(u8),
+    FieldersChoices(u8),
+    Fouls(u8),
+
+    // Season 1
+    GroundOuts(u8),
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+
+    use crate::{
+        binary_serde::BinaryEvent, enums::NowBattingStats, parsed_event::GameEventParseError,
+        process_game, Game, NotRecognized, ParsedEventMessage,
+    };
+
+    #[test]
+    fn livingston_postcard_round_trip() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let mut events = process_game(&game, "68474b55452606ed6b72dbe8")
+            .iter()
+            .map(|event| BinaryEvent(event.to_owned()))
+            .collect::<Vec<_>>();
+        assert!(events.iter().any(|BinaryEvent(event)| matches!(
+            event,
+            ParsedEventMessage::NowBatting {
+                stats: NowBattingStats::Stats(_),
+                ..
+            }
+        )));
+        events.push(BinaryEvent(ParsedEventMessage::ParseError {
+            error: GameEventParseError::EventTypeNotRecognized(NotRecognized("Mystery".into())),
+            message: "Something new happened.".to_string(),
+        }));
+
+        let bytes = postcard::to_allocvec(&events).unwrap();
+        let read_back: Vec<BinaryEvent<String>> = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(read_back, events);
+    }
+}
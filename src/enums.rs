@@ -22,6 +22,18 @@ use thiserror::Error;
 use crate::nom_parsing::shared::IResult;
 
 /// Possible values of the "event" field of an mmolb event.
+///
+/// ```
+/// use mmolb_parsing::enums::EventType;
+///
+/// assert!(EventType::Pitch.is_pitch());
+/// assert!(EventType::AwayLineup.is_lineup());
+/// assert!(EventType::WeatherDelivery.is_weather());
+/// assert!(EventType::FallingStar.is_weather());
+/// assert!(EventType::HrcChange.is_hrc());
+/// assert!(!EventType::Field.is_pitch());
+/// assert!(!EventType::Field.is_weather());
+/// ```
 #[derive(
     Debug,
     Clone,
@@ -36,6 +48,8 @@ use crate::nom_parsing::shared::IResult;
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum EventType {
     // Season 0
     PitchingMatchup,
@@ -114,6 +128,159 @@ pub enum EventType {
     WeatherSimulacrum,
 }
 
+impl EventType {
+    /// Whether this is a pitch event.
+    pub fn is_pitch(self) -> bool {
+        match self {
+            EventType::Pitch => true,
+            EventType::PitchingMatchup
+            | EventType::MoundVisit
+            | EventType::GameOver
+            | EventType::Field
+            | EventType::HomeLineup
+            | EventType::Recordkeeping
+            | EventType::LiveNow
+            | EventType::InningStart
+            | EventType::AwayLineup
+            | EventType::InningEnd
+            | EventType::PlayBall
+            | EventType::NowBatting
+            | EventType::WeatherDelivery
+            | EventType::FallingStar
+            | EventType::Weather
+            | EventType::HrcLiveNow
+            | EventType::HrcPitchingMatchup
+            | EventType::HrcBattingMatchup
+            | EventType::HrcPlayBall
+            | EventType::HrcChange
+            | EventType::WeatherShipment
+            | EventType::WeatherSpecialDelivery
+            | EventType::Balk
+            | EventType::WeatherProsperity
+            | EventType::PhotoContest
+            | EventType::Party
+            | EventType::WeatherReflection
+            | EventType::WeatherWither
+            | EventType::LinealBeltTransfer
+            | EventType::WeatherConsumption
+            | EventType::WeatherSimulacrum => false,
+        }
+    }
+
+    /// Whether this is either team's lineup.
+    pub fn is_lineup(self) -> bool {
+        match self {
+            EventType::HomeLineup | EventType::AwayLineup => true,
+            EventType::PitchingMatchup
+            | EventType::MoundVisit
+            | EventType::GameOver
+            | EventType::Field
+            | EventType::Recordkeeping
+            | EventType::LiveNow
+            | EventType::InningStart
+            | EventType::Pitch
+            | EventType::InningEnd
+            | EventType::PlayBall
+            | EventType::NowBatting
+            | EventType::WeatherDelivery
+            | EventType::FallingStar
+            | EventType::Weather
+            | EventType::HrcLiveNow
+            | EventType::HrcPitchingMatchup
+            | EventType::HrcBattingMatchup
+            | EventType::HrcPlayBall
+            | EventType::HrcChange
+            | EventType::WeatherShipment
+            | EventType::WeatherSpecialDelivery
+            | EventType::Balk
+            | EventType::WeatherProsperity
+            | EventType::PhotoContest
+            | EventType::Party
+            | EventType::WeatherReflection
+            | EventType::WeatherWither
+            | EventType::LinealBeltTransfer
+            | EventType::WeatherConsumption
+            | EventType::WeatherSimulacrum => false,
+        }
+    }
+
+    /// Whether this event comes from the weather, including falling stars.
+    pub fn is_weather(self) -> bool {
+        match self {
+            EventType::WeatherDelivery
+            | EventType::Weather
+            | EventType::WeatherShipment
+            | EventType::WeatherSpecialDelivery
+            | EventType::WeatherProsperity
+            | EventType::WeatherReflection
+            | EventType::WeatherWither
+            | EventType::WeatherConsumption
+            | EventType::WeatherSimulacrum
+            | EventType::FallingStar => true,
+            EventType::PitchingMatchup
+            | EventType::MoundVisit
+            | EventType::GameOver
+            | EventType::Field
+            | EventType::HomeLineup
+            | EventType::Recordkeeping
+            | EventType::LiveNow
+            | EventType::InningStart
+            | EventType::Pitch
+            | EventType::AwayLineup
+            | EventType::InningEnd
+            | EventType::PlayBall
+            | EventType::NowBatting
+            | EventType::HrcLiveNow
+            | EventType::HrcPitchingMatchup
+            | EventType::HrcBattingMatchup
+            | EventType::HrcPlayBall
+            | EventType::HrcChange
+            | EventType::Balk
+            | EventType::PhotoContest
+            | EventType::Party
+            | EventType::LinealBeltTransfer => false,
+        }
+    }
+
+    /// Whether this is part of a home run challenge.
+    pub fn is_hrc(self) -> bool {
+        match self {
+            EventType::HrcLiveNow
+            | EventType::HrcPitchingMatchup
+            | EventType::HrcBattingMatchup
+            | EventType::HrcPlayBall
+            | EventType::HrcChange => true,
+            EventType::PitchingMatchup
+            | EventType::MoundVisit
+            | EventType::GameOver
+            | EventType::Field
+            | EventType::HomeLineup
+            | EventType::Recordkeeping
+            | EventType::LiveNow
+            | EventType::InningStart
+            | EventType::Pitch
+            | EventType::AwayLineup
+            | EventType::InningEnd
+            | EventType::PlayBall
+            | EventType::NowBatting
+            | EventType::WeatherDelivery
+            | EventType::FallingStar
+            | EventType::Weather
+            | EventType::WeatherShipment
+            | EventType::WeatherSpecialDelivery
+            | EventType::Balk
+            | EventType::WeatherProsperity
+            | EventType::PhotoContest
+            | EventType::Party
+            | EventType::WeatherReflection
+            | EventType::WeatherWither
+            | EventType::LinealBeltTransfer
+            | EventType::WeatherConsumption
+            | EventType::WeatherSimulacrum => false,
+        }
+    }
+}
+
 /// Top or bottom of an inning.
 ///
 /// ```
@@ -146,6 +313,8 @@ pub enum EventType {
     EnumIter,
     Default,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum TopBottom {
     #[default]
     #[strum(to_string = "top")]
@@ -247,6 +416,8 @@ impl From<HomeAway> for TopBottom {
     Display,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum HomeAway {
     Away,
     Home,
@@ -403,6 +574,50 @@ impl Inning {
             None
         }
     }
+
+    /// The 0-based index of the current half inning, during a game. Useful for indexing into a scoreboard.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Inning;
+    /// use mmolb_parsing::enums::TopBottom;
+    ///
+    /// assert_eq!(Inning::BeforeGame.half_index(), None);
+    /// assert_eq!(Inning::DuringGame { number: 1, batting_side: TopBottom::Top }.half_index(), Some(0));
+    /// assert_eq!(Inning::DuringGame { number: 1, batting_side: TopBottom::Bottom }.half_index(), Some(1));
+    /// assert_eq!(Inning::DuringGame { number: 9, batting_side: TopBottom::Bottom }.half_index(), Some(17));
+    /// assert_eq!(Inning::DuringGame { number: 9, batting_side: TopBottom::Bottom }.next(true).unwrap().half_index(), Some(18));
+    /// assert_eq!(Inning::AfterGame {final_inning_number: 9}.half_index(), None);
+    /// ```
+    pub fn half_index(self) -> Option<usize> {
+        if let Inning::DuringGame {
+            number,
+            batting_side,
+        } = self
+        {
+            let side_offset = match batting_side {
+                TopBottom::Top => 0,
+                TopBottom::Bottom => 1,
+            };
+            Some(usize::from(number.saturating_sub(1)) * 2 + side_offset)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the game has gone past the 9th inning.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Inning;
+    /// use mmolb_parsing::enums::TopBottom;
+    ///
+    /// let ninth = Inning::DuringGame { number: 9, batting_side: TopBottom::Bottom };
+    /// assert!(!ninth.is_extra_innings());
+    /// assert!(ninth.next(true).unwrap().is_extra_innings());
+    /// assert!(!Inning::AfterGame {final_inning_number: 10}.is_extra_innings());
+    /// ```
+    pub fn is_extra_innings(self) -> bool {
+        matches!(self, Inning::DuringGame { number, .. } if number > 9)
+    }
 }
 
 /// Player roster/fielding positions.
@@ -412,6 +627,8 @@ impl Inning {
 ///
 /// assert_eq!(Position::FirstBaseman.to_string(), "1B");
 /// ```
+///
+/// The default is Pitcher. This choice is arbitrary, it's only there for building test data.
 #[derive(
     EnumString,
     IntoStaticStr,
@@ -425,8 +642,10 @@ impl Inning {
     PartialEq,
     Eq,
     Hash,
+    Default,
 )]
 pub enum Position {
+    #[default]
     #[strum(to_string = "P")]
     #[serde(rename = "P")]
     Pitcher,
@@ -486,6 +705,8 @@ pub enum Position {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FairBallDestination {
     #[strum(to_string = "the shortstop")]
     ShortStop,
@@ -507,6 +728,57 @@ pub enum FairBallDestination {
     #[strum(to_string = "right field")]
     RightField,
 }
+impl FairBallDestination {
+    /// The fielder who plays where the ball went. Balls hit to a base go to that baseman.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::{FairBallDestination, Place};
+    ///
+    /// assert_eq!(FairBallDestination::SecondBase.place(), Place::SecondBaseman);
+    /// assert_eq!(FairBallDestination::try_from(Place::LeftField), Ok(FairBallDestination::LeftField));
+    /// assert!(FairBallDestination::try_from(Place::DesignatedHitter).is_err());
+    /// ```
+    pub fn place(self) -> Place {
+        match self {
+            FairBallDestination::ShortStop => Place::ShortStop,
+            FairBallDestination::Catcher => Place::Catcher,
+            FairBallDestination::Pitcher => Place::Pitcher,
+            FairBallDestination::FirstBase => Place::FirstBaseman,
+            FairBallDestination::SecondBase => Place::SecondBaseman,
+            FairBallDestination::ThirdBase => Place::ThirdBaseman,
+            FairBallDestination::LeftField => Place::LeftField,
+            FairBallDestination::CenterField => Place::CenterField,
+            FairBallDestination::RightField => Place::RightField,
+        }
+    }
+}
+
+/// Error for FairBallDestination's TryFrom<Place> implementation: the designated hitter doesn't field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("balls aren't hit to the {0}")]
+pub struct NotAFielder(pub Place);
+
+/// Every kind of pitcher fields balls hit to "the pitcher".
+impl TryFrom<Place> for FairBallDestination {
+    type Error = NotAFielder;
+    fn try_from(value: Place) -> Result<Self, Self::Error> {
+        match value {
+            Place::Pitcher
+            | Place::StartingPitcher(_)
+            | Place::ReliefPitcher(_)
+            | Place::Closer => Ok(FairBallDestination::Pitcher),
+            Place::Catcher => Ok(FairBallDestination::Catcher),
+            Place::FirstBaseman => Ok(FairBallDestination::FirstBase),
+            Place::SecondBaseman => Ok(FairBallDestination::SecondBase),
+            Place::ThirdBaseman => Ok(FairBallDestination::ThirdBase),
+            Place::ShortStop => Ok(FairBallDestination::ShortStop),
+            Place::LeftField => Ok(FairBallDestination::LeftField),
+            Place::CenterField => Ok(FairBallDestination::CenterField),
+            Place::RightField => Ok(FairBallDestination::RightField),
+            Place::DesignatedHitter => Err(NotAFielder(value)),
+        }
+    }
+}
 
 /// A characterisation of a fair ball.
 ///
@@ -530,6 +802,8 @@ pub enum FairBallDestination {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FairBallType {
     #[strum(to_string = "ground ball")]
     GroundBall,
@@ -642,6 +916,8 @@ impl PitchType {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum StrikeType {
     #[strum(to_string = "looking")]
     Looking,
@@ -670,6 +946,8 @@ pub enum StrikeType {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FieldingErrorType {
     #[strum(to_string = "Throwing", serialize = "throwing")]
     Throwing,
@@ -721,6 +999,8 @@ impl FieldingErrorType {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FoulType {
     #[strum(to_string = "tip")]
     Tip,
@@ -728,12 +1008,18 @@ pub enum FoulType {
     Ball,
 }
 
+/// Home means two different things depending on context: in a steal or an advance, a runner going home has scored,
+/// but as a starting point (e.g. in [`Base::advance`]) it's the batter at the plate. Bases are ordered in the
+/// direction runners travel, starting from home plate.
+///
 /// ```
 /// use mmolb_parsing::enums::Base;
 ///
 /// assert_eq!(Base::First.to_string(), "first");
 /// assert_eq!(Base::First.to_base_str(), "first base");
 /// assert_eq!(Base::Home.to_base_str(), "home");
+/// assert!(Base::Home < Base::First);
+/// assert!(Base::Second < Base::Third);
 /// ```
 #[derive(
     Clone,
@@ -746,9 +1032,13 @@ pub enum FoulType {
     Deserialize,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Base {
     #[strum(to_string = "home")]
     Home,
@@ -774,6 +1064,42 @@ impl Base {
             Base::Home => "home",
         }
     }
+
+    /// How many bases a runner here has covered, treating Home as the batter at the plate.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Base;
+    ///
+    /// assert_eq!(Base::Home.distance_from_home(), 0);
+    /// assert_eq!(Base::Third.distance_from_home(), 3);
+    /// ```
+    pub fn distance_from_home(self) -> u8 {
+        match self {
+            Base::Home => 0,
+            Base::First => 1,
+            Base::Second => 2,
+            Base::Third => 3,
+        }
+    }
+
+    /// The base a runner here reaches after advancing the given number of bases, treating Home as the batter at the plate.
+    /// None means they made it all the way around and scored.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Base;
+    ///
+    /// assert_eq!(Base::Home.advance(2), Some(Base::Second));
+    /// assert_eq!(Base::Second.advance(2), None);
+    /// ```
+    pub fn advance(self, bases: u8) -> Option<Base> {
+        match self.distance_from_home().saturating_add(bases) {
+            0 => Some(Base::Home),
+            1 => Some(Base::First),
+            2 => Some(Base::Second),
+            3 => Some(Base::Third),
+            _ => None,
+        }
+    }
 }
 impl From<BaseNameVariant> for Base {
     fn from(value: BaseNameVariant) -> Self {
@@ -815,6 +1141,8 @@ impl From<BaseNameVariant> for Base {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum BaseNameVariant {
     #[strum(to_string = "first")]
     First,
@@ -847,6 +1175,42 @@ impl BaseNameVariant {
             Base::Home => BaseNameVariant::Home,
         }
     }
+
+    /// The name for a given base in the given style. Home only has one name, "home", which is used for every style.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::{Base, BaseNameStyle, BaseNameVariant};
+    ///
+    /// assert_eq!(BaseNameVariant::for_base(Base::First, BaseNameStyle::Numeric), BaseNameVariant::OneB);
+    /// assert_eq!(BaseNameVariant::for_base(Base::First, BaseNameStyle::Spoken), BaseNameVariant::FirstBase);
+    /// assert_eq!(BaseNameVariant::for_base(Base::Third, BaseNameStyle::Short).to_string(), "third");
+    /// assert_eq!(BaseNameVariant::for_base(Base::Home, BaseNameStyle::Numeric), BaseNameVariant::Home);
+    /// ```
+    pub fn for_base(base: Base, style: BaseNameStyle) -> BaseNameVariant {
+        match (base, style) {
+            (Base::First, BaseNameStyle::Short) => BaseNameVariant::First,
+            (Base::First, BaseNameStyle::Spoken) => BaseNameVariant::FirstBase,
+            (Base::First, BaseNameStyle::Numeric) => BaseNameVariant::OneB,
+            (Base::Second, BaseNameStyle::Short) => BaseNameVariant::Second,
+            (Base::Second, BaseNameStyle::Spoken) => BaseNameVariant::SecondBase,
+            (Base::Second, BaseNameStyle::Numeric) => BaseNameVariant::TwoB,
+            (Base::Third, BaseNameStyle::Short) => BaseNameVariant::Third,
+            (Base::Third, BaseNameStyle::Spoken) => BaseNameVariant::ThirdBase,
+            (Base::Third, BaseNameStyle::Numeric) => BaseNameVariant::ThreeB,
+            (Base::Home, _) => BaseNameVariant::Home,
+        }
+    }
+}
+
+/// The ways mmolb spells out a base, used with [`BaseNameVariant::for_base`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter)]
+pub enum BaseNameStyle {
+    /// e.g. "first"
+    Short,
+    /// e.g. "first base"
+    Spoken,
+    /// e.g. "1B"
+    Numeric,
 }
 
 /// ```
@@ -868,6 +1232,8 @@ impl BaseNameVariant {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Distance {
     #[strum(to_string = "singles")]
     Single,
@@ -876,11 +1242,65 @@ pub enum Distance {
     #[strum(to_string = "triples")]
     Triple,
 }
+impl Distance {
+    /// How many bases the batter reached.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Distance;
+    ///
+    /// assert_eq!(Distance::Single.bases(), 1);
+    /// assert_eq!(Distance::Triple.bases(), 3);
+    /// ```
+    pub fn bases(self) -> u8 {
+        match self {
+            Distance::Single => 1,
+            Distance::Double => 2,
+            Distance::Triple => 3,
+        }
+    }
+
+    /// Doubles and triples. Home runs aren't a [`Distance`], so they aren't covered here.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Distance;
+    ///
+    /// assert!(!Distance::Single.is_extra_base_hit());
+    /// assert!(Distance::Double.is_extra_base_hit());
+    /// ```
+    pub fn is_extra_base_hit(self) -> bool {
+        self != Distance::Single
+    }
+}
+
+/// Error for Distance's TryFrom<u8> implementation: fails because the given number of bases wasn't 1, 2 or 3.
+///
+/// ```
+/// use mmolb_parsing::enums::{Distance, NotADistance};
+///
+/// assert_eq!(Distance::try_from(2), Ok(Distance::Double));
+/// assert_eq!(Distance::try_from(4), Err(NotADistance(4)));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Error)]
+#[error("{0} bases is not a single, double or triple")]
+pub struct NotADistance(pub u8);
+impl TryFrom<u8> for Distance {
+    type Error = NotADistance;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Distance::Single),
+            2 => Ok(Distance::Double),
+            3 => Ok(Distance::Triple),
+            _ => Err(NotADistance(value)),
+        }
+    }
+}
 
 /// Possible followup to "Now batting: [BATTER]". (e.g. "(1st PA of game)")
 #[derive(
     Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, EnumIter, EnumDiscriminants,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum_discriminants(derive(Display))]
 pub enum NowBattingStats {
     FirstPA,
@@ -900,6 +1320,8 @@ pub enum NowBattingStats {
 #[derive(
     Clone, Debug, EnumDiscriminants, Serialize, Deserialize, PartialEq, Eq, Hash, EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum_discriminants(derive(EnumString, IntoStaticStr, Display))]
 #[serde(tag = "stat", content = "value")]
 pub enum BatterStat {
@@ -1005,7 +1427,6 @@ impl FromStr for BatterStat {
             a_u8_tag(" SF").map(BatterStat::SacrificeFlies),
             a_u8_tag(" F").map(BatterStat::Fouls),
             a_u8_tag(" BB").map(BatterStat::BaseOnBalls),
-            a_u8_tag(" FC").map(BatterStat::SacrificeFlies),
             a_u8_tag(" HBP").map(BatterStat::HitByPitchs),
             a_u8_tag(" GIDP").map(BatterStat::GroundIntoDoublePlays),
             a_u8_tag(" CDP").map(BatterStat::CaughtDoublePlays),
@@ -1151,6 +1572,288 @@ pub enum GameStat {
     Holds,
 }
 
+impl GameStat {
+    /// Whether this stat only counts plays with runners in scoring position.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::GameStat;
+    ///
+    /// assert!(GameStat::RunsRisp.is_risp());
+    /// assert!(!GameStat::Runs.is_risp());
+    /// ```
+    pub fn is_risp(self) -> bool {
+        self.base_stat() != self
+    }
+
+    /// The stat without the runners in scoring position split, e.g. Runs for RunsRisp.
+    /// Stats that aren't RISP splits are returned as is.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::GameStat;
+    ///
+    /// assert_eq!(GameStat::SinglesRisp.base_stat(), GameStat::Singles);
+    /// assert_eq!(GameStat::Singles.base_stat(), GameStat::Singles);
+    /// ```
+    pub fn base_stat(self) -> GameStat {
+        match self {
+            GameStat::LeftOnBaseRisp => GameStat::LeftOnBase,
+            GameStat::StrikeoutsRisp => GameStat::Strikeouts,
+            GameStat::HomeRunsRisp => GameStat::HomeRuns,
+            GameStat::EarnedRunsRisp => GameStat::EarnedRuns,
+            GameStat::StolenBasesRisp => GameStat::StolenBases,
+            GameStat::HomeRunsAllowedRisp => GameStat::HomeRunsAllowed,
+            GameStat::FieldersChoiceRisp => GameStat::FieldersChoice,
+            GameStat::SacFliesRisp => GameStat::SacFlies,
+            GameStat::TriplesRisp => GameStat::Triples,
+            GameStat::FieldOutRisp => GameStat::FieldOut,
+            GameStat::UnearnedRunsRisp => GameStat::UnearnedRuns,
+            GameStat::InheritedRunnersRisp => GameStat::InheritedRunners,
+            GameStat::RunsRisp => GameStat::Runs,
+            GameStat::GroundedIntoDoublePlayRisp => GameStat::GroundedIntoDoublePlay,
+            GameStat::RunsBattedInRisp => GameStat::RunsBattedIn,
+            GameStat::AssistsRisp => GameStat::Assists,
+            GameStat::CaughtDoublePlayRisp => GameStat::CaughtDoublePlay,
+            GameStat::LineoutsRisp => GameStat::Lineouts,
+            GameStat::ReachedOnErrorRisp => GameStat::ReachedOnError,
+            GameStat::PlateAppearancesRisp => GameStat::PlateAppearances,
+            GameStat::CaughtStealingRisp => GameStat::CaughtStealing,
+            GameStat::RunnersCaughtStealingRisp => GameStat::RunnersCaughtStealing,
+            GameStat::BattersFacedRisp => GameStat::BattersFaced,
+            GameStat::ForceOutsRisp => GameStat::ForceOuts,
+            GameStat::SinglesRisp => GameStat::Singles,
+            GameStat::AtBatsRisp => GameStat::AtBats,
+            GameStat::DoublePlaysRisp => GameStat::DoublePlays,
+            GameStat::WalkedRisp => GameStat::Walked,
+            GameStat::StruckOutRisp => GameStat::StruckOut,
+            GameStat::PopoutsRisp => GameStat::Popouts,
+            GameStat::HitByPitchRisp => GameStat::HitByPitch,
+            GameStat::InheritedRunsAllowedRisp => GameStat::InheritedRunsAllowed,
+            GameStat::WalksRisp => GameStat::Walks,
+            GameStat::SacrificeDoublePlaysRisp => GameStat::SacrificeDoublePlays,
+            GameStat::HitBattersRisp => GameStat::HitBatters,
+            GameStat::DoublesRisp => GameStat::Doubles,
+            GameStat::FlyoutsRisp => GameStat::Flyouts,
+            GameStat::PitchesThrownRisp => GameStat::PitchesThrown,
+            GameStat::PutoutsRisp => GameStat::Putouts,
+            GameStat::GroundoutRisp => GameStat::Groundout,
+            GameStat::ErrorsRisp => GameStat::Errors,
+            GameStat::HitsAllowedRisp => GameStat::HitsAllowed,
+            GameStat::AllowedStolenBasesRisp => GameStat::AllowedStolenBases,
+            GameStat::GroundoutsRisp => GameStat::Groundouts,
+            GameStat::BalksRisp => GameStat::Balks,
+            GameStat::GroundedIntoDoublePlay
+            | GameStat::Groundout
+            | GameStat::AllowedStolenBases
+            | GameStat::FieldersChoice
+            | GameStat::SacFlies
+            | GameStat::Assists
+            | GameStat::RunsBattedIn
+            | GameStat::Popouts
+            | GameStat::AtBats
+            | GameStat::Strikeouts
+            | GameStat::Losses
+            | GameStat::ForceOuts
+            | GameStat::Shutouts
+            | GameStat::BattersFaced
+            | GameStat::EarnedRuns
+            | GameStat::FieldOut
+            | GameStat::StolenBases
+            | GameStat::Walked
+            | GameStat::MoundVisits
+            | GameStat::QualityStarts
+            | GameStat::Wins
+            | GameStat::HitsAllowed
+            | GameStat::RunnersCaughtStealing
+            | GameStat::StruckOut
+            | GameStat::Saves
+            | GameStat::Walks
+            | GameStat::ReachedOnError
+            | GameStat::BlownSaves
+            | GameStat::LeftOnBase
+            | GameStat::UnearnedRuns
+            | GameStat::Triples
+            | GameStat::SacrificeDoublePlays
+            | GameStat::Starts
+            | GameStat::InheritedRunsAllowed
+            | GameStat::NoHitters
+            | GameStat::GamesFinished
+            | GameStat::DoublePlays
+            | GameStat::Singles
+            | GameStat::Lineouts
+            | GameStat::PlateAppearances
+            | GameStat::CaughtStealing
+            | GameStat::Putouts
+            | GameStat::HitBatters
+            | GameStat::HitByPitch
+            | GameStat::Errors
+            | GameStat::HomeRuns
+            | GameStat::Appearances
+            | GameStat::Outs
+            | GameStat::Doubles
+            | GameStat::InheritedRunners
+            | GameStat::PitchesThrown
+            | GameStat::CompleteGames
+            | GameStat::Flyouts
+            | GameStat::CaughtDoublePlay
+            | GameStat::HomeRunsAllowed
+            | GameStat::Runs
+            | GameStat::PerfectGames
+            | GameStat::Groundouts
+            | GameStat::Balks
+            | GameStat::HomeRunChallengeAppearances
+            | GameStat::HomeRunChallengeHomeRunsAllowed
+            | GameStat::HomeRunChallengeHomeRuns
+            | GameStat::Ejected
+            | GameStat::Holds => self,
+        }
+    }
+}
+
+/// Which part of the game a [`GameStat`] is recorded for.
+///
+/// ```
+/// use mmolb_parsing::enums::{GameStat, GameStatCategory};
+///
+/// assert_eq!(GameStatCategory::from(GameStat::Strikeouts), GameStatCategory::Pitching);
+/// assert_eq!(GameStatCategory::from(GameStat::StruckOut), GameStatCategory::Batting);
+/// ```
+#[derive(
+    EnumString,
+    IntoStaticStr,
+    Display,
+    Debug,
+    SerializeDisplay,
+    DeserializeFromStr,
+    Clone,
+    Copy,
+    EnumIter,
+    PartialEq,
+    Eq,
+    Hash,
+)]
+pub enum GameStatCategory {
+    Batting,
+    Pitching,
+    Fielding,
+    Baserunning,
+}
+
+impl From<GameStat> for GameStatCategory {
+    fn from(value: GameStat) -> Self {
+        match value {
+            GameStat::GroundedIntoDoublePlay
+            | GameStat::GroundedIntoDoublePlayRisp
+            | GameStat::Groundout
+            | GameStat::GroundoutRisp
+            | GameStat::Groundouts
+            | GameStat::GroundoutsRisp
+            | GameStat::FieldersChoice
+            | GameStat::FieldersChoiceRisp
+            | GameStat::SacFlies
+            | GameStat::SacFliesRisp
+            | GameStat::SacrificeDoublePlays
+            | GameStat::SacrificeDoublePlaysRisp
+            | GameStat::RunsBattedIn
+            | GameStat::RunsBattedInRisp
+            | GameStat::Popouts
+            | GameStat::PopoutsRisp
+            | GameStat::Flyouts
+            | GameStat::FlyoutsRisp
+            | GameStat::Lineouts
+            | GameStat::LineoutsRisp
+            | GameStat::FieldOut
+            | GameStat::FieldOutRisp
+            | GameStat::ForceOuts
+            | GameStat::ForceOutsRisp
+            | GameStat::AtBats
+            | GameStat::AtBatsRisp
+            | GameStat::PlateAppearances
+            | GameStat::PlateAppearancesRisp
+            | GameStat::Walked
+            | GameStat::WalkedRisp
+            | GameStat::StruckOut
+            | GameStat::StruckOutRisp
+            | GameStat::HitByPitch
+            | GameStat::HitByPitchRisp
+            | GameStat::ReachedOnError
+            | GameStat::ReachedOnErrorRisp
+            | GameStat::LeftOnBase
+            | GameStat::LeftOnBaseRisp
+            | GameStat::Singles
+            | GameStat::SinglesRisp
+            | GameStat::Doubles
+            | GameStat::DoublesRisp
+            | GameStat::Triples
+            | GameStat::TriplesRisp
+            | GameStat::HomeRuns
+            | GameStat::HomeRunsRisp
+            | GameStat::Runs
+            | GameStat::RunsRisp
+            | GameStat::HomeRunChallengeAppearances
+            | GameStat::HomeRunChallengeHomeRuns => GameStatCategory::Batting,
+            GameStat::Appearances
+            | GameStat::Starts
+            | GameStat::Wins
+            | GameStat::Losses
+            | GameStat::Saves
+            | GameStat::BlownSaves
+            | GameStat::Holds
+            | GameStat::QualityStarts
+            | GameStat::CompleteGames
+            | GameStat::Shutouts
+            | GameStat::NoHitters
+            | GameStat::PerfectGames
+            | GameStat::GamesFinished
+            | GameStat::Outs
+            | GameStat::BattersFaced
+            | GameStat::BattersFacedRisp
+            | GameStat::PitchesThrown
+            | GameStat::PitchesThrownRisp
+            | GameStat::Strikeouts
+            | GameStat::StrikeoutsRisp
+            | GameStat::Walks
+            | GameStat::WalksRisp
+            | GameStat::HitBatters
+            | GameStat::HitBattersRisp
+            | GameStat::HitsAllowed
+            | GameStat::HitsAllowedRisp
+            | GameStat::HomeRunsAllowed
+            | GameStat::HomeRunsAllowedRisp
+            | GameStat::EarnedRuns
+            | GameStat::EarnedRunsRisp
+            | GameStat::UnearnedRuns
+            | GameStat::UnearnedRunsRisp
+            | GameStat::InheritedRunners
+            | GameStat::InheritedRunnersRisp
+            | GameStat::InheritedRunsAllowed
+            | GameStat::InheritedRunsAllowedRisp
+            | GameStat::AllowedStolenBases
+            | GameStat::AllowedStolenBasesRisp
+            | GameStat::MoundVisits
+            | GameStat::Balks
+            | GameStat::BalksRisp
+            | GameStat::Ejected
+            | GameStat::HomeRunChallengeHomeRunsAllowed => GameStatCategory::Pitching,
+            GameStat::Putouts
+            | GameStat::PutoutsRisp
+            | GameStat::Assists
+            | GameStat::AssistsRisp
+            | GameStat::Errors
+            | GameStat::ErrorsRisp
+            | GameStat::DoublePlays
+            | GameStat::DoublePlaysRisp
+            | GameStat::RunnersCaughtStealing
+            | GameStat::RunnersCaughtStealingRisp => GameStatCategory::Fielding,
+            GameStat::StolenBases
+            | GameStat::StolenBasesRisp
+            | GameStat::CaughtStealing
+            | GameStat::CaughtStealingRisp
+            | GameStat::CaughtDoublePlay
+            | GameStat::CaughtDoublePlayRisp => GameStatCategory::Baserunning,
+        }
+    }
+}
+
 #[derive(
     Clone,
     Copy,
@@ -1165,6 +1868,8 @@ pub enum GameStat {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum GameOverMessage {
     /// Early season 0 "Game over." e.g. 6805db4bac48194de3cd42d2
     #[strum(to_string = "Game over.")]
@@ -1188,6 +1893,8 @@ pub enum GameOverMessage {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ItemName {
     Cap,
     Gloves,
@@ -1239,6 +1946,74 @@ pub enum ItemName {
     PitchersSocks,
 }
 
+impl ItemName {
+    /// ```
+    /// use mmolb_parsing::enums::{ItemCategory, ItemName};
+    ///
+    /// assert_eq!(ItemName::Cap.category(), ItemCategory::Apparel);
+    /// assert_eq!(ItemName::Ring.category(), ItemCategory::Accessory);
+    /// ```
+    pub fn category(self) -> ItemCategory {
+        self.into()
+    }
+
+    /// ```
+    /// use mmolb_parsing::enums::ItemName;
+    ///
+    /// assert!(ItemName::ProgressOrb.is_orb());
+    /// assert!(!ItemName::Gloves.is_orb());
+    /// ```
+    pub fn is_orb(self) -> bool {
+        self.category() == ItemCategory::Orb
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    EnumString,
+    IntoStaticStr,
+    Display,
+    Debug,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ItemCategory {
+    Apparel,
+    Accessory,
+    Orb,
+}
+
+impl From<ItemName> for ItemCategory {
+    fn from(value: ItemName) -> Self {
+        match value {
+            ItemName::Cap
+            | ItemName::Gloves
+            | ItemName::TShirt
+            | ItemName::Sneakers
+            | ItemName::SluggersHelm
+            | ItemName::SluggersJersey
+            | ItemName::SluggersCleats
+            | ItemName::SluggersMitts
+            | ItemName::PitchersSunHat
+            | ItemName::PitchersJacket
+            | ItemName::PitchersSleeve
+            | ItemName::PitchersSocks => ItemCategory::Apparel,
+            ItemName::Ring | ItemName::SluggersCharm | ItemName::PitchersPendant => {
+                ItemCategory::Accessory
+            }
+            ItemName::AmplificationOrb | ItemName::ProgressOrb | ItemName::AmbitionOrb => {
+                ItemCategory::Orb
+            }
+        }
+    }
+}
+
 #[derive(
     Clone,
     Copy,
@@ -1273,6 +2048,7 @@ pub enum SpecialItemType {
     Hash,
     EnumIter,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum FeedEventType {
@@ -1308,10 +2084,12 @@ pub enum LinkType {
     Team,
 }
 
+/// The default is RegularSeason.
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, SerializeDisplay, DeserializeFromStr,
+    Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, SerializeDisplay, DeserializeFromStr, Default,
 )]
 pub enum SeasonStatus {
+    #[default]
     RegularSeason,
     SuperstarBreak,
     HomeRunChallenge,
@@ -1435,6 +2213,13 @@ where
         .map_err(|_| D::Error::custom("Expected a number"))
 }
 
+/// The default is the first day of the regular season, Day(1).
+impl Default for Day {
+    fn default() -> Self {
+        Day::Day(1)
+    }
+}
+
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1454,6 +2239,57 @@ impl Display for Day {
     }
 }
 
+impl Day {
+    /// The day number, for regular season days.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Day;
+    ///
+    /// assert_eq!(Day::Day(42).as_number(), Some(42));
+    /// assert_eq!(Day::SuperstarBreak.as_number(), None);
+    /// ```
+    pub fn as_number(self) -> Option<u16> {
+        match self {
+            Day::Day(day) => Some(day),
+            _ => None,
+        }
+    }
+
+    /// A key that sorts days in the order they happen within a season:
+    ///
+    /// Preseason, days 1 to 120, Superstar Break, Superstar Days, Superstar Game, days 121 onwards.
+    ///
+    /// The other named days (Postseason Preview, Postseason Rounds, Election, Holiday, Event, Special Event
+    /// and Offseason) return None, because where they fall among the numbered days changes from season to season.
+    /// For example, season 6's Holiday came after day 299 but season 9's came before day 270. That's also why
+    /// `Day` doesn't implement `Ord`: sort by the feed event's timestamp when these days are involved.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::Day;
+    ///
+    /// assert!(Day::Day(120).chronological_key() < Day::SuperstarBreak.chronological_key());
+    /// assert!(Day::SuperstarGame.chronological_key() < Day::Day(121).chronological_key());
+    /// assert_eq!(Day::Holiday.chronological_key(), None);
+    /// ```
+    pub fn chronological_key(self) -> Option<(u8, u16)> {
+        match self {
+            Day::Preseason => Some((0, 0)),
+            Day::Day(day) if day <= 120 => Some((1, day)),
+            Day::SuperstarBreak => Some((2, 0)),
+            Day::SuperstarDay(day) => Some((2, day as u16 + 1)),
+            Day::SuperstarGame => Some((3, 0)),
+            Day::Day(day) => Some((4, day)),
+            Day::PostseasonPreview
+            | Day::PostseasonRound(_)
+            | Day::Election
+            | Day::Holiday
+            | Day::Event
+            | Day::SpecialEvent
+            | Day::Offseason => None,
+        }
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -1503,6 +2339,57 @@ impl Display for RecordType {
     }
 }
 
+#[derive(Debug, Clone, Copy, Error)]
+#[error("{0} records don't have a season status")]
+pub struct NoSeasonStatus(pub RecordType);
+
+/// The season status during which games of this record type are played. Kumite has no season status.
+///
+/// ```
+/// use mmolb_parsing::enums::{RecordType, SeasonStatus};
+///
+/// assert_eq!(SeasonStatus::try_from(RecordType::PostseasonRound(2)).unwrap(), SeasonStatus::PostseasonRound(2));
+/// assert!(SeasonStatus::try_from(RecordType::Kumite).is_err());
+/// ```
+impl TryFrom<RecordType> for SeasonStatus {
+    type Error = NoSeasonStatus;
+    fn try_from(value: RecordType) -> Result<Self, Self::Error> {
+        match value {
+            RecordType::RegularSeason => Ok(SeasonStatus::RegularSeason),
+            RecordType::PostseasonRound(round) => Ok(SeasonStatus::PostseasonRound(round)),
+            RecordType::SuperstarGame => Ok(SeasonStatus::SuperstarGame),
+            RecordType::HomeRunChallenge => Ok(SeasonStatus::HomeRunChallenge),
+            RecordType::Kumite => Err(NoSeasonStatus(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Error)]
+#[error("games during {0} don't have a record type")]
+pub struct NoRecordType(pub SeasonStatus);
+
+/// The record type of games played during this season status. Only statuses with games that count
+/// towards a record have one, so e.g. Holiday, Election and Superstar Break don't.
+impl TryFrom<SeasonStatus> for RecordType {
+    type Error = NoRecordType;
+    fn try_from(value: SeasonStatus) -> Result<Self, Self::Error> {
+        match value {
+            SeasonStatus::RegularSeason => Ok(RecordType::RegularSeason),
+            SeasonStatus::PostseasonRound(round) => Ok(RecordType::PostseasonRound(round)),
+            SeasonStatus::SuperstarGame => Ok(RecordType::SuperstarGame),
+            SeasonStatus::HomeRunChallenge => Ok(RecordType::HomeRunChallenge),
+            SeasonStatus::SuperstarBreak
+            | SeasonStatus::Holiday
+            | SeasonStatus::SpecialEvent
+            | SeasonStatus::Event
+            | SeasonStatus::Election
+            | SeasonStatus::Preseason
+            | SeasonStatus::PostseasonPreview
+            | SeasonStatus::Offseason => Err(NoRecordType(value)),
+        }
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -1597,6 +2484,72 @@ impl FromStr for Slot {
     }
 }
 
+/// Error for Position's TryFrom<Slot> implementation: the designated hitter has no roster position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("{0} doesn't have a position")]
+pub struct NoPosition(pub Slot);
+
+/// Error for Slot's TryFrom<Position> implementation: a pitching position doesn't say which numbered slot the pitcher is in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("{0} doesn't correspond to a single slot")]
+pub struct NoSingleSlot(pub Position);
+
+/// Numbered pitcher slots lose their number, and DH fails because Position has no designated hitter.
+///
+/// ```
+/// use mmolb_parsing::enums::{Position, Slot};
+///
+/// assert_eq!(Position::try_from(Slot::StartingPitcher(3)), Ok(Position::StartingPitcher));
+/// assert!(Position::try_from(Slot::DesignatedHitter).is_err());
+/// ```
+impl TryFrom<Slot> for Position {
+    type Error = NoPosition;
+    fn try_from(value: Slot) -> Result<Self, Self::Error> {
+        match value {
+            Slot::Catcher => Ok(Position::Catcher),
+            Slot::FirstBaseman => Ok(Position::FirstBaseman),
+            Slot::SecondBaseman => Ok(Position::SecondBaseman),
+            Slot::ThirdBaseman => Ok(Position::ThirdBaseman),
+            Slot::ShortStop => Ok(Position::ShortStop),
+            Slot::LeftField => Ok(Position::LeftField),
+            Slot::CenterField => Ok(Position::CenterField),
+            Slot::RightField => Ok(Position::RightField),
+            Slot::StartingPitcher(_) => Ok(Position::StartingPitcher),
+            Slot::ReliefPitcher(_) => Ok(Position::ReliefPitcher),
+            Slot::Closer => Ok(Position::Closer),
+            Slot::DesignatedHitter => Err(NoPosition(value)),
+        }
+    }
+}
+
+/// Fails for Pitcher, StartingPitcher and ReliefPitcher, since the slot would need a number the position doesn't have.
+///
+/// ```
+/// use mmolb_parsing::enums::{Position, Slot};
+///
+/// assert_eq!(Slot::try_from(Position::Closer), Ok(Slot::Closer));
+/// assert!(Slot::try_from(Position::StartingPitcher).is_err());
+/// ```
+impl TryFrom<Position> for Slot {
+    type Error = NoSingleSlot;
+    fn try_from(value: Position) -> Result<Self, Self::Error> {
+        match value {
+            Position::Catcher => Ok(Slot::Catcher),
+            Position::FirstBaseman => Ok(Slot::FirstBaseman),
+            Position::SecondBaseman => Ok(Slot::SecondBaseman),
+            Position::ThirdBaseman => Ok(Slot::ThirdBaseman),
+            Position::ShortStop => Ok(Slot::ShortStop),
+            Position::LeftField => Ok(Slot::LeftField),
+            Position::CenterField => Ok(Slot::CenterField),
+            Position::RightField => Ok(Slot::RightField),
+            Position::Closer => Ok(Slot::Closer),
+            Position::Pitcher | Position::StartingPitcher | Position::ReliefPitcher => {
+                Err(NoSingleSlot(value))
+            }
+        }
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -1673,6 +2626,7 @@ impl Display for FullSlot {
     Eq,
     Hash,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Attribute {
     Priority,
     Luck,
@@ -1785,6 +2739,78 @@ impl TryFrom<Attribute> for AttributeCategory {
     }
 }
 
+impl Attribute {
+    /// The category this attribute is listed under. None for Priority and Luck, which don't have one.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::{Attribute, AttributeCategory};
+    ///
+    /// assert_eq!(Attribute::Contact.category(), Some(AttributeCategory::Batting));
+    /// assert_eq!(Attribute::Luck.category(), None);
+    /// ```
+    pub fn category(self) -> Option<AttributeCategory> {
+        AttributeCategory::try_from(self).ok()
+    }
+}
+
+impl AttributeCategory {
+    /// Every attribute in this category. The inverse of [`Attribute::category`].
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::{Attribute, AttributeCategory};
+    ///
+    /// assert!(AttributeCategory::Baserunning.attributes().contains(&Attribute::Speed));
+    /// ```
+    pub fn attributes(self) -> &'static [Attribute] {
+        match self {
+            AttributeCategory::Batting => &[
+                Attribute::Aiming,
+                Attribute::Contact,
+                Attribute::Cunning,
+                Attribute::Discipline,
+                Attribute::Insight,
+                Attribute::Intimidation,
+                Attribute::Lift,
+                Attribute::Vision,
+                Attribute::Determination,
+                Attribute::Wisdom,
+                Attribute::Muscle,
+                Attribute::Selflessness,
+            ],
+            AttributeCategory::Pitching => &[
+                Attribute::Accuracy,
+                Attribute::Rotation,
+                Attribute::Presence,
+                Attribute::Persuasion,
+                Attribute::Stamina,
+                Attribute::Velocity,
+                Attribute::Control,
+                Attribute::Stuff,
+                Attribute::Defiance,
+                Attribute::Guts,
+                Attribute::Deception,
+                Attribute::Intuition,
+            ],
+            AttributeCategory::Defense => &[
+                Attribute::Acrobatics,
+                Attribute::Agility,
+                Attribute::Arm,
+                Attribute::Awareness,
+                Attribute::Composure,
+                Attribute::Dexterity,
+                Attribute::Patience,
+                Attribute::Reaction,
+            ],
+            AttributeCategory::Baserunning => &[
+                Attribute::Greed,
+                Attribute::Performance,
+                Attribute::Speed,
+                Attribute::Stealth,
+            ],
+        }
+    }
+}
+
 #[derive(
     EnumString,
     IntoStaticStr,
@@ -1799,6 +2825,7 @@ impl TryFrom<Attribute> for AttributeCategory {
     Eq,
     Hash,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ItemPrefix {
     Sharp,
     Consistent,
@@ -1868,6 +2895,48 @@ pub enum ItemPrefix {
     Intuitive,
 }
 
+impl ItemPrefix {
+    /// The attributes this prefix boosts.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::{Attribute, ItemPrefix};
+    ///
+    /// assert_eq!(ItemPrefix::Consistent.attributes(), &[Attribute::Contact]);
+    /// ```
+    pub fn attributes(self) -> &'static [Attribute] {
+        match self {
+            ItemPrefix::Sharp | ItemPrefix::Deadeyes => &[Attribute::Aiming],
+            ItemPrefix::Consistent | ItemPrefix::Pinpoint => &[Attribute::Contact],
+            ItemPrefix::Clever | ItemPrefix::Scheming => &[Attribute::Cunning],
+            ItemPrefix::Steadfast | ItemPrefix::Resolute => &[Attribute::Determination],
+            ItemPrefix::Insightful | ItemPrefix::Oracles => &[Attribute::Insight],
+            ItemPrefix::Menacing | ItemPrefix::Fearsome => &[Attribute::Intimidation],
+            ItemPrefix::Lofty | ItemPrefix::Skyborne => &[Attribute::Lift],
+            ItemPrefix::EagleEyed | ItemPrefix::Clairvoyant => &[Attribute::Vision],
+            ItemPrefix::Stalwart | ItemPrefix::Meticulous => &[Attribute::Discipline],
+            ItemPrefix::Wise | ItemPrefix::Sages => &[Attribute::Wisdom],
+            ItemPrefix::Mighty | ItemPrefix::Titanic => &[Attribute::Muscle],
+            ItemPrefix::Selfless | ItemPrefix::Altruistic => &[Attribute::Selflessness],
+            ItemPrefix::True | ItemPrefix::LaserGuided => &[Attribute::Accuracy],
+            ItemPrefix::Commanding | ItemPrefix::Imperious => &[Attribute::Presence],
+            ItemPrefix::Charming | ItemPrefix::Sirens => &[Attribute::Persuasion],
+            ItemPrefix::Courageous | ItemPrefix::Dauntless => &[Attribute::Guts],
+            ItemPrefix::Rebellious | ItemPrefix::Unbowing => &[Attribute::Defiance],
+            ItemPrefix::Enduring | ItemPrefix::Tireless => &[Attribute::Stamina],
+            ItemPrefix::Rapid | ItemPrefix::Lightspeed => &[Attribute::Velocity],
+            ItemPrefix::Precise | ItemPrefix::IronWilled => &[Attribute::Control],
+            ItemPrefix::Whirling | ItemPrefix::Gyroscopic => &[Attribute::Rotation],
+            ItemPrefix::Filthy | ItemPrefix::Wicked => &[Attribute::Stuff],
+            ItemPrefix::Avaricious | ItemPrefix::Covetous => &[Attribute::Greed],
+            ItemPrefix::Dazzling | ItemPrefix::Showstopping => &[Attribute::Performance],
+            ItemPrefix::Swift | ItemPrefix::Lightning => &[Attribute::Speed],
+            ItemPrefix::Sneaky | ItemPrefix::Phantasmal => &[Attribute::Stealth],
+            ItemPrefix::Deceptive | ItemPrefix::Phantoms => &[Attribute::Deception],
+            ItemPrefix::Prescient | ItemPrefix::Intuitive => &[Attribute::Intuition],
+        }
+    }
+}
+
 #[derive(
     EnumString,
     IntoStaticStr,
@@ -1882,6 +2951,7 @@ pub enum ItemPrefix {
     Eq,
     Hash,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum ItemSuffix {
     #[strum(to_string = "of the Acrobat")]
     Acrobat,
@@ -1921,6 +2991,29 @@ pub enum ItemSuffix {
     Watcher,
 }
 
+impl ItemSuffix {
+    /// The attributes this suffix boosts.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::{Attribute, ItemSuffix};
+    ///
+    /// assert_eq!(ItemSuffix::Cat.attributes(), &[Attribute::Agility]);
+    /// ```
+    pub fn attributes(self) -> &'static [Attribute] {
+        match self {
+            ItemSuffix::Acrobat | ItemSuffix::Sky => &[Attribute::Acrobatics],
+            ItemSuffix::Cat | ItemSuffix::Gale => &[Attribute::Agility],
+            ItemSuffix::Cannon | ItemSuffix::Ballista => &[Attribute::Arm],
+            ItemSuffix::Awareness | ItemSuffix::Sentinel => &[Attribute::Awareness],
+            ItemSuffix::Calm | ItemSuffix::Equilibrium => &[Attribute::Composure],
+            ItemSuffix::Skill | ItemSuffix::Artisan => &[Attribute::Dexterity],
+            ItemSuffix::Patience | ItemSuffix::Watcher => &[Attribute::Patience],
+            ItemSuffix::Reflexes | ItemSuffix::Instinct => &[Attribute::Reaction],
+            ItemSuffix::Fortune | ItemSuffix::Serendipity => &[Attribute::Luck],
+        }
+    }
+}
+
 /// The various places a player in a game has been said to be.
 #[derive(
     Debug,
@@ -1934,6 +3027,7 @@ pub enum ItemSuffix {
     Hash,
     EnumDiscriminants,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[strum_discriminants(derive(EnumString, IntoStaticStr, Display))]
 pub enum Place {
     #[strum_discriminants(strum(to_string = "P"))]
@@ -2050,6 +3144,8 @@ impl Display for Place {
     Eq,
     Hash,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum MoundVisitType {
     #[strum(to_string = "mound visit")]
     MoundVisit,
@@ -2078,6 +3174,18 @@ pub enum LeagueScale {
     Exhibition,
 }
 
+/// The default is Right, the most common handedness.
+///
+/// Parses from both the single letter the API uses and the full word, but always displays
+/// and serializes as the single letter.
+///
+/// ```
+/// use std::str::FromStr;
+/// use mmolb_parsing::enums::Handedness;
+///
+/// assert_eq!(Handedness::from_str("Left"), Ok(Handedness::Left));
+/// assert_eq!(Handedness::Left.to_string(), "L");
+/// ```
 #[derive(
     Debug,
     Serialize,
@@ -2091,16 +3199,18 @@ pub enum LeagueScale {
     EnumString,
     IntoStaticStr,
     Display,
+    Default,
 )]
 pub enum Handedness {
-    #[strum(to_string = "L")]
-    #[serde(rename = "L")]
+    #[strum(to_string = "L", serialize = "Left")]
+    #[serde(rename = "L", alias = "Left")]
     Left,
-    #[strum(to_string = "R")]
-    #[serde(rename = "R")]
+    #[default]
+    #[strum(to_string = "R", serialize = "Right")]
+    #[serde(rename = "R", alias = "Right")]
     Right,
-    #[strum(to_string = "S")]
-    #[serde(rename = "S")]
+    #[strum(to_string = "S", serialize = "Switch")]
+    #[serde(rename = "S", alias = "Switch")]
     Switch,
 }
 
@@ -2123,6 +3233,15 @@ pub enum EquipmentEffectType {
     Multiplier,
 }
 
+/// Ordered from most to least common: Normal items have no affixes, Magic items have a prefix and/or suffix,
+/// and Rare items have a rare name on top of their prefix and suffix.
+///
+/// ```
+/// use mmolb_parsing::enums::EquipmentRarity;
+///
+/// assert!(EquipmentRarity::Normal < EquipmentRarity::Magic);
+/// assert!(EquipmentRarity::Magic < EquipmentRarity::Rare);
+/// ```
 #[derive(
     Debug,
     Serialize,
@@ -2132,6 +3251,8 @@ pub enum EquipmentEffectType {
     EnumIter,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     Hash,
     EnumString,
     IntoStaticStr,
@@ -2139,8 +3260,15 @@ pub enum EquipmentEffectType {
 )]
 pub enum EquipmentRarity {
     Normal,
-    Rare,
     Magic,
+    Rare,
+}
+
+impl EquipmentRarity {
+    /// True for anything better than [`EquipmentRarity::Normal`], i.e. Magic and Rare items.
+    pub fn is_rare(self) -> bool {
+        self != EquipmentRarity::Normal
+    }
 }
 
 #[derive(
@@ -2227,6 +3355,7 @@ fn _check(_: &str) -> Infallible {
     IntoStaticStr,
     Display,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum(
     parse_err_fn = check,
     parse_err_ty = Infallible
@@ -2323,6 +3452,80 @@ impl ModificationType {
 
         r
     }
+
+    /// The modification's name, as it appears in the API.
+    pub fn base_name(&self) -> &str {
+        match self {
+            ModificationType::Unknown(name) => name,
+            known => known.into(),
+        }
+    }
+
+    /// The emoji the API shows alongside this modification, taken from the feed events where
+    /// players gained it.
+    ///
+    /// None for [`Self::Unknown`] and for modifications that haven't been seen in a feed yet.
+    ///
+    /// ```
+    /// use mmolb_parsing::enums::ModificationType;
+    ///
+    /// assert_eq!(ModificationType::ROBO.emoji(), Some("🤖"));
+    /// assert_eq!(ModificationType::Giant.emoji(), None);
+    /// ```
+    pub fn emoji(&self) -> Option<&'static str> {
+        match self {
+            ModificationType::FireElemental => Some("🔥"),
+            ModificationType::WaterElemental => Some("💧"),
+            ModificationType::EarthElemental => Some("⛰️"),
+            ModificationType::Demonic => Some("😈"),
+            ModificationType::ROBO => Some("🤖"),
+            ModificationType::Draconic => Some("🐲"),
+            ModificationType::Angelic => Some("👼"),
+            ModificationType::Fae => Some("🧚"),
+            ModificationType::ArchersMark => Some("🏹"),
+            ModificationType::Spectral => Some("👻"),
+            ModificationType::TenaciousBadger => Some("🦡"),
+            ModificationType::Psychic => Some("👁️"),
+            ModificationType::OneWithAll => Some("⚾"),
+            ModificationType::Amphibian => Some("🐸"),
+            ModificationType::GeometryExpert => Some("📐"),
+            ModificationType::TheLight => Some("🚦"),
+            ModificationType::Insectoid => Some("🐞"),
+            ModificationType::Shiny => Some("🌟"),
+            ModificationType::Scooter => Some("🛴"),
+            ModificationType::Mer => Some("🧜"),
+            ModificationType::Clean => Some("🧹"),
+            ModificationType::Cowardly => Some("🐇"),
+            ModificationType::Logical => Some("💭"),
+            ModificationType::Creative => Some("🎨"),
+            ModificationType::Introverted => Some("🤐"),
+            ModificationType::Intuitive => Some("✨"),
+            ModificationType::Unrelenting => Some("🦛"),
+            ModificationType::StrongStarter => Some("🚀"),
+            ModificationType::Outsider => Some("➡️"),
+            ModificationType::Analytical => Some("🔍"),
+            ModificationType::IronWill => Some("🦾"),
+            ModificationType::Lucky => Some("🍀"),
+            ModificationType::Prolific => Some("🛡️"),
+            ModificationType::Insider => Some("⬅️"),
+            ModificationType::Criminal => Some("🕶️"),
+            ModificationType::Clutch => Some("👏"),
+            ModificationType::FirstStrike => Some("☄️"),
+            ModificationType::Unwavering => Some("🌳"),
+            ModificationType::Partier => Some("🎉"),
+            ModificationType::AllKnowing => Some("🧠"),
+            ModificationType::Underdog => Some("🐶"),
+            ModificationType::Immovable => Some("🪨"),
+            // No sample of these emojis yet.
+            ModificationType::AirElemental
+            | ModificationType::Undead
+            | ModificationType::Giant
+            | ModificationType::Stormrider
+            | ModificationType::UFO
+            | ModificationType::Calculated
+            | ModificationType::Unknown(_) => None,
+        }
+    }
 }
 
 #[derive(
@@ -2339,6 +3542,7 @@ impl ModificationType {
     IntoStaticStr,
     Display,
 )]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum CelestialEnergyTier {
     BeganToGlow,
     Infused,
@@ -2359,6 +3563,8 @@ pub enum CelestialEnergyTier {
     IntoStaticStr,
     Display,
 )]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum(serialize_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum FoodName {
@@ -2577,4 +3783,326 @@ mod test {
         serde_round_trip_inner::<ModificationType>();
         serde_round_trip_inner::<BallparkSuffix>();
     }
+
+    #[test]
+    fn slot_position_round_trip() {
+        for slot in Slot::iter().chain([Slot::StartingPitcher(5), Slot::ReliefPitcher(3)]) {
+            match Position::try_from(slot) {
+                Ok(position) => {
+                    assert_eq!(
+                        Place::from(slot).discriminant(),
+                        Place::from(position).discriminant()
+                    );
+                    match slot {
+                        Slot::StartingPitcher(_) | Slot::ReliefPitcher(_) => {
+                            assert_eq!(Slot::try_from(position), Err(NoSingleSlot(position)))
+                        }
+                        _ => assert_eq!(Slot::try_from(position), Ok(slot)),
+                    }
+                }
+                Err(e) => assert_eq!(e, NoPosition(Slot::DesignatedHitter)),
+            }
+        }
+    }
+
+    #[test]
+    fn base_advance() {
+        for base in Base::iter() {
+            assert_eq!(base.advance(0), Some(base));
+            for bases in 1..=u8::MAX {
+                match base.advance(bases) {
+                    Some(advanced) => {
+                        assert!(advanced > base);
+                        assert_eq!(
+                            advanced.distance_from_home(),
+                            base.distance_from_home() + bases
+                        );
+                    }
+                    None => assert!(base.distance_from_home() as u16 + bases as u16 >= 4),
+                }
+            }
+        }
+
+        assert_eq!(Base::First.advance(3), None);
+        assert_eq!(Base::Third.advance(1), None);
+        assert_eq!(Base::Home.advance(3), Some(Base::Third));
+    }
+
+    #[test]
+    fn fair_ball_destination_place_round_trip() {
+        for destination in FairBallDestination::iter() {
+            assert_eq!(
+                FairBallDestination::try_from(destination.place()),
+                Ok(destination)
+            );
+        }
+    }
+
+    #[test]
+    fn game_stat_base_stat() {
+        for stat in GameStat::iter() {
+            let base_stat = stat.base_stat();
+            assert_eq!(base_stat.base_stat(), base_stat);
+            assert!(!base_stat.is_risp());
+            assert_eq!(stat.is_risp(), stat.to_string().ends_with("_risp"));
+        }
+    }
+
+    #[test]
+    fn game_stat_category() {
+        for stat in GameStat::iter() {
+            let category = GameStatCategory::from(stat);
+            assert_eq!(GameStatCategory::from(stat.base_stat()), category);
+        }
+    }
+
+    /// Modifications that haven't been seen in a feed yet, so have no known emoji.
+    const MODIFICATIONS_WITHOUT_EMOJI: [ModificationType; 6] = [
+        ModificationType::AirElemental,
+        ModificationType::Undead,
+        ModificationType::Giant,
+        ModificationType::Stormrider,
+        ModificationType::UFO,
+        ModificationType::Calculated,
+    ];
+
+    #[test]
+    fn modification_type_display() {
+        for modification in ModificationType::iter() {
+            assert_eq!(modification.base_name(), modification.to_string());
+
+            if matches!(modification, ModificationType::Unknown(_))
+                || MODIFICATIONS_WITHOUT_EMOJI.contains(&modification)
+            {
+                assert_eq!(modification.emoji(), None, "{modification}");
+            } else {
+                assert!(
+                    modification.emoji().is_some_and(|emoji| !emoji.is_empty()),
+                    "{modification}"
+                );
+            }
+        }
+
+        // A player gaining a modification is shown with the modification's emoji
+        let mut checked = 0;
+        for (_, feed) in crate::utils::raw_test_data::<crate::team_feed::TeamFeed>("team_feed") {
+            for event in feed.feed {
+                let Some(name) = event
+                    .text
+                    .split_once(" gained the ")
+                    .and_then(|(_, rest)| rest.strip_suffix(" Modification."))
+                else {
+                    continue;
+                };
+                if let Some(emoji) = ModificationType::new(name).emoji() {
+                    assert_eq!(emoji, event.emoji, "{}", event.text);
+                    checked += 1;
+                }
+            }
+        }
+        assert!(checked > 0);
+
+        assert_eq!(ModificationType::FireElemental.emoji(), Some("🔥"));
+
+        let unknown = ModificationType::Unknown("Wither".to_string());
+        assert_eq!(unknown.base_name(), "Wither");
+        assert_eq!(unknown.emoji(), None);
+    }
+
+    #[test]
+    fn item_affix_attributes() {
+        for prefix in ItemPrefix::iter() {
+            assert!(!prefix.attributes().is_empty());
+        }
+        for suffix in ItemSuffix::iter() {
+            assert!(!suffix.attributes().is_empty());
+        }
+    }
+
+    #[test]
+    fn handedness_full_words() -> Result<(), serde_json::Error> {
+        for handedness in Handedness::iter() {
+            let letter = handedness.to_string();
+            let word = format!("{handedness:?}");
+            assert_eq!(Handedness::from_str(&letter), Ok(handedness));
+            assert_eq!(Handedness::from_str(&word), Ok(handedness));
+            assert_eq!(
+                serde_json::from_value::<Handedness>(letter.into())?,
+                handedness
+            );
+            assert_eq!(
+                serde_json::from_value::<Handedness>(word.into())?,
+                handedness
+            );
+        }
+
+        let left: Handedness = serde_json::from_str("\"Left\"")?;
+        assert_eq!(serde_json::to_string(&left)?, "\"L\"");
+        Ok(())
+    }
+
+    #[test]
+    fn season_status_record_type() {
+        for record_type in RecordType::iter() {
+            match SeasonStatus::try_from(record_type) {
+                Ok(season_status) => {
+                    assert_eq!(RecordType::try_from(season_status).ok(), Some(record_type))
+                }
+                Err(_) => assert_eq!(record_type, RecordType::Kumite),
+            }
+        }
+
+        let mut with_record_type = 0;
+        for season_status in SeasonStatus::iter() {
+            if let Ok(record_type) = RecordType::try_from(season_status) {
+                assert_eq!(
+                    SeasonStatus::try_from(record_type).ok(),
+                    Some(season_status)
+                );
+                with_record_type += 1;
+            }
+        }
+        assert_eq!(with_record_type, 4);
+
+        assert_eq!(
+            SeasonStatus::try_from(RecordType::PostseasonRound(3)).ok(),
+            Some(SeasonStatus::PostseasonRound(3))
+        );
+    }
+
+    #[test]
+    fn batter_stat_from_str() {
+        let stats = [
+            "1 1B", "2 2B", "1 3B", "1 HR", "1 SF", "2 PO", "1 LO", "3 SO", "1 FO", "2 BB",
+            "1 HBP", "1 GIDP", "1 CDP", "1 FC", "4 F", "2 GO", "1 for 3",
+        ];
+        for stat in stats {
+            let parsed = BatterStat::from_str(stat).unwrap();
+            assert_eq!(parsed.to_string(), stat);
+        }
+
+        assert_eq!(
+            BatterStat::from_str("1 SF"),
+            Ok(BatterStat::SacrificeFlies(1))
+        );
+        assert_eq!(
+            BatterStat::from_str("1 FC"),
+            Ok(BatterStat::FieldersChoices(1))
+        );
+    }
+
+    #[test]
+    fn attribute_categories() {
+        for attribute in Attribute::iter() {
+            let categories = AttributeCategory::iter()
+                .filter(|category| category.attributes().contains(&attribute))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                categories,
+                attribute.category().into_iter().collect::<Vec<_>>(),
+                "{attribute}"
+            );
+        }
+    }
+
+    #[test]
+    fn item_categories() {
+        for item in ItemName::iter() {
+            assert_eq!(item.is_orb(), item.to_string().ends_with(" Orb"), "{item}");
+        }
+        for category in ItemCategory::iter() {
+            assert!(
+                ItemName::iter().any(|item| item.category() == category),
+                "{category}"
+            );
+        }
+    }
+
+    #[test]
+    fn equipment_rarity_ordering() {
+        let mut rarities = vec![
+            EquipmentRarity::Rare,
+            EquipmentRarity::Normal,
+            EquipmentRarity::Magic,
+            EquipmentRarity::Normal,
+        ];
+        rarities.sort();
+        assert_eq!(
+            rarities,
+            [
+                EquipmentRarity::Normal,
+                EquipmentRarity::Normal,
+                EquipmentRarity::Magic,
+                EquipmentRarity::Rare
+            ]
+        );
+        assert_eq!(
+            EquipmentRarity::iter()
+                .filter(|rarity| rarity.is_rare())
+                .collect::<Vec<_>>(),
+            [EquipmentRarity::Magic, EquipmentRarity::Rare]
+        );
+    }
+
+    #[test]
+    fn base_name_styles() {
+        for base in Base::iter() {
+            for style in BaseNameStyle::iter() {
+                assert_eq!(Base::from(BaseNameVariant::for_base(base, style)), base);
+            }
+            assert_eq!(
+                BaseNameVariant::for_base(base, BaseNameStyle::Short),
+                BaseNameVariant::basic_name(base)
+            );
+        }
+        // Every spelling is reachable from some style
+        for variant in BaseNameVariant::iter() {
+            assert!(BaseNameStyle::iter()
+                .any(|style| BaseNameVariant::for_base(Base::from(variant), style) == variant));
+        }
+    }
+
+    #[test]
+    fn day_chronological_key() {
+        let f =
+            std::fs::File::open("test_data/raw/team_feed/6805f2d34277d0dcecdd3b2e.json").unwrap();
+        let feed: crate::team_feed::TeamFeed = serde_json::from_reader(f).unwrap();
+
+        // Team feeds are in timestamp order, so within a season every day with a key should be in key order
+        let mut keyed = 0;
+        for season in feed.feed.chunk_by(|a, b| a.season == b.season) {
+            let keys = season
+                .iter()
+                .filter_map(|event| event.day.as_ref().ok()?.chronological_key())
+                .collect::<Vec<_>>();
+            assert!(keys.is_sorted(), "season {}", season[0].season);
+            keyed += keys.len();
+        }
+        assert!(keyed > 0);
+
+        // Seen on both sides of numbered postseason days
+        assert_eq!(Day::Holiday.chronological_key(), None);
+        assert_eq!(Day::PostseasonPreview.chronological_key(), None);
+    }
+}
+
+/// These are (de)serialized through their Display and FromStr impls, so their schema is just a string.
+#[cfg(feature = "schemars")]
+macro_rules! string_json_schema {
+    ($($t:ty),*) => {
+        $(
+            impl schemars::JsonSchema for $t {
+                fn schema_name() -> std::borrow::Cow<'static, str> {
+                    stringify!($t).into()
+                }
+
+                fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                    schemars::json_schema!({ "type": "string" })
+                }
+            }
+        )*
+    };
 }
+
+#[cfg(feature = "schemars")]
+string_json_schema!(Attribute, ItemPrefix, ItemSuffix, Place, Slot, FullSlot);
@@ -1,8 +1,11 @@
+use crate::player_feed::{parse_player_feed_event, ParsedPlayerFeedEventText};
+use crate::team_feed::{parse_team_feed_event, ParsedTeamFeedEventText};
 use crate::time::Breakpoints;
 use crate::{
     enums::{CelestialEnergyTier, Day, FeedEventType, LinkType, SeasonStatus},
     utils::{
         extra_fields_deserialize, MaybeRecognizedHelper, MaybeRecognizedResult, TimestampHelper,
+        TIMESTAMP_FORMAT,
     },
 };
 use chrono::{DateTime, Utc};
@@ -34,6 +37,60 @@ pub struct FeedEvent {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl FeedEvent {
+    /// The timestamp in the same format mmolb uses, e.g. "2025-07-14T11:30:00.000000+00:00".
+    pub fn timestamp_raw(&self) -> String {
+        self.timestamp.format(TIMESTAMP_FORMAT).to_string()
+    }
+
+    /// A key that sorts events in the order they happened, e.g. to merge a player's feed with their team's.
+    ///
+    /// Events are ordered by timestamp, then by season and [`Day::chronological_key`] if the timestamps are equal.
+    /// Unrecognized days, and days without a fixed place in the season, sort before every other day.
+    pub fn chronological_key(&self) -> (DateTime<Utc>, u8, Option<(u8, u16)>) {
+        (
+            self.timestamp,
+            self.season,
+            self.day
+                .as_ref()
+                .ok()
+                .and_then(|day| day.chronological_key()),
+        )
+    }
+
+    /// The links to entities of the given type that this event mentions, in the order they appear.
+    /// Links with an unrecognized type are skipped.
+    pub fn links_of_type(&self, link_type: LinkType) -> impl Iterator<Item = &Link> {
+        self.links
+            .iter()
+            .filter(move |link| link.link_type.as_ref() == Ok(&link_type))
+    }
+
+    /// Parse this event as an event from a player's feed. Equivalent to [`parse_player_feed_event`].
+    ///
+    /// ```
+    /// use mmolb_parsing::{feed_event::FeedEvent, player_feed::ParsedPlayerFeedEventText};
+    ///
+    /// let event: FeedEvent = serde_json::from_str(r#"{"day":2,"emoji":"📦","links":[],"season":1,"status":"Regular Season","text":"Yumi Pratt received a 🧢 Cap Delivery.","ts":"2025-06-09T13:53:55.598427+00:00","type":"game"}"#).unwrap();
+    /// assert!(matches!(event.parse_as_player(), ParsedPlayerFeedEventText::Delivery { .. }));
+    /// ```
+    pub fn parse_as_player(&self) -> ParsedPlayerFeedEventText<&str> {
+        parse_player_feed_event(self)
+    }
+
+    /// Parse this event as an event from a team's feed. Equivalent to [`parse_team_feed_event`].
+    ///
+    /// ```
+    /// use mmolb_parsing::{feed_event::FeedEvent, team_feed::ParsedTeamFeedEventText};
+    ///
+    /// let event: FeedEvent = serde_json::from_str(r#"{"day":2,"emoji":"📦","links":[],"season":1,"status":"Regular Season","text":"Yumi Pratt received a 🧢 Cap Delivery.","ts":"2025-06-09T13:53:55.598427+00:00","type":"game"}"#).unwrap();
+    /// assert!(matches!(event.parse_as_team(), ParsedTeamFeedEventText::Delivery { .. }));
+    /// ```
+    pub fn parse_as_team(&self) -> ParsedTeamFeedEventText<&str> {
+        parse_team_feed_event(self)
+    }
+}
+
 #[serde_as]
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct Link {
@@ -50,6 +107,7 @@ pub struct Link {
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FeedFallingStarOutcome {
     Injury,
     Infusion(CelestialEnergyTier),
@@ -98,13 +156,27 @@ impl FeedFallingStarOutcome {
     }
 }
 
+/// Whether every event in the feed is at or after the event before it. Shared by player and team feeds.
+pub(crate) fn is_chronological(feed: &[FeedEvent]) -> bool {
+    feed.windows(2)
+        .all(|events| events[0].timestamp <= events[1].timestamp)
+}
+
+/// Sorts the feed by timestamp. Events with the same timestamp keep their order.
+pub(crate) fn sort_by_time(feed: &mut [FeedEvent]) {
+    feed.sort_by_key(|event| event.timestamp);
+}
+
 #[cfg(test)]
 mod test {
     use std::path::Path;
 
     use crate::{
+        enums::{Day, LinkType},
         feed_event::FeedEvent,
-        utils::{assert_round_trip, no_tracing_errs},
+        player_feed::{parse_player_feed_event, PlayerFeed},
+        team_feed::{parse_team_feed_event, TeamFeed},
+        utils::{assert_round_trip, no_tracing_errs, raw_test_data},
     };
 
     #[test]
@@ -116,4 +188,142 @@ mod test {
         drop(no_tracing_errs);
         Ok(())
     }
+
+    #[test]
+    fn timestamp_raw() -> Result<(), Box<dyn std::error::Error>> {
+        let f = std::fs::File::open("test_data/s2_feed_event.json")?;
+        let event: FeedEvent = serde_json::from_reader(f)?;
+
+        assert_eq!(event.timestamp_raw(), "2025-06-09T13:53:55.598427+00:00");
+        Ok(())
+    }
+
+    #[test]
+    fn chronological_key() {
+        let f =
+            std::fs::File::open("test_data/raw/player_feed/6805db0cac48194de3cd4003.json").unwrap();
+        let player_feed: PlayerFeed = serde_json::from_reader(f).unwrap();
+        let f =
+            std::fs::File::open("test_data/raw/team_feed/685e20f5d7b446d87c10153d.json").unwrap();
+        let team_feed: TeamFeed = serde_json::from_reader(f).unwrap();
+
+        let mut merged = team_feed
+            .feed
+            .iter()
+            .chain(&player_feed.feed)
+            .collect::<Vec<_>>();
+        merged.sort_by_key(|event| event.chronological_key());
+
+        assert_eq!(merged.len(), player_feed.feed.len() + team_feed.feed.len());
+        assert!(merged
+            .windows(2)
+            .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+
+        // Equal timestamps fall back to the day
+        let mut event: FeedEvent =
+            serde_json::from_reader(std::fs::File::open("test_data/s2_feed_event.json").unwrap())
+                .unwrap();
+        event.day = Ok(Day::Day(5));
+        let mut earlier = event.clone();
+        earlier.day = Ok(Day::Preseason);
+        assert!(earlier.chronological_key() < event.chronological_key());
+    }
+
+    #[test]
+    fn sort_by_time() {
+        let player_feeds = raw_test_data::<PlayerFeed>("player_feed");
+        let team_feeds = raw_test_data::<TeamFeed>("team_feed");
+
+        // Feeds come from the API in timestamp order
+        for (id, feed) in &player_feeds {
+            assert!(feed.is_chronological(), "{id}");
+        }
+        for (id, feed) in &team_feeds {
+            assert!(feed.is_chronological(), "{id}");
+        }
+
+        let (_, player_feed) = &player_feeds[0];
+        let mut shuffled = player_feed.clone();
+        shuffled.feed.reverse();
+        assert!(!shuffled.is_chronological());
+        shuffled.sort_by_time();
+        assert!(shuffled.is_chronological());
+        assert_eq!(
+            shuffled
+                .feed
+                .iter()
+                .map(|event| event.timestamp)
+                .collect::<Vec<_>>(),
+            player_feed
+                .feed
+                .iter()
+                .map(|event| event.timestamp)
+                .collect::<Vec<_>>()
+        );
+
+        let (_, team_feed) = &team_feeds[0];
+        let mut shuffled = team_feed.clone();
+        shuffled.feed.rotate_left(team_feed.feed.len() / 2);
+        assert!(!shuffled.is_chronological());
+        shuffled.sort_by_time();
+        assert!(shuffled.is_chronological());
+        assert_eq!(
+            shuffled
+                .feed
+                .iter()
+                .map(|event| event.timestamp)
+                .collect::<Vec<_>>(),
+            team_feed
+                .feed
+                .iter()
+                .map(|event| event.timestamp)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_as() {
+        let f =
+            std::fs::File::open("test_data/raw/player_feed/6805db0cac48194de3cd4003.json").unwrap();
+        let feed: PlayerFeed = serde_json::from_reader(f).unwrap();
+        for event in &feed.feed {
+            assert_eq!(event.parse_as_player(), parse_player_feed_event(event));
+        }
+
+        let f =
+            std::fs::File::open("test_data/raw/team_feed/685e20f5d7b446d87c10153d.json").unwrap();
+        let feed: TeamFeed = serde_json::from_reader(f).unwrap();
+        for event in &feed.feed {
+            assert_eq!(event.parse_as_team(), parse_team_feed_event(event));
+        }
+    }
+
+    #[test]
+    fn links_of_type() {
+        let f =
+            std::fs::File::open("test_data/raw/team_feed/685e20f5d7b446d87c10153d.json").unwrap();
+        let feed: TeamFeed = serde_json::from_reader(f).unwrap();
+        let game_result = &feed.feed[0];
+
+        let teams = game_result
+            .links_of_type(LinkType::Team)
+            .map(|link| (link.id.as_str(), link.link_match.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            teams,
+            [
+                ("685e20f5d7b446d87c10153d", "🌟 Pelican Lake Star Drops"),
+                ("680e13257d5b06095ef46718", "⚽ Falkirk Rookies")
+            ]
+        );
+
+        let games = game_result
+            .links_of_type(LinkType::Game)
+            .collect::<Vec<_>>();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].id, "685e3364e296e1542209222a");
+        assert_eq!(games[0].index, Some(526));
+
+        assert_eq!(game_result.links_of_type(LinkType::Player).count(), 0);
+    }
 }
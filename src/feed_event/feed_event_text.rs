@@ -9,12 +9,13 @@ use crate::{
         ItemSuffix, ModificationType,
     },
     feed_event::FeedEvent,
-    parsed_event::{EmojiTeam, Item},
+    parsed_event::{EmojiTeam, Item, ItemAffixes},
     time::{Breakpoints, Timestamp},
     NotRecognized,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Error)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum FeedEventParseError {
     #[error("feed event type {} not recognized", .0.0)]
     EventTypeNotRecognized(#[source] NotRecognized),
@@ -268,6 +269,7 @@ impl<S: Display> ParsedFeedEventText<S> {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct AttributeChange<S> {
     pub player_name: S,
     pub amount: i16,
@@ -275,6 +277,7 @@ pub struct AttributeChange<S> {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum GreaterAugment {
     Headliners,
     StartSmall,
@@ -283,6 +286,7 @@ pub enum GreaterAugment {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PlayerGreaterAugment {
     Headliners { attribute: Attribute },
     StartSmall { attribute: Attribute },
@@ -291,6 +295,7 @@ pub enum PlayerGreaterAugment {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedDelivery<S> {
     pub player: S,
     pub item: Item<S>,
@@ -342,6 +347,7 @@ impl<S: Display> FeedDelivery<S> {
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EmojilessItem {
     pub prefix: Option<ItemPrefix>,
     pub item: ItemName,
@@ -366,3 +372,65 @@ impl Display for EmojilessItem {
         write!(f, "{prefix}{item}{suffix}")
     }
 }
+
+impl EmojilessItem {
+    /// The full item, for when the emoji is known from elsewhere.
+    pub fn with_emoji<'a>(&self, emoji: &'a str) -> Item<&'a str> {
+        let affixes = if self.prefix.is_none() && self.suffix.is_none() {
+            ItemAffixes::None
+        } else {
+            ItemAffixes::PrefixSuffix(
+                self.prefix.into_iter().collect(),
+                self.suffix.into_iter().collect(),
+            )
+        };
+
+        Item {
+            item_emoji: emoji,
+            item: self.item,
+            affixes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        enums::{ItemName, ItemPrefix, ItemSuffix},
+        feed_event::EmojilessItem,
+        parsed_event::ItemAffixes,
+    };
+
+    #[test]
+    fn emojiless_item() {
+        let plain = EmojilessItem {
+            prefix: None,
+            item: ItemName::Cap,
+            suffix: None,
+        };
+        assert_eq!(plain.to_string(), "Cap");
+        assert_eq!(plain.with_emoji("🧢").affixes, ItemAffixes::None);
+        assert_eq!(plain.with_emoji("🧢").to_string(), "🧢 Cap");
+
+        let affixed = EmojilessItem {
+            prefix: Some(ItemPrefix::Sharp),
+            item: ItemName::Cap,
+            suffix: Some(ItemSuffix::Acrobat),
+        };
+        assert_eq!(affixed.to_string(), "Sharp Cap of the Acrobat");
+        assert_eq!(
+            affixed.with_emoji("🧢").to_string(),
+            format!("🧢 {affixed}")
+        );
+
+        let prefix_only = EmojilessItem {
+            suffix: None,
+            ..affixed
+        };
+        assert_eq!(
+            prefix_only.with_emoji("🧢").affixes,
+            ItemAffixes::PrefixSuffix(vec![ItemPrefix::Sharp], vec![])
+        );
+        assert_eq!(prefix_only.with_emoji("🧢").to_string(), "🧢 Sharp Cap");
+    }
+}
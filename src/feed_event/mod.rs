@@ -1,6 +1,7 @@
 mod feed_event;
 mod feed_event_text;
 
+pub(crate) use feed_event::{is_chronological, sort_by_time};
 pub use feed_event::{FeedEvent, FeedFallingStarOutcome};
 pub use feed_event_text::{
     AttributeChange, EmojilessItem, FeedDelivery, FeedEventParseError, GreaterAugment,
@@ -0,0 +1,104 @@
+use serde::Serialize;
+
+use crate::{
+    enums::{EventType, Inning, TopBottom},
+    game::{EventBatterVersions, EventPitcherVersions},
+    parsed_event::ParsedEventMessageDiscriminants,
+    process_game, Game,
+};
+
+/// One row per event, for loading a game into a columnar store. All strings borrow from the game.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventRow<'a> {
+    pub index: Option<u16>,
+    /// None if mmolb sent an event type that isn't recognized.
+    pub event_type: Option<EventType>,
+    /// None before and after the game.
+    pub inning: Option<u8>,
+    /// None before and after the game.
+    pub side: Option<TopBottom>,
+    pub batter: Option<&'a str>,
+    pub pitcher: Option<&'a str>,
+    /// The name of the ParsedEventMessage variant this event parsed into.
+    pub outcome: String,
+    /// Runs scored by either team during this event.
+    pub runs_scored: u16,
+    /// Whether the batter got a hit (including home runs).
+    pub is_hit: bool,
+    /// Whether at least one out was recorded during this event, including runners caught stealing.
+    /// See [`crate::ParsedEventMessage::outs_recorded`].
+    pub is_out: bool,
+}
+
+/// Flattens a game into one [`EventRow`] per event.
+pub fn event_rows<'a>(game: &'a Game, game_id: &str) -> Vec<EventRow<'a>> {
+    let parsed_events = process_game(game, game_id);
+
+    let mut previous_score = 0;
+    game.event_log
+        .iter()
+        .zip(parsed_events)
+        .map(|(event, parsed)| {
+            let (inning, side) = match event.inning {
+                Inning::DuringGame {
+                    number,
+                    batting_side,
+                } => (Some(number), Some(batting_side)),
+                _ => (None, None),
+            };
+
+            let score = event.away_score as u16 + event.home_score as u16;
+            let runs_scored = score.saturating_sub(previous_score);
+            previous_score = score;
+
+            EventRow {
+                index: event.index,
+                event_type: event.event.as_ref().ok().copied(),
+                inning,
+                side,
+                batter: batter_name(&event.batter),
+                pitcher: pitcher_name(&event.pitcher),
+                outcome: ParsedEventMessageDiscriminants::from(&parsed).to_string(),
+                runs_scored,
+                is_hit: parsed.is_hit(),
+                is_out: parsed.outs_recorded() > 0,
+            }
+        })
+        .collect()
+}
+
+fn batter_name(batter: &EventBatterVersions<String>) -> Option<&str> {
+    let name = match batter {
+        EventBatterVersions::New(batter) => &batter.name,
+        EventBatterVersions::Old(name) => name,
+    };
+    name.map_as_str().player()
+}
+
+fn pitcher_name(pitcher: &EventPitcherVersions<String>) -> Option<&str> {
+    let name = match pitcher {
+        EventPitcherVersions::New(pitcher) => &pitcher.name,
+        EventPitcherVersions::Old(name) => name,
+    };
+    name.map_as_str().player()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+
+    use crate::{game::analytics::event_rows, Game};
+
+    #[test]
+    fn livingston_runs() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let rows = event_rows(&game, "68474b55452606ed6b72dbe8");
+        assert_eq!(rows.len(), game.event_log.len());
+
+        let last = game.event_log.last().unwrap();
+        let runs: u16 = rows.iter().map(|row| row.runs_scored).sum();
+        assert_eq!(runs, last.away_score as u16 + last.home_score as u16);
+    }
+}
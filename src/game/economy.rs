@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use crate::{
+    parsed_event::{Delivery, DoorPrize, Item, ItemEquip, Prize, WeatherConsumptionEvents},
+    process_game, Game, ParsedEventMessage,
+};
+
+/// Where the tokens and items went during a game. Teams are keyed by name and players by name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameEconomy<'a> {
+    /// From prosperity, photo contests, consumption contests and the simulacrum.
+    pub tokens_by_team: HashMap<&'a str, u32>,
+    /// From door prizes.
+    pub tokens_by_player: HashMap<&'a str, u32>,
+    /// Deliveries that weren't given to a player, and consumption contest prizes.
+    pub items_by_team: HashMap<&'a str, Vec<Item<&'a str>>>,
+    /// Deliveries and door prizes. Door prize items are counted for whoever equipped them.
+    pub items_by_player: HashMap<&'a str, Vec<Item<&'a str>>>,
+}
+
+impl<'a> GameEconomy<'a> {
+    fn add_team_tokens(&mut self, team: &'a str, tokens: u32) {
+        *self.tokens_by_team.entry(team).or_default() += tokens;
+    }
+
+    fn add_delivery(&mut self, delivery: Delivery<&'a str>) {
+        if let Delivery::Successful {
+            team, player, item, ..
+        } = delivery
+        {
+            match player {
+                Some(player) => self.items_by_player.entry(player).or_default().push(item),
+                None => self.items_by_team.entry(team.name).or_default().push(item),
+            }
+        }
+    }
+
+    fn add_door_prizes(&mut self, door_prizes: Vec<DoorPrize<&'a str>>) {
+        for DoorPrize { player, prize } in door_prizes {
+            match prize {
+                Some(Prize::Tokens(tokens)) => {
+                    *self.tokens_by_player.entry(player).or_default() += tokens as u32
+                }
+                Some(Prize::Items(items)) => {
+                    for prize in items {
+                        let recipient = match prize.equip {
+                            ItemEquip::Discarded => continue,
+                            ItemEquip::None => player,
+                            ItemEquip::Equipped { player_name, .. } => player_name,
+                        };
+                        self.items_by_player
+                            .entry(recipient)
+                            .or_default()
+                            .push(prize.item);
+                    }
+                }
+                None => (),
+            }
+        }
+    }
+}
+
+/// Sums up the tokens and items handed out over the course of a game.
+pub fn economy<'a>(game: &'a Game, game_id: &'a str) -> GameEconomy<'a> {
+    let mut economy = GameEconomy::default();
+
+    for event in process_game(game, game_id) {
+        match event {
+            ParsedEventMessage::WeatherProsperity {
+                home_income,
+                away_income,
+            } => {
+                economy.add_team_tokens(&game.home_team_name, home_income);
+                economy.add_team_tokens(&game.away_team_name, away_income);
+            }
+            ParsedEventMessage::PhotoContest {
+                winning_team,
+                winning_tokens,
+                losing_team,
+                losing_tokens,
+                ..
+            } => {
+                economy.add_team_tokens(winning_team.name, winning_tokens);
+                economy.add_team_tokens(losing_team.name, losing_tokens);
+            }
+            ParsedEventMessage::WeatherConsumption(WeatherConsumptionEvents::EndContest {
+                winning_team,
+                winning_tokens,
+                winning_prize,
+                losing_team,
+                losing_tokens,
+                ..
+            }) => {
+                economy.add_team_tokens(winning_team.name, winning_tokens);
+                economy.add_team_tokens(losing_team.name, losing_tokens);
+                economy
+                    .items_by_team
+                    .entry(winning_team.name)
+                    .or_default()
+                    .push(winning_prize);
+            }
+            ParsedEventMessage::WeatherConsumption(WeatherConsumptionEvents::EndContestTie {
+                batting_team,
+                batting_team_tokens,
+                batting_team_prize,
+                pitching_team,
+                pitching_team_tokens,
+                pitching_team_prize,
+                ..
+            }) => {
+                economy.add_team_tokens(batting_team.name, batting_team_tokens);
+                economy.add_team_tokens(pitching_team.name, pitching_team_tokens);
+                economy
+                    .items_by_team
+                    .entry(batting_team.name)
+                    .or_default()
+                    .push(batting_team_prize);
+                economy
+                    .items_by_team
+                    .entry(pitching_team.name)
+                    .or_default()
+                    .push(pitching_team_prize);
+            }
+            ParsedEventMessage::WeatherSimulacrum {
+                real_team,
+                tokens_earnt,
+                ..
+            } => economy.add_team_tokens(real_team.name, tokens_earnt),
+            ParsedEventMessage::WeatherDelivery { delivery }
+            | ParsedEventMessage::WeatherSpecialDelivery { delivery } => {
+                economy.add_delivery(delivery)
+            }
+            ParsedEventMessage::WeatherShipment { deliveries } => {
+                for delivery in deliveries {
+                    economy.add_delivery(delivery)
+                }
+            }
+            ParsedEventMessage::Ball { door_prizes, .. }
+            | ParsedEventMessage::Strike { door_prizes, .. }
+            | ParsedEventMessage::Foul { door_prizes, .. }
+            | ParsedEventMessage::HitByPitch { door_prizes, .. }
+            | ParsedEventMessage::FairBall { door_prizes, .. } => {
+                economy.add_door_prizes(door_prizes)
+            }
+            _ => (),
+        }
+    }
+
+    economy
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+
+    use serde::Deserialize;
+
+    use crate::{game::economy, Game};
+
+    #[test]
+    fn prosperity() -> Result<(), Box<dyn std::error::Error>> {
+        #[derive(Deserialize)]
+        pub struct FreeCashewResponse {
+            pub items: Vec<GameEntity>,
+        }
+
+        #[derive(Deserialize)]
+        pub struct GameEntity {
+            pub data: Game,
+            pub entity_id: String,
+        }
+
+        let f = File::open("test_data/fbcag.json")?;
+        let response: FreeCashewResponse = serde_json::from_reader(f)?;
+        let game = &response
+            .items
+            .iter()
+            .find(|entity| entity.entity_id == "68782f7d206bc4d2a2003b05")
+            .unwrap()
+            .data;
+
+        let economy = economy(game, "68782f7d206bc4d2a2003b05");
+        assert_eq!(economy.tokens_by_team.get("Albuquerque Ants"), Some(&6));
+        assert_eq!(
+            economy.tokens_by_team.get("Wizard Wells Well Wizards"),
+            Some(&5)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn door_prizes() -> Result<(), Box<dyn std::error::Error>> {
+        let f = File::open("test_data/raw/game/69046c86bc5a28e68e538a02.json")?;
+        let game: Game = serde_json::from_reader(f)?;
+
+        let economy = economy(&game, "69046c86bc5a28e68e538a02");
+        let items = |player| {
+            economy.items_by_player[player]
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+
+        // Veronica Amos won the only door prize, and every item in it was equipped by someone else
+        assert_eq!(economy.items_by_player.len(), 3);
+        assert_eq!(
+            items("Ford Potpurri"),
+            ["🧢 Lurking Cursors Cap", "👟 Delicious Adhesive Sneakers"]
+        );
+        assert_eq!(
+            items("Khalid Maier"),
+            ["🥾 Secular Impactors Slugger's Cleats"]
+        );
+        assert_eq!(items("V. Lovelady"), ["👟 Dire Launches Sneakers"]);
+
+        assert!(economy.items_by_team.is_empty());
+        assert!(economy.tokens_by_player.is_empty());
+        assert!(economy.tokens_by_team.is_empty());
+        Ok(())
+    }
+}
@@ -61,6 +61,7 @@ pub(crate) struct RawEvent {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(from = "RawEvent", into = "RawEvent")]
 pub struct Event {
+    /// Use [`Inning::number`] for just the inning number.
     pub inning: Inning,
 
     pub away_score: u8,
@@ -78,6 +79,7 @@ pub struct Event {
     pub batter: EventBatterVersions<String>,
     pub pitcher: EventPitcherVersions<String>,
 
+    /// The pitch's speed, type and zone. Only present for [`EventType::Pitch`] events.
     pub pitch: Option<Pitch>,
     pitch_info_is_some: bool,
     pub home_run_distance: Option<u32>,
@@ -2,9 +2,12 @@ use std::collections::HashMap;
 
 use crate::utils::{ExpectNone, MaybeRecognizedHelper, SometimesMissingHelper};
 use crate::{
-    enums::{Day, GameStat, LeagueScale, SeasonStatus, Slot},
+    enums::{Base, Day, EventType, GameStat, HomeAway, Inning, LeagueScale, SeasonStatus, Slot},
     game::{Event, PitcherEntry, Weather},
+    parsed_event::EmojiTeam,
+    process_event,
     utils::{extra_fields_deserialize, AddedLaterResult, MaybeRecognizedResult},
+    ParsedEventMessage,
 };
 
 use serde::{Deserialize, Serialize};
@@ -161,6 +164,149 @@ pub struct Game {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl Game {
+    /// The weather the game was played in. Weather events such as falling stars or shipments only happen in the matching weather.
+    pub fn weather(&self) -> &Weather {
+        &self.weather
+    }
+
+    /// The emoji and name of the team on the given side.
+    pub fn team(&self, side: HomeAway) -> EmojiTeam<&str> {
+        match side {
+            HomeAway::Away => EmojiTeam {
+                emoji: &self.away_team_emoji,
+                name: &self.away_team_name,
+            },
+            HomeAway::Home => EmojiTeam {
+                emoji: &self.home_team_emoji,
+                name: &self.home_team_name,
+            },
+        }
+    }
+
+    /// The id of the team on the given side.
+    pub fn team_id(&self, side: HomeAway) -> &str {
+        match side {
+            HomeAway::Away => &self.away_team_id,
+            HomeAway::Home => &self.home_team_id,
+        }
+    }
+
+    /// The pitcher entry for the team on the given side. None for games from before pitcher entries were added.
+    pub fn pitcher_entry(&self, side: HomeAway) -> Option<&PitcherEntry> {
+        self.pitcher_entries.as_ref().ok()?.get(self.team_id(side))
+    }
+
+    /// The ids of the pitchers the team on the given side has used, in order.
+    /// Empty for games from before pitchers used were recorded.
+    pub fn pitchers(&self, side: HomeAway) -> &[String] {
+        self.pitchers_used
+            .as_ref()
+            .ok()
+            .and_then(|pitchers_used| pitchers_used.get(self.team_id(side)))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The team batting in the given inning. None before and after the game.
+    pub fn batting_team(&self, inning: Inning) -> Option<EmojiTeam<&str>> {
+        inning.batting_team().map(|side| self.team(side))
+    }
+
+    /// The event log split into half innings, using each event's inning rather than the
+    /// inning start and end messages, so games from before those messages existed split the same way.
+    ///
+    /// - Events before the game starts (live now, lineups, pitching matchup, play ball) are skipped.
+    /// - A walk-off half inning ends at its last play, with no inning end event.
+    /// - Events after the game ends (game over, recordkeeping, weather) are yielded last,
+    ///   as an [`Inning::AfterGame`] group.
+    pub fn innings(&self) -> impl Iterator<Item = (Inning, &[Event])> {
+        self.event_log
+            .chunk_by(|a, b| a.inning == b.inning)
+            .filter(|events| events[0].inning != Inning::BeforeGame)
+            .map(|events| (events[0].inning, events))
+    }
+
+    /// Who started each extra half inning on second base, as announced by its inning start message.
+    ///
+    /// The announcement was only added partway through season 0, and unannounced automatic runners
+    /// aren't tracked, so half innings without an announcement are left out rather than guessed at.
+    pub fn automatic_runners<'a>(&'a self, game_id: &'a str) -> Vec<(Inning, &'a str)> {
+        self.event_log
+            .iter()
+            .filter(|event| event.event == Ok(EventType::InningStart))
+            .filter_map(|event| match process_event(event, self, game_id) {
+                ParsedEventMessage::InningStart {
+                    automatic_runner: Some(runner),
+                    ..
+                } => Some((event.inning, runner)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The (home, away) score, tallied from the runs scored in the event log.
+    /// For games that haven't finished this is the score so far. None if the game hasn't started.
+    ///
+    /// Logs a warning if the tally disagrees with the game's recordkeeping event.
+    pub fn final_score(&self, game_id: &str) -> Option<(u8, u8)> {
+        let mut score = None;
+        for event in &self.event_log {
+            let parsed = process_event(event, self, game_id);
+
+            if let ParsedEventMessage::Recordkeeping {
+                winning_team,
+                winning_score,
+                losing_score,
+                ..
+            } = parsed
+            {
+                let recorded = if winning_team.name == self.home_team_name {
+                    (winning_score, losing_score)
+                } else {
+                    (losing_score, winning_score)
+                };
+                let (home, away) = score.unwrap_or_default();
+                if recorded != (home, away) {
+                    tracing::warn!(
+                        "Tallied a score of {home}-{away} but the game recorded {}-{}",
+                        recorded.0,
+                        recorded.1
+                    );
+                }
+                continue;
+            }
+
+            let Some(side) = event.inning.batting_team() else {
+                continue;
+            };
+            let (home, away) = score.get_or_insert((0, 0));
+            let runs = runs_scored(&parsed);
+            match side {
+                HomeAway::Home => *home += runs,
+                HomeAway::Away => *away += runs,
+            }
+        }
+        score
+    }
+}
+
+/// Runners who scored, plus the batter on a home run and any runner who stole home.
+fn runs_scored(event: &ParsedEventMessage<&str>) -> u8 {
+    let steals = match event {
+        ParsedEventMessage::HomeRun { .. } => return 1 + event.scoring_runners().len() as u8,
+        ParsedEventMessage::Ball { steals, .. }
+        | ParsedEventMessage::Strike { steals, .. }
+        | ParsedEventMessage::Foul { steals, .. }
+        | ParsedEventMessage::StrikeOut { steals, .. } => steals.as_slice(),
+        _ => &[],
+    };
+    let stole_home = steals
+        .iter()
+        .filter(|steal| steal.base == Base::Home && !steal.caught)
+        .count();
+    (event.scoring_runners().len() + stole_home) as u8
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuroraPhoto {
     pub luck: f64,
@@ -178,3 +324,167 @@ pub struct Bench {
     /// ids
     pub pitchers: Vec<String>,
 }
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, fs::File};
+
+    use crate::{
+        enums::{HomeAway, Inning, TopBottom},
+        utils::no_tracing_errs,
+        Game,
+    };
+
+    #[test]
+    fn automatic_runners() {
+        let f = File::open("test_data/raw/game/68f4a8d04d9c8c3ffed1a951.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let runners = game.automatic_runners("68f4a8d04d9c8c3ffed1a951");
+        // Both halves of the 10th through the 15th
+        assert_eq!(runners.len(), 12);
+        assert_eq!(
+            runners.first(),
+            Some(&(
+                Inning::DuringGame {
+                    number: 10,
+                    batting_side: TopBottom::Top
+                },
+                "Dwight Combs"
+            ))
+        );
+        assert_eq!(
+            runners.last(),
+            Some(&(
+                Inning::DuringGame {
+                    number: 15,
+                    batting_side: TopBottom::Bottom
+                },
+                "May Villalobos"
+            ))
+        );
+
+        // No extra innings
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+        assert!(game
+            .automatic_runners("68474b55452606ed6b72dbe8")
+            .is_empty());
+    }
+
+    #[test]
+    fn pitchers() {
+        let f = File::open("test_data/s2_d240_game.json").unwrap();
+        let mut game: Game = serde_json::from_reader(f).unwrap();
+
+        let home = game.pitcher_entry(HomeAway::Home).unwrap();
+        assert_eq!(home.id, "684253b5183c892d88a1033f");
+        assert_eq!(home.batters_faced, 0);
+        let away = game.pitcher_entry(HomeAway::Away).unwrap();
+        assert_eq!(away.id, "6846d77cfc876482fd5f9ab1");
+
+        // This game predates PitchersUsed
+        assert!(game.pitchers(HomeAway::Home).is_empty());
+        game.pitchers_used = Ok(HashMap::from([(
+            game.home_team_id.clone(),
+            vec!["684253b5183c892d88a1033f".to_string()],
+        )]));
+        assert_eq!(game.pitchers(HomeAway::Home), ["684253b5183c892d88a1033f"]);
+        assert!(game.pitchers(HomeAway::Away).is_empty());
+
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+        assert!(game.pitcher_entry(HomeAway::Home).is_none());
+    }
+
+    #[test]
+    fn team_by_side() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let home = game.team(HomeAway::Home);
+        assert_eq!(home.emoji, game.home_team_emoji);
+        assert_eq!(home.name, game.home_team_name);
+        assert_eq!(game.team_id(HomeAway::Home), game.home_team_id);
+
+        let away = game.team(HomeAway::Away);
+        assert_eq!(away.emoji, game.away_team_emoji);
+        assert_eq!(away.name, game.away_team_name);
+        assert_eq!(game.team_id(HomeAway::Away), game.away_team_id);
+
+        let top_of_the_first = Inning::DuringGame {
+            number: 1,
+            batting_side: TopBottom::Top,
+        };
+        assert_eq!(game.batting_team(top_of_the_first), Some(away));
+        assert_eq!(game.batting_team(Inning::BeforeGame), None);
+    }
+
+    #[test]
+    fn innings() {
+        for path in [
+            "test_data/livingston_game.json",
+            "test_data/s2_d240_game.json",
+        ] {
+            let game: Game = serde_json::from_reader(File::open(path).unwrap()).unwrap();
+
+            let innings = game.innings().collect::<Vec<_>>();
+            let pre_game = game
+                .event_log
+                .iter()
+                .take_while(|event| event.inning == Inning::BeforeGame)
+                .count();
+            let concatenated = innings
+                .iter()
+                .flat_map(|(_, events)| events.iter())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                concatenated,
+                game.event_log[pre_game..].iter().collect::<Vec<_>>()
+            );
+
+            let (first_inning, _) = innings[0];
+            assert_eq!(
+                first_inning,
+                Inning::DuringGame {
+                    number: 1,
+                    batting_side: TopBottom::Top
+                }
+            );
+            let half_innings = innings
+                .iter()
+                .filter(|(inning, _)| matches!(inning, Inning::DuringGame { .. }))
+                .count();
+            assert!(half_innings >= 17);
+            assert!(matches!(
+                innings.last(),
+                Some((Inning::AfterGame { .. }, _))
+            ));
+        }
+    }
+
+    #[test]
+    fn final_score() {
+        let f = File::open("test_data/s2_d240_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let last_event = game.event_log.last().unwrap();
+        let _guard = no_tracing_errs();
+        assert_eq!(
+            game.final_score("s2_d240_game"),
+            Some((last_event.home_score, last_event.away_score))
+        );
+    }
+
+    #[test]
+    fn weather() {
+        let f = File::open("test_data/raw/game/68f051cdd1004b0ad6d643e5.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let weather = game.weather();
+        assert_eq!(weather.emoji, "🌠");
+        assert_eq!(weather.name, "Starfall");
+        assert_eq!(weather.tooltip, "A chance of Falling Stars.");
+        assert!(weather.extra_fields.is_empty());
+    }
+}
@@ -14,12 +14,17 @@ use crate::utils::{
     MaybeRecognizedHelper, MaybeRecognizedResult, ZeroOrF64,
 };
 
+pub mod analytics;
+pub(crate) mod economy;
 pub(crate) mod event;
 pub(crate) mod game;
+pub(crate) mod names;
 pub(crate) mod weather;
 
+pub use economy::{economy, GameEconomy};
 pub use event::Event;
 pub use game::Game;
+pub use names::resolve_names;
 pub use weather::Weather;
 
 #[serde_as]
@@ -358,21 +363,28 @@ pub struct Pitch {
     #[serde_as(as = "MaybeRecognizedHelper<_>")]
     pub pitch_type: MaybeRecognizedResult<PitchType>,
     pub zone: u8,
+    /// Whether the speed was written without a decimal place, e.g. "95 MPH" rather than "95.0 MPH"
+    #[serde(default)]
+    pub whole_number_speed: bool,
 }
 impl Pitch {
     pub fn new(pitch_info: String, zone: u8) -> Self {
         let mut iter = pitch_info.split(" MPH ");
-        let pitch_speed = iter.next().unwrap().parse().unwrap();
+        let speed = iter.next().unwrap();
         let pitch_type = maybe_recognized_from_str(iter.next().unwrap());
         Self {
-            speed: pitch_speed,
+            speed: speed.parse().unwrap(),
             pitch_type,
             zone,
+            whole_number_speed: !speed.contains('.'),
         }
     }
     pub fn unparse(self) -> (String, u8) {
-        let speed = format!("{:.1}", self.speed);
-        // let speed = speed.strip_suffix(".0").unwrap_or(speed.as_str());
+        let speed = if self.whole_number_speed {
+            format!("{:.0}", self.speed)
+        } else {
+            format!("{:.1}", self.speed)
+        };
         let pitch_info = format!(
             "{speed} MPH {}",
             maybe_recognized_to_string(&self.pitch_type)
@@ -403,11 +415,13 @@ where
 
 #[cfg(test)]
 mod test {
-    use std::path::Path;
+    use std::{fs::File, path::Path};
 
     use tracing_test::traced_test;
 
     use crate::{
+        enums::{EventType, PitchType},
+        game::Pitch,
         utils::{assert_round_trip, no_tracing_errs},
         Game,
     };
@@ -421,6 +435,46 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn pitch_round_trip() {
+        let whole = Pitch::new("95 MPH Fastball".to_string(), 5);
+        assert_eq!(whole.speed, 95.0);
+        assert_eq!(whole.pitch_type, Ok(PitchType::Fastball));
+        assert_eq!(whole.unparse(), ("95 MPH Fastball".to_string(), 5));
+
+        let decimal = Pitch::new("95.3 MPH Slider".to_string(), 12);
+        assert_eq!(decimal.pitch_type, Ok(PitchType::Slider));
+        assert_eq!(decimal.unparse(), ("95.3 MPH Slider".to_string(), 12));
+
+        let zero_decimal = Pitch::new("95.0 MPH Sinker".to_string(), 1);
+        assert_eq!(zero_decimal.unparse(), ("95.0 MPH Sinker".to_string(), 1));
+    }
+
+    #[test]
+    fn event_pitches() -> Result<(), Box<dyn std::error::Error>> {
+        let game: Game = serde_json::from_reader(File::open("test_data/s2_d240_game.json")?)?;
+
+        let pitches = game
+            .event_log
+            .iter()
+            .filter_map(|event| Some((event.inning.number()?, event.pitch.as_ref()?)))
+            .collect::<Vec<_>>();
+        assert_eq!(pitches.len(), 290);
+        assert_eq!(
+            pitches[0].1,
+            &Pitch::new("72.0 MPH Curveball".to_string(), 14)
+        );
+        assert!(pitches
+            .iter()
+            .all(|(_, pitch)| (1..=14).contains(&pitch.zone) && pitch.pitch_type.is_ok()));
+
+        assert!(game
+            .event_log
+            .iter()
+            .all(|event| event.pitch.is_some() == (event.event == Ok(EventType::Pitch))));
+        Ok(())
+    }
+
     #[test]
     #[traced_test]
     fn extra_fields() -> Result<(), Box<dyn std::error::Error>> {
@@ -0,0 +1,91 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use crate::{
+    process_game,
+    team::{Team, TeamPlayer, TeamPlayerCollection},
+    Game,
+};
+
+/// Maps the name of every rostered player mentioned in the game's events to their player id.
+///
+/// A name maps to None when two different players on the rosters share it, since the event text can't tell them apart.
+/// Mentions come from the players in each parsed event, so players who aren't on either roster
+/// (e.g. players who have since been released) won't be included.
+pub fn resolve_names(
+    game: &Game,
+    game_id: &str,
+    home: &Team,
+    away: &Team,
+) -> HashMap<String, Option<String>> {
+    let parsed = process_game(game, game_id);
+    let mentioned: HashSet<&str> = parsed
+        .iter()
+        .flat_map(|event| event.mentioned_players())
+        .copied()
+        .collect();
+
+    let mut roster: HashMap<String, Option<String>> = HashMap::new();
+
+    for player in team_players(home).chain(team_players(away)) {
+        let name = format!("{} {}", player.first_name, player.last_name);
+        match roster.entry(name) {
+            Entry::Vacant(entry) => {
+                entry.insert(Some(player.player_id.clone()));
+            }
+            Entry::Occupied(mut entry) => {
+                if entry.get().as_ref() != Some(&player.player_id) {
+                    entry.insert(None);
+                }
+            }
+        }
+    }
+
+    roster
+        .into_iter()
+        .filter(|(name, _)| mentioned.contains(name.as_str()))
+        .collect()
+}
+
+fn team_players(team: &Team) -> Box<dyn Iterator<Item = &TeamPlayer> + '_> {
+    match &team.players {
+        TeamPlayerCollection::Vec(players) => Box::new(players.iter()),
+        TeamPlayerCollection::Map(players) => Box::new(players.values()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+
+    use crate::{game::resolve_names, team::Team, Game};
+
+    #[test]
+    fn s2_d240_names() {
+        let f = File::open("test_data/s2_d240_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+        let f = File::open("test_data/s2_team.json").unwrap();
+        let away: Team = serde_json::from_reader(f).unwrap();
+
+        // A home player whose name is only part of an away player's name isn't mentioned
+        let mut partial_name = super::team_players(&away).next().unwrap().clone();
+        partial_name.last_name.pop();
+        partial_name.player_id = "partial_name".to_string();
+        let mut home = away.clone();
+        home.players = vec![partial_name.clone()].into();
+
+        let names = resolve_names(&game, "s2_d240_game", &home, &away);
+        assert!(!names.is_empty());
+        assert!(!names.contains_key(&format!(
+            "{} {}",
+            partial_name.first_name, partial_name.last_name
+        )));
+
+        for (name, id) in names {
+            let id = id.unwrap_or_else(|| panic!("{name} should not be ambiguous"));
+            let player = super::team_players(&away)
+                .find(|player| player.player_id == id)
+                .unwrap();
+            assert_eq!(name, format!("{} {}", player.first_name, player.last_name));
+        }
+    }
+}
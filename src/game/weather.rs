@@ -2,11 +2,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::utils::extra_fields_deserialize;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Weather {
     pub emoji: String,
+    /// e.g. "Starfall". mmolb doesn't give weathers an id, and new weathers are added often, so this is kept as a string.
     pub name: String,
+    /// A description of the weather's effects, e.g. "A chance of Falling Stars."
     pub tooltip: String,
 
     #[serde(flatten, deserialize_with = "extra_fields_deserialize")]
@@ -3,6 +3,7 @@
 pub(crate) mod time;
 pub(crate) mod utils;
 
+pub mod binary_serde;
 pub mod enums;
 pub mod feed_event;
 pub mod game;
@@ -16,16 +17,37 @@ pub mod team_feed;
 
 pub use game::Game;
 pub use parsed_event::ParsedEventMessage;
-pub use parsing::{process_event, process_game};
+pub use time::Breakpoints;
+#[cfg(feature = "rayon")]
+pub use parsing::process_games_par;
+pub use parsing::{
+    process_event, process_game, process_game_checked, process_games, process_player_feed,
+    process_team_feed, GameParser, ParsedGameCache,
+};
 
 pub use utils::{
-    AddedLater, AddedLaterResult, EmptyArrayOr, MaybeRecognizedResult, NotRecognized, RemovedLater,
-    RemovedLaterResult,
+    AddedLater, AddedLaterResult, EmptyArrayOr, MaybeRecognizedExt, MaybeRecognizedResult,
+    NotRecognized, RemovedLater, RemovedLaterResult,
 };
 
 use crate::{enums::Day, parsed_event::EmojiTeam, time::Time};
 
-#[derive(Clone, Copy)]
+/// Everything needed to unparse an event besides its index. Usually built from a [`Game`],
+/// but it can be built directly when the game itself isn't available (e.g. events loaded from a database).
+///
+/// ```
+/// use mmolb_parsing::{enums::{Day, TopBottom}, parsed_event::EmojiTeam, ParsedEventMessage, UnparsingContext};
+///
+/// let context = UnparsingContext {
+///     season: 3,
+///     day: Some(Day::Day(10)),
+///     away_emoji_team: EmojiTeam { emoji: "🛸", name: "Roswell Weather Balloons" },
+///     home_emoji_team: EmojiTeam { emoji: "🧹", name: "Boston Street Sweepers" },
+/// };
+/// let event: ParsedEventMessage<&str> = ParsedEventMessage::InningEnd { number: 1, side: TopBottom::Top };
+/// assert_eq!(event.unparse(context, Some(20)), "End of the top of the 1st.");
+/// ```
+#[derive(Debug, Clone, Copy)]
 pub struct UnparsingContext<'a> {
     pub season: u32,
     pub day: Option<Day>,
@@ -64,9 +86,10 @@ impl<'a> UnparsingContext<'a> {
     pub(crate) fn before(&self, event_index: Option<u16>, time: impl Into<Time>) -> bool {
         time.into().before(self.season, self.day, event_index)
     }
+}
 
-    /// Whether this event is after the given time
-    pub(crate) fn after(&self, event_index: Option<u16>, time: impl Into<Time>) -> bool {
-        time.into().after(self.season, self.day, event_index)
-    }
+/// Implemented by parsed game events and parsed feed events, so that any of them can be filtered by player.
+pub trait MentionsPlayer {
+    /// Whether a player with this name appears anywhere in the event. Team names don't count.
+    fn mentions(&self, name: &str) -> bool;
 }
@@ -7,5 +7,5 @@ pub(crate) mod parse_player_feed_event;
 pub(crate) mod parse_team_feed_event;
 pub(crate) mod shared;
 
-pub use parse::parse_event;
+pub use parse::{parse_event, parse_event_str};
 pub use shared::ParsingContext;
@@ -19,6 +19,7 @@ use nom::{
 };
 use nom::{bytes::complete::take_till, character::complete::u32, error::ErrorKind};
 use nom::{character::complete::space0, sequence::pair};
+use nom_language::error::VerboseErrorKind;
 use phf::phf_map;
 use std::str::FromStr;
 
@@ -85,56 +86,122 @@ pub fn parse_event<'parse, 'output: 'parse>(
         }
     };
 
+    parse_event_message(
+        *event_type,
+        &event.message,
+        PitchingTeam::Known(event.inning.pitching_team()),
+        parsing_context,
+    )
+}
+
+/// Parses a message without the [`Event`] it came from, e.g. a single line pulled out of a game.
+/// Uses the same dispatch as [`parse_event`], except that no known bug overrides apply (there's no event index)
+/// and the pitching team isn't known, so inning start and mound visit messages accept either team's emoji.
+///
+/// ```
+/// use mmolb_parsing::{enums::EventType, nom_parsing::{parse_event_str, ParsingContext}, parsed_event::EmojiTeam, ParsedEventMessage};
+///
+/// let context = ParsingContext::for_test(
+///     EmojiTeam { emoji: "🧹", name: "Boston Street Sweepers" },
+///     EmojiTeam { emoji: "🛸", name: "Roswell Weather Balloons" },
+///     3,
+///     None,
+/// );
+/// assert_eq!(parse_event_str(EventType::PlayBall, "\"PLAY BALL.\"", &context), ParsedEventMessage::PlayBall);
+/// ```
+pub fn parse_event_str<'parse, 'output: 'parse>(
+    event_type: EventType,
+    message: &'output str,
+    parsing_context: &ParsingContext<'parse>,
+) -> ParsedEventMessage<&'output str> {
+    parse_event_message(event_type, message, PitchingTeam::Unknown, parsing_context)
+}
+
+/// Which team is pitching, for the parsers that check the pitching team's emoji.
+#[derive(Clone, Copy)]
+enum PitchingTeam {
+    /// Taken from the event's inning. None outside of an inning, where no pitching team emoji can match.
+    Known(Option<HomeAway>),
+    /// There's no event to take it from, so either team's emoji is accepted.
+    Unknown,
+}
+
+impl PitchingTeam {
+    fn emoji<'parse, 'output: 'parse>(
+        self,
+        parsing_context: &'parse ParsingContext<'parse>,
+    ) -> impl MyParser<'output, &'output str> + 'parse {
+        move |input| match self {
+            PitchingTeam::Known(Some(side)) => team_emoji(side, parsing_context).parse(input),
+            PitchingTeam::Known(None) => fail().parse(input),
+            PitchingTeam::Unknown => either_team_emoji(parsing_context).parse(input),
+        }
+    }
+}
+
+fn parse_event_message<'parse, 'output: 'parse>(
+    event_type: EventType,
+    message: &'output str,
+    pitching_team: PitchingTeam,
+    parsing_context: &ParsingContext<'parse>,
+) -> ParsedEventMessage<&'output str> {
     match event_type {
-        EventType::PitchingMatchup => pitching_matchup(parsing_context).parse(&event.message),
-        EventType::MoundVisit => mound_visit(event, parsing_context).parse(&event.message),
-        EventType::GameOver => game_over().parse(&event.message),
-        EventType::Field => field(parsing_context).parse(&event.message),
-        EventType::HomeLineup => lineup(HomeAway::Home).parse(&event.message),
-        EventType::Recordkeeping => record_keeping().parse(&event.message),
-        EventType::LiveNow => live_now(parsing_context).parse(&event.message),
-        EventType::InningStart => inning_start(event, parsing_context).parse(&event.message),
-        EventType::Pitch => pitch(parsing_context).parse(&event.message),
-        EventType::AwayLineup => lineup(HomeAway::Away).parse(&event.message),
-        EventType::InningEnd => inning_end().parse(&event.message),
-        EventType::PlayBall => play_ball().parse(&event.message),
-        EventType::NowBatting => now_batting().parse(&event.message),
-        EventType::WeatherDelivery => weather_delivery(parsing_context).parse(&event.message),
-        EventType::FallingStar => falling_star().parse(&event.message),
-        EventType::Weather => weather().parse(&event.message),
+        EventType::PitchingMatchup => pitching_matchup(parsing_context).parse(message),
+        EventType::MoundVisit => mound_visit(pitching_team, parsing_context).parse(message),
+        EventType::GameOver => game_over().parse(message),
+        EventType::Field => field(parsing_context).parse(message),
+        EventType::HomeLineup => lineup(HomeAway::Home).parse(message),
+        EventType::Recordkeeping => record_keeping().parse(message),
+        EventType::LiveNow => live_now(parsing_context).parse(message),
+        EventType::InningStart => inning_start(pitching_team, parsing_context).parse(message),
+        EventType::Pitch => pitch(parsing_context).parse(message),
+        EventType::AwayLineup => lineup(HomeAway::Away).parse(message),
+        EventType::InningEnd => inning_end().parse(message),
+        EventType::PlayBall => play_ball().parse(message),
+        EventType::NowBatting => now_batting().parse(message),
+        EventType::WeatherDelivery => weather_delivery(parsing_context).parse(message),
+        EventType::FallingStar => falling_star().parse(message),
+        EventType::Weather => weather().parse(message),
+        // Home Run Challenge messages aren't parsed yet: there are no HRC games in test_data,
+        // so their message formats haven't been pinned down.
         EventType::HrcLiveNow
         | EventType::HrcPitchingMatchup
         | EventType::HrcBattingMatchup
         | EventType::HrcPlayBall
-        | EventType::HrcChange => fail().parse(event.message.as_str()),
-        EventType::WeatherShipment => weather_shipment(parsing_context).parse(&event.message),
-        EventType::WeatherSpecialDelivery => {
-            special_delivery(parsing_context).parse(&event.message)
-        }
-        EventType::WeatherProsperity => weather_prosperity(parsing_context).parse(&event.message),
-        EventType::Balk => balk().parse(&event.message),
-        EventType::PhotoContest => photo_contest(parsing_context).parse(event.message.as_str()),
-        EventType::Party => party(parsing_context).parse(event.message.as_str()),
-        EventType::WeatherReflection => weather_reflection(parsing_context).parse(&event.message),
-        EventType::WeatherWither => weather_wither(parsing_context).parse(&event.message),
-        EventType::LinealBeltTransfer => lineal_belt(parsing_context).parse(event.message.as_str()),
-        EventType::WeatherConsumption => {
-            weather_consumption(parsing_context).parse(event.message.as_str())
-        }
-        EventType::WeatherSimulacrum => weather_simulacrum().parse(event.message.as_str()),
+        | EventType::HrcChange => fail().parse(message),
+        EventType::WeatherShipment => weather_shipment(parsing_context).parse(message),
+        EventType::WeatherSpecialDelivery => special_delivery(parsing_context).parse(message),
+        EventType::WeatherProsperity => weather_prosperity(parsing_context).parse(message),
+        EventType::Balk => balk().parse(message),
+        EventType::PhotoContest => photo_contest(parsing_context).parse(message),
+        EventType::Party => party(parsing_context).parse(message),
+        EventType::WeatherReflection => weather_reflection(parsing_context).parse(message),
+        EventType::WeatherWither => weather_wither(parsing_context).parse(message),
+        EventType::LinealBeltTransfer => lineal_belt(parsing_context).parse(message),
+        EventType::WeatherConsumption => weather_consumption(parsing_context).parse(message),
+        EventType::WeatherSimulacrum => weather_simulacrum().parse(message),
     }
     .finish()
     .map(|(_, o)| o)
     .unwrap_or_else(move |e| {
         let error = GameEventParseError::FailedParsingMessage {
-            event_type: *event_type,
-            message: event.message.clone(),
+            event_type,
+            message: message.to_string(),
+            offset: e
+                .errors
+                .first()
+                .map(|(input, _)| message.len() - input.len()),
+            context: e
+                .errors
+                .iter()
+                .filter_map(|(_, kind)| match kind {
+                    VerboseErrorKind::Context(context) => Some(context.to_string()),
+                    _ => None,
+                })
+                .collect(),
         };
         tracing::error!("Parse error: {e}");
-        ParsedEventMessage::ParseError {
-            error,
-            message: &event.message,
-        }
+        ParsedEventMessage::ParseError { error, message }
     })
 }
 fn photo_contest<'parse, 'output: 'parse>(
@@ -1161,14 +1228,11 @@ fn lineup<'output>(side: HomeAway) -> impl MyParser<'output, ParsedEventMessage<
 }
 
 fn inning_start<'parse, 'output: 'parse>(
-    event: &'output Event,
+    pitching_team: PitchingTeam,
     parsing_context: &'parse ParsingContext<'parse>,
 ) -> impl MyParser<'output, ParsedEventMessage<&'output str>> + 'parse {
-    let parser = |input: &'output str| {
-        let pitching_team_emoji = |input| match event.inning.pitching_team() {
-            Some(side) => team_emoji(side, parsing_context).parse(input),
-            None => fail().parse(input),
-        };
+    let parser = move |input: &'output str| {
+        let pitching_team_emoji = |input| pitching_team.emoji(parsing_context).parse(input);
 
         let keep_pitcher = sentence(separated_pair(
             pitching_team_emoji,
@@ -1257,7 +1321,7 @@ fn inning_start<'parse, 'output: 'parse>(
 }
 
 fn mound_visit<'parse, 'output: 'parse>(
-    event: &'output Event,
+    pitching_team: PitchingTeam,
     parsing_context: &'parse ParsingContext<'parse>,
 ) -> impl MyParser<'output, ParsedEventMessage<&'output str>> + 'parse {
     let leaves_player = |i| {
@@ -1272,10 +1336,7 @@ fn mound_visit<'parse, 'output: 'parse>(
         }
     };
 
-    let team_emoji = |input| match event.inning.pitching_team() {
-        Some(side) => team_emoji(side, parsing_context).parse(input),
-        None => fail().parse(input),
-    };
+    let team_emoji = move |input| pitching_team.emoji(parsing_context).parse(input);
 
     let mound_visit_options = alt((
         preceded(
@@ -1746,13 +1807,20 @@ fn weather_simulacrum<'parse, 'output: 'parse>(
 
 #[cfg(test)]
 mod test {
+    use std::fs::File;
+
     use nom::{Finish, Parser};
 
     use crate::{
-        enums::{Base, BaseNameVariant, Day, Distance, FairBallType, Place},
-        nom_parsing::{shared::verify_name, ParsingContext},
+        enums::{Base, BaseNameVariant, Day, Distance, EventType, FairBallType, HomeAway, Place},
+        nom_parsing::{
+            parse::{parse_event_message, PitchingTeam},
+            parse_event, parse_event_str,
+            shared::verify_name,
+            ParsingContext,
+        },
         parsed_event::{EmojiTeam, PlacedPlayer, RunnerAdvance, RunnerOut},
-        ParsedEventMessage, UnparsingContext,
+        Game, ParsedEventMessage, UnparsingContext,
     };
 
     #[test]
@@ -1986,4 +2054,112 @@ mod test {
 
         assert_eq!(text, event.unparse(unparsing_context, Some(14)));
     }
+
+    #[test]
+    fn live_now_for_test_context() {
+        let crabs = EmojiTeam {
+            emoji: "🦀",
+            name: "Crabs",
+        };
+        let lobsters = EmojiTeam {
+            emoji: "🦞",
+            name: "Lobsters",
+        };
+        let parsing_context = ParsingContext::for_test(crabs, lobsters, 2, Some(Day::Day(10)));
+
+        assert_eq!(
+            super::live_now(&parsing_context).parse("🦞 Lobsters @ 🦀 Crabs"),
+            Ok((
+                "",
+                ParsedEventMessage::LiveNow {
+                    away_team: lobsters,
+                    home_team: crabs,
+                    stadium: None,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_event_str_without_game() {
+        let sweepers = EmojiTeam {
+            emoji: "🧹",
+            name: "Boston Street Sweepers",
+        };
+        let balloons = EmojiTeam {
+            emoji: "🛸",
+            name: "Roswell Weather Balloons",
+        };
+        let parsing_context = ParsingContext::for_test(sweepers, balloons, 3, Some(Day::Day(10)));
+
+        assert_eq!(
+            parse_event_str(EventType::PlayBall, "\"PLAY BALL.\"", &parsing_context),
+            ParsedEventMessage::PlayBall
+        );
+        assert!(matches!(
+            parse_event_str(EventType::Pitch, " Ball. 1-0.", &parsing_context),
+            ParsedEventMessage::Ball { count: (1, 0), .. }
+        ));
+        assert!(matches!(
+            parse_event_str(
+                EventType::InningStart,
+                "Start of the top of the 1st. 🛸 Roswell Weather Balloons batting. 🧹 Elena Karapetyan pitching.",
+                &parsing_context
+            ),
+            ParsedEventMessage::InningStart { number: 1, .. }
+        ));
+        assert!(matches!(
+            parse_event_str(EventType::PlayBall, "PLAY BALL", &parsing_context),
+            ParsedEventMessage::ParseError { .. }
+        ));
+    }
+
+    #[test]
+    fn pitching_emoji_needs_a_pitching_team() {
+        let sweepers = EmojiTeam {
+            emoji: "🧹",
+            name: "Boston Street Sweepers",
+        };
+        let balloons = EmojiTeam {
+            emoji: "🛸",
+            name: "Roswell Weather Balloons",
+        };
+        let parsing_context = ParsingContext::for_test(sweepers, balloons, 3, Some(Day::Day(10)));
+        let message = "Start of the top of the 1st. 🛸 Roswell Weather Balloons batting. 🧹 Elena Karapetyan pitching.";
+
+        // An event outside of an inning has no pitching team, so the parse_event path can't match it
+        assert!(matches!(
+            parse_event_message(
+                EventType::InningStart,
+                message,
+                PitchingTeam::Known(None),
+                &parsing_context
+            ),
+            ParsedEventMessage::ParseError { .. }
+        ));
+        assert!(matches!(
+            parse_event_message(
+                EventType::InningStart,
+                message,
+                PitchingTeam::Known(Some(HomeAway::Home)),
+                &parsing_context
+            ),
+            ParsedEventMessage::InningStart { number: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn parse_event_str_matches_parse_event() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        for event in &game.event_log {
+            let parsing_context = ParsingContext::new("", &game, event.index);
+            let event_type = *event.event.as_ref().unwrap();
+            assert_eq!(
+                parse_event_str(event_type, &event.message, &parsing_context),
+                parse_event(event, &parsing_context)
+            );
+        }
+    }
 }
@@ -43,6 +43,7 @@ pub fn parse_player_feed_event(event: &FeedEvent) -> ParsedPlayerFeedEventText<&
     let event_type = match &event.event_type {
         Ok(event_type) => event_type,
         Err(e) => {
+            tracing::error!("Feed event type {e} not recognized: {}", event.text);
             let error = FeedEventParseError::EventTypeNotRecognized(e.clone());
             return ParsedPlayerFeedEventText::ParseError {
                 error,
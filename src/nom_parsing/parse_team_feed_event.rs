@@ -50,6 +50,7 @@ pub fn parse_team_feed_event(event: &FeedEvent) -> ParsedTeamFeedEventText<&str>
     let event_type = match &event.event_type {
         Ok(event_type) => event_type,
         Err(e) => {
+            tracing::error!("Feed event type {e} not recognized: {}", event.text);
             let error = FeedEventParseError::EventTypeNotRecognized(e.clone());
             return ParsedTeamFeedEventText::ParseError {
                 error,
@@ -257,6 +258,8 @@ fn release<'output>(_event: &'output FeedEvent) -> impl TeamFeedEventParser<'out
     )
 }
 
+// Seasonal durability loss is only posted to player feeds, so unlike the player feed's
+// season parser there's no arm for it here.
 fn season<'output>(_event: &'output FeedEvent) -> impl TeamFeedEventParser<'output> {
     context("Season Feed Event", alt((retirement(false),)))
 }
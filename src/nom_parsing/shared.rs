@@ -5,7 +5,7 @@ use nom::number::double;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take, take_till, take_until, take_until1, take_while},
-    character::complete::{one_of, space0, u16, u8},
+    character::complete::{alphanumeric1, one_of, space0, u16, u8},
     combinator::{all_consuming, fail, opt, recognize, rest, value, verify},
     error::{ErrorKind, ParseError},
     multi::{count, many0, many1, separated_list1},
@@ -81,6 +81,25 @@ impl<'parse> ParsingContext<'parse> {
         }
     }
 
+    /// A context for parsing messages outside of a game, e.g. when testing a parser on a single message.
+    /// There's no game id, so no known bug overrides apply, and no event log or event index.
+    pub fn for_test(
+        home_emoji_team: EmojiTeam<&'parse str>,
+        away_emoji_team: EmojiTeam<&'parse str>,
+        season: u32,
+        day: Option<Day>,
+    ) -> Self {
+        Self {
+            game_id: "",
+            event_log: &[],
+            event_index: None,
+            home_emoji_team,
+            away_emoji_team,
+            season,
+            day,
+        }
+    }
+
     /// Whether this event is before the given time
     pub(crate) fn before(&self, time: impl Into<Time>) -> bool {
         time.into().before(self.season, self.day, self.event_index)
@@ -482,7 +501,7 @@ pub(super) fn parse_until_exclamation_point_eof(input: &str) -> IResult<'_, &str
     Ok((input, replacement_name))
 }
 
-pub(super) fn placed_player_eof(input: &str) -> IResult<'_, &str, PlacedPlayer<&str>> {
+pub(crate) fn placed_player_eof(input: &str) -> IResult<'_, &str, PlacedPlayer<&str>> {
     separated_pair(try_from_word, tag(" "), verify_name)
         .map(|(place, name)| PlacedPlayer { name, place })
         .parse(input)
@@ -553,28 +572,39 @@ pub(super) fn emoji_team_eof_maybe_no_space(input: &str) -> IResult<'_, &str, Em
 }
 
 pub(super) fn batter_stat(input: &str) -> IResult<'_, &str, BatterStat> {
-    alt((
-        terminated(u8, tag(" 1B")).map(BatterStat::FirstBases),
-        terminated(u8, tag(" 2B")).map(BatterStat::SecondBases),
-        terminated(u8, tag(" 3B")).map(BatterStat::ThirdBases),
-        terminated(u8, tag(" LO")).map(BatterStat::LineOuts),
-        terminated(u8, tag(" SO")).map(BatterStat::StrikeOuts),
-        terminated(u8, tag(" FO")).map(BatterStat::ForceOuts),
-        terminated(u8, tag(" HR")).map(BatterStat::HomeRuns),
-        terminated(u8, tag(" FC")).map(BatterStat::FieldersChoices),
-        terminated(u8, tag(" SF")).map(BatterStat::SacrificeFlies),
-        terminated(u8, tag(" F")).map(BatterStat::Fouls),
-        terminated(u8, tag(" BB")).map(BatterStat::BaseOnBalls),
-        terminated(u8, tag(" FC")).map(BatterStat::SacrificeFlies),
-        terminated(u8, tag(" HBP")).map(BatterStat::HitByPitchs),
-        terminated(u8, tag(" GIDP")).map(BatterStat::GroundIntoDoublePlays),
-        terminated(u8, tag(" CDP")).map(BatterStat::CaughtDoublePlays),
-        terminated(u8, tag(" PO")).map(BatterStat::PopOuts),
-        terminated(u8, tag(" GO")).map(BatterStat::GroundOuts),
-        separated_pair(u8, tag(" for "), u8)
-            .map(|(hits, at_bats)| BatterStat::HitsForAtBats { hits, at_bats }),
-    ))
-    .parse(input)
+    // Matching on the suffix after the count, rather than an alt of every "{count} {suffix}",
+    // avoids building an error for every stat this isn't. Now batting events are very common.
+    let (input, count) = terminated(u8, tag(" ")).parse(input)?;
+    if let Some(input) = input.strip_prefix("for ") {
+        return u8
+            .map(|at_bats| BatterStat::HitsForAtBats {
+                hits: count,
+                at_bats,
+            })
+            .parse(input);
+    }
+
+    let (rest, suffix) = alphanumeric1(input)?;
+    let stat = match suffix {
+        "1B" => BatterStat::FirstBases(count),
+        "2B" => BatterStat::SecondBases(count),
+        "3B" => BatterStat::ThirdBases(count),
+        "LO" => BatterStat::LineOuts(count),
+        "SO" => BatterStat::StrikeOuts(count),
+        "FO" => BatterStat::ForceOuts(count),
+        "HR" => BatterStat::HomeRuns(count),
+        "FC" => BatterStat::FieldersChoices(count),
+        "SF" => BatterStat::SacrificeFlies(count),
+        "F" => BatterStat::Fouls(count),
+        "BB" => BatterStat::BaseOnBalls(count),
+        "HBP" => BatterStat::HitByPitchs(count),
+        "GIDP" => BatterStat::GroundIntoDoublePlays(count),
+        "CDP" => BatterStat::CaughtDoublePlays(count),
+        "PO" => BatterStat::PopOuts(count),
+        "GO" => BatterStat::GroundOuts(count),
+        _ => return fail().parse(input),
+    };
+    Ok((rest, stat))
 }
 
 /// Doesn't include the brackets. e.g. "1st PA of game" or "1 for 2, 1 1B, 1 FO"
@@ -1178,6 +1208,7 @@ pub(super) fn efflorescences(input: &str) -> IResult<'_, &str, Vec<Efflorescence
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedEventParty<S> {
     pub player_name: S,
     pub amount_gained: u8,
@@ -1235,21 +1266,26 @@ pub(super) fn feed_event_party(input: &str) -> IResult<'_, &str, FeedEventParty<
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FeedEventDoorPrize<S> {
     pub player_name: S,
     pub prize: Prize<S>,
 }
 
+impl<S> FeedEventDoorPrize<S> {
+    /// The winner, followed by anyone who equipped an item from the prize.
+    pub fn player_names(&self) -> impl Iterator<Item = &S> {
+        std::iter::once(&self.player_name).chain(self.prize.equipping_players())
+    }
+}
+
 impl<S: Display> Display for FeedEventDoorPrize<S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let punct = match &self.prize {
-            Prize::Items(i) if i.iter().any(|prize| !prize.equip.is_none()) => "!",
-            _ => ":",
-        };
         write!(
             f,
-            "{} won a Door Prize{punct} {}.",
+            "{} won a Door Prize{} {}.",
             self.player_name,
+            self.prize.door_prize_punctuation(),
             self.prize.unparse()
         )
     }
@@ -1342,6 +1378,16 @@ pub(super) fn feed_event_contained(input: &str) -> IResult<'_, &str, (&str, &str
     Ok((input, (contained_player_name, container_player_name)))
 }
 
+/// Shared by the team and player feeds, which use the same text for containment.
+pub(crate) fn unparse_feed_event_contained<S: Display>(
+    contained_player_name: S,
+    container_player_name: S,
+) -> String {
+    format!(
+        "{contained_player_name} was contained by {container_player_name} during the 🥀 Wither."
+    )
+}
+
 pub(super) fn feed_event_efflorescence_growth(
     input: &str,
 ) -> IResult<'_, &str, (&str, [GrowAttributeChange; 2])> {
@@ -1397,6 +1443,7 @@ fn full_slot(input: &str) -> IResult<'_, &str, FullSlot> {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PositionSwap<S> {
     first_player_name: S,
     first_player_new_slot: FullSlot,
@@ -1404,6 +1451,12 @@ pub struct PositionSwap<S> {
     second_player_new_slot: FullSlot,
 }
 
+impl<S> PositionSwap<S> {
+    pub fn player_names(&self) -> [&S; 2] {
+        [&self.first_player_name, &self.second_player_name]
+    }
+}
+
 impl<S: Display> Display for PositionSwap<S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let PositionSwap {
@@ -1452,6 +1505,8 @@ pub(super) fn player_positions_swapped(input: &str) -> IResult<'_, &str, Positio
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GrowAttributeChange {
     pub attribute: Attribute,
     pub amount: f64,
@@ -1464,6 +1519,7 @@ impl Display for GrowAttributeChange {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum GainedImmovable {
     No,
     Yes,
@@ -1472,12 +1528,19 @@ pub enum GainedImmovable {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Grow<S> {
     player_name: S,
     attribute_changes: [GrowAttributeChange; 3],
     immovable_granted: GainedImmovable,
 }
 
+impl<S> Grow<S> {
+    pub fn player_name(&self) -> &S {
+        &self.player_name
+    }
+}
+
 impl<S: Display> Display for Grow<S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}'s Corruption grew: ", self.player_name)?;
@@ -1726,19 +1789,6 @@ pub fn emoji_food(input: &str) -> IResult<'_, &str, EmojiFood<&str>> {
     Ok((input, EmojiFood { food_emoji, food }))
 }
 
-pub(super) fn team_emoji_player_eof<'parse, 'output>(
-    side: HomeAway,
-    parsing_context: &'parse ParsingContext<'parse>,
-) -> impl MyParser<'output, EmojiPlayer<&'output str>> + 'parse {
-    move |input| {
-        let (input, (emoji, name)) =
-            separated_pair(team_emoji(side, parsing_context), tag(" "), verify_name)
-                .parse(input)?;
-
-        Ok((input, EmojiPlayer { emoji, name }))
-    }
-}
-
 pub(super) fn either_team_emoji_player_eof<'parse, 'output>(
     parsing_context: &'parse ParsingContext<'parse>,
 ) -> impl MyParser<'output, EmojiPlayer<&'output str>> + 'parse {
@@ -1754,12 +1804,24 @@ pub(super) fn either_team_emoji_player_eof<'parse, 'output>(
 #[cfg(test)]
 mod test {
     use crate::{
-        enums::{BaseNameVariant, Day, FairBallType, TopBottom},
+        enums::{
+            BaseNameVariant, BatterStat, Day, FairBallType, FeedEventType, NowBattingStats,
+            TopBottom,
+        },
+        feed_event::FeedEvent,
         nom_parsing::{
-            shared::{delivery, emoji, out, parse_and, try_from_word, try_from_words_m_n},
+            parse_player_feed_event::parse_player_feed_event,
+            parse_team_feed_event::parse_team_feed_event,
+            shared::{
+                delivery, door_prize, emoji, now_batting_stats, out, parse_and, purified,
+                try_from_word, try_from_words_m_n,
+            },
             ParsingContext,
         },
         parsed_event::{EmojiTeam, RunnerOut},
+        team_feed::PurifiedOutcome,
+        utils::no_tracing_errs,
+        MentionsPlayer,
     };
     use nom::Parser;
 
@@ -1796,6 +1858,93 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_door_prize_round_trip() {
+        let texts = [
+            "🥳 Tyler Blevins didn't win a Door Prize.",
+            "🥳 Tyler Blevins won a Door Prize: 25 🪙.",
+            "🥳 Tyler Blevins won a Door Prize! Tyler Blevins equips 📿 Enduring Pitcher's Pendant of Reflexes from the Door Prize.",
+            "🥳 Tyler Blevins won a Door Prize! 🪖 Steadfast Slugger's Helm of the Gale is discarded; nobody can use it.",
+        ];
+
+        for text in texts {
+            let (rest, prize) = door_prize(text).unwrap();
+            assert_eq!(rest, "");
+            assert_eq!(prize.won(), !text.contains("didn't win"));
+            assert_eq!(prize.unparse(), text);
+        }
+    }
+
+    #[test]
+    fn test_purified_round_trip() {
+        let cases = [
+            (
+                "Tyler Blevins was Purified of 🫀 Corruption and earned 120 🪙.",
+                PurifiedOutcome::Payment(120),
+            ),
+            (
+                "Tyler Blevins was Purified of 🌹 Efflorescence, earned 80 🪙, and gained 🦠 Immunity.",
+                PurifiedOutcome::PaymentAndImmunityRemoved(80),
+            ),
+            (
+                "Tyler Blevins was Purified of 🫀 Corruption. Tyler Blevins had no Corruption to remove.",
+                PurifiedOutcome::NoCorruption,
+            ),
+            (
+                "Tyler Blevins was Purified of 🫀 Corruption.",
+                PurifiedOutcome::None,
+            ),
+        ];
+
+        for (text, expected) in cases {
+            let (rest, (player_name, outcome)) = purified(text).unwrap();
+            assert_eq!(rest, "");
+            assert_eq!(player_name, "Tyler Blevins");
+            assert_eq!(outcome, expected);
+            assert_eq!(outcome.unparse(player_name), text);
+        }
+
+        assert_eq!(PurifiedOutcome::Payment(120).payment(), Some(120));
+        assert_eq!(
+            PurifiedOutcome::PaymentAndImmunityRemoved(80).payment(),
+            Some(80)
+        );
+        assert_eq!(PurifiedOutcome::NoCorruption.payment(), None);
+        assert_eq!(PurifiedOutcome::None.payment(), None);
+    }
+
+    #[test]
+    fn test_now_batting_stats() {
+        // Hobble Dalton, S2
+        let text = "0 for 2, 1 SO, 1 SF, 1 FO";
+        let (rest, stats) = now_batting_stats(text).unwrap();
+        assert_eq!(rest, "");
+
+        let NowBattingStats::Stats(stats) = stats else {
+            panic!("Expected stats, got {stats:?}");
+        };
+        assert_eq!(
+            stats,
+            vec![
+                BatterStat::HitsForAtBats {
+                    hits: 0,
+                    at_bats: 2
+                },
+                BatterStat::StrikeOuts(1),
+                BatterStat::SacrificeFlies(1),
+                BatterStat::ForceOuts(1),
+            ]
+        );
+        assert_eq!(
+            stats
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            text
+        );
+    }
+
     #[test]
     fn test_emoji() {
         assert_eq!(Ok(("", "\u{26be}")), emoji("\u{26be}"));
@@ -1805,25 +1954,63 @@ mod test {
     fn whale_bones() {
         let text = "🏴󠁧󠁢󠁷󠁬󠁳󠁿 Llanfairpwllgwyngyll Whale Bones received a 🧢 Artistic Gloves Cap Special Delivery.";
 
-        let mut parser = delivery(
-            &ParsingContext {
-                game_id: "",
-                event_log: &[],
-                event_index: None,
-                home_emoji_team: EmojiTeam {
-                    emoji: "",
-                    name: "",
-                },
-                away_emoji_team: EmojiTeam {
-                    emoji: "🏴󠁧󠁢󠁷󠁬󠁳󠁿",
-                    name: "Llanfairpwllgwyngyll Whale Bones",
-                },
-                season: 3,
-                day: Some(Day::Day(166)),
+        let parsing_context = ParsingContext::for_test(
+            EmojiTeam {
+                emoji: "",
+                name: "",
+            },
+            EmojiTeam {
+                emoji: "🏴󠁧󠁢󠁷󠁬󠁳󠁿",
+                name: "Llanfairpwllgwyngyll Whale Bones",
             },
-            "Special Delivery",
+            3,
+            Some(Day::Day(166)),
         );
+        let mut parser = delivery(&parsing_context, "Special Delivery");
 
         parser.parse(text).unwrap();
     }
+
+    #[test]
+    fn shared_feed_events_round_trip() {
+        let no_tracing_errs = no_tracing_errs();
+
+        let f = std::fs::File::open("test_data/s2_feed_event.json").unwrap();
+        let mut event: FeedEvent = serde_json::from_reader(f).unwrap();
+        event.season = 7;
+        event.day = Ok(Day::Day(50));
+        event.event_type = Ok(FeedEventType::Game);
+
+        for text in [
+            "Yumi Pratt was contained by Lance Green during the 🥀 Wither.",
+            "Yumi Pratt is Partying! Yumi Pratt gained +50 Contact and lost 5 Durability.",
+        ] {
+            event.text = text.to_string();
+
+            let player = parse_player_feed_event(&event);
+            let team = parse_team_feed_event(&event);
+            assert_eq!(text, player.unparse(&event));
+            assert_eq!(text, team.unparse(&event));
+        }
+
+        drop(no_tracing_errs);
+    }
+
+    #[test]
+    fn shared_feed_events_mention_players() {
+        let f = std::fs::File::open("test_data/s2_feed_event.json").unwrap();
+        let mut event: FeedEvent = serde_json::from_reader(f).unwrap();
+        event.season = 7;
+        event.day = Ok(Day::Day(50));
+        event.text = "Yumi Pratt was contained by Lance Green during the 🥀 Wither.".to_string();
+
+        let player = parse_player_feed_event(&event);
+        let team = parse_team_feed_event(&event);
+        for name in ["Yumi Pratt", "Lance Green"] {
+            assert!(player.mentions(name));
+            assert!(team.mentions(name));
+        }
+        assert!(!player.mentions("Lance"));
+        assert!(!team.mentions("Lance"));
+    }
 }
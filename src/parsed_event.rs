@@ -1,17 +1,16 @@
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
 use std::{
     convert::Infallible,
     fmt::{Display, Write},
-    iter::once,
     str::FromStr,
 };
-use strum::{Display, EnumDiscriminants, EnumString, IntoStaticStr};
+use strum::{Display, EnumDiscriminants, EnumIter, EnumString, IntoStaticStr};
 use thiserror::Error;
 
-use crate::enums::{Attribute, FoodName};
-use crate::nom_parsing::shared::{discarded_text, received_text};
-use crate::UnparsingContext;
+use crate::enums::{Attribute, CelestialEnergyTier, FoodName};
+use crate::nom_parsing::shared::{discarded_text, placed_player_eof, received_text};
 use crate::{
     enums::{
         Base, BaseNameVariant, BatterStat, Distance, EventType, FairBallDestination, FairBallType,
@@ -22,10 +21,13 @@ use crate::{
     time::Breakpoints,
     NotRecognized,
 };
+use crate::{MentionsPlayer, UnparsingContext};
 
 pub use crate::nom_parsing::shared::GrowAttributeChange;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Error)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum GameEventParseError {
     #[error("event type {} not recognized", .0.0)]
     EventTypeNotRecognized(#[source] NotRecognized),
@@ -33,13 +35,21 @@ pub enum GameEventParseError {
     FailedParsingMessage {
         event_type: EventType,
         message: String,
+        /// Byte offset into the message where the innermost sub-parser failed.
+        #[serde(default)]
+        offset: Option<usize>,
+        /// Labels of the `context` parsers that were active at the failure, innermost first.
+        #[serde(default)]
+        context: Vec<String>,
     },
 }
 
 /// S is the string type used. S = &'output str is used by the parser,
 /// but a mutable type is necessary when directly deserializing, because some players have escaped characters in their names
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
-#[strum_discriminants(derive(Display))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum_discriminants(derive(Display, Hash, EnumIter))]
 #[serde(tag = "event_type")]
 pub enum ParsedEventMessage<S> {
     ParseError {
@@ -76,6 +86,7 @@ pub enum ParsedEventMessage<S> {
         losing_score: u8,
     },
     InningStart {
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::inning_number))]
         number: u8,
         side: TopBottom,
         batting_team: EmojiTeam<S>,
@@ -89,6 +100,7 @@ pub enum ParsedEventMessage<S> {
         stats: NowBattingStats,
     },
     InningEnd {
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::inning_number))]
         number: u8,
         side: TopBottom,
     },
@@ -112,6 +124,7 @@ pub enum ParsedEventMessage<S> {
     // Pitch
     Ball {
         steals: Vec<BaseSteal<S>>,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::count))]
         count: (u8, u8),
         cheer: Option<Cheer>,
         aurora_photos: Option<SnappedPhotos<S>>,
@@ -123,6 +136,7 @@ pub enum ParsedEventMessage<S> {
     Strike {
         strike: StrikeType,
         steals: Vec<BaseSteal<S>>,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::count))]
         count: (u8, u8),
         cheer: Option<Cheer>,
         aurora_photos: Option<SnappedPhotos<S>>,
@@ -134,6 +148,7 @@ pub enum ParsedEventMessage<S> {
     Foul {
         foul: FoulType,
         steals: Vec<BaseSteal<S>>,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::count))]
         count: (u8, u8),
         cheer: Option<Cheer>,
         aurora_photos: Option<SnappedPhotos<S>>,
@@ -211,6 +226,7 @@ pub enum ParsedEventMessage<S> {
     },
     GroundedOut {
         batter: S,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::fielders))]
         fielders: Vec<PlacedPlayer<S>>,
         scores: Vec<S>,
         advances: Vec<RunnerAdvance<S>>,
@@ -219,6 +235,7 @@ pub enum ParsedEventMessage<S> {
     },
     ForceOut {
         batter: S,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::fielders))]
         fielders: Vec<PlacedPlayer<S>>,
         fair_ball_type: FairBallType,
         out: RunnerOut<S>,
@@ -228,6 +245,7 @@ pub enum ParsedEventMessage<S> {
     },
     ReachOnFieldersChoice {
         batter: S,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::fielders))]
         fielders: Vec<PlacedPlayer<S>>,
         result: FieldingAttempt<S>,
         scores: Vec<S>,
@@ -236,6 +254,7 @@ pub enum ParsedEventMessage<S> {
     },
     DoublePlayGrounded {
         batter: S,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::fielders))]
         fielders: Vec<PlacedPlayer<S>>,
         out_one: RunnerOut<S>,
         out_two: RunnerOut<S>,
@@ -247,6 +266,7 @@ pub enum ParsedEventMessage<S> {
     DoublePlayCaught {
         batter: S,
         fair_ball_type: FairBallType,
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_constraints::fielders))]
         fielders: Vec<PlacedPlayer<S>>,
         out_two: RunnerOut<S>,
         scores: Vec<S>,
@@ -350,41 +370,57 @@ impl<S: Display> ParsedEventMessage<S> {
         context: impl Into<UnparsingContext<'a>>,
         event_index: Option<u16>,
     ) -> String {
+        let mut message = String::new();
+        self.unparse_into(&mut message, context, event_index)
+            .expect("writing to a String doesn't fail");
+        message
+    }
+
+    /// Like [`Self::unparse`], but writes the event message straight into `w`,
+    /// without building the whole message as a String first.
+    ///
+    /// Pitches, plays and everything attached to them (cheers, ejections, door prizes, etc.)
+    /// are written without allocating. A few weather messages, like deliveries, still build
+    /// some pieces as Strings.
+    pub fn unparse_into<'a>(
+        &self,
+        w: &mut impl Write,
+        context: impl Into<UnparsingContext<'a>>,
+        event_index: Option<u16>,
+    ) -> std::fmt::Result {
         let context = context.into();
         match self {
-            Self::ParseError { message, .. } => message.to_string(),
+            Self::ParseError { message, .. } => write!(w, "{message}"),
             Self::LiveNow {
                 away_team,
                 home_team,
                 stadium,
             } => match stadium {
-                Some(stadium) => format!("{} vs {} @ {}", away_team, home_team, stadium),
-                None => format!("{} @ {}", away_team, home_team),
+                Some(stadium) => write!(w, "{} vs {} @ {}", away_team, home_team, stadium),
+                None => write!(w, "{} @ {}", away_team, home_team),
             },
             Self::PitchingMatchup {
                 away_team,
                 home_team,
                 home_pitcher,
                 away_pitcher,
-            } => format!("{away_team} {away_pitcher} vs. {home_team} {home_pitcher}"),
-            Self::Lineup { side: _, players } => {
-                players
-                    .iter()
-                    .enumerate()
-                    .fold(String::new(), |mut acc, (index, player)| {
-                        let _ = write!(acc, "{}. {player}<br>", index + 1);
-                        acc
-                    })
-            }
-            Self::PlayBall => "\"PLAY BALL.\"".to_string(),
-            Self::GameOver { message } => message.to_string(),
+            } => write!(
+                w,
+                "{away_team} {away_pitcher} vs. {home_team} {home_pitcher}"
+            ),
+            Self::Lineup { side: _, players } => players
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, player)| write!(w, "{}. {player}<br>", index + 1)),
+            Self::PlayBall => w.write_str("\"PLAY BALL.\""),
+            Self::GameOver { message } => write!(w, "{message}"),
             Self::Recordkeeping {
                 winning_team,
                 losing_team,
                 winning_score,
                 losing_score,
             } => {
-                format!("{winning_team} defeated {losing_team}. Final score: {winning_score}-{losing_score}")
+                write!(w, "{winning_team} defeated {losing_team}. Final score: {winning_score}-{losing_score}")
             }
             Self::InningStart {
                 number,
@@ -393,21 +429,17 @@ impl<S: Display> ParsedEventMessage<S> {
                 automatic_runner,
                 pitcher_status,
             } => {
-                let ordinal = match number {
-                    0 => panic!("Should not have 0th innings"),
-                    11 => "11th".to_string(),
-                    12 => "12th".to_string(),
-                    13 => "13th".to_string(),
-                    _ => match number % 10 {
-                        1 => format!("{number}st"),
-                        2 => format!("{number}nd"),
-                        3 => format!("{number}rd"),
-                        _ => format!("{number}th"),
-                    },
-                };
-                let pitcher_message = match pitcher_status {
+                let ordinal = ordinal(*number);
+                write!(
+                    w,
+                    "Start of the {side} of the {ordinal}. {batting_team} batting."
+                )?;
+                if let Some(runner) = automatic_runner {
+                    write!(w, " {runner} starts the inning on second base.")?;
+                }
+                match pitcher_status {
                     Some(StartOfInningPitcher::Same { emoji, name }) => {
-                        format!(" {emoji} {name} pitching.")
+                        write!(w, " {emoji} {name} pitching.")
                     }
                     Some(StartOfInningPitcher::Different {
                         leaving_emoji,
@@ -415,69 +447,44 @@ impl<S: Display> ParsedEventMessage<S> {
                         arriving_emoji,
                         arriving_pitcher,
                     }) => {
-                        let leaving_emoji = leaving_emoji
-                            .as_ref()
-                            .map(|e| format!("{e} "))
-                            .unwrap_or_default();
-                        let arriving_emoji = arriving_emoji
-                            .as_ref()
-                            .map(|e| format!("{e} "))
-                            .unwrap_or_default();
-                        format!(" {leaving_emoji}{leaving_pitcher} is leaving the game. {arriving_emoji}{arriving_pitcher} takes the mound.")
+                        let leaving_emoji = with_space(leaving_emoji);
+                        let arriving_emoji = with_space(arriving_emoji);
+                        write!(w, " {leaving_emoji}{leaving_pitcher} is leaving the game. {arriving_emoji}{arriving_pitcher} takes the mound.")
                     }
-                    None => String::new(),
-                };
-                let automatic_runner = match automatic_runner {
-                    Some(runner) => format!(" {runner} starts the inning on second base."),
-                    None => String::new(),
-                };
-                format!("Start of the {side} of the {ordinal}. {batting_team} batting.{automatic_runner}{pitcher_message}")
+                    None => Ok(()),
+                }
             }
             Self::NowBatting { batter, stats } => {
-                let stats = match stats {
-                    NowBattingStats::FirstPA => " (1st PA of game)".to_string(),
+                write!(w, "Now batting: {batter}")?;
+                match stats {
+                    NowBattingStats::FirstPA => w.write_str(" (1st PA of game)"),
                     NowBattingStats::Stats(stats) => {
-                        format!(
+                        write!(
+                            w,
                             " ({})",
-                            stats
-                                .iter()
-                                .map(BatterStat::unparse)
-                                .collect::<Vec<_>>()
-                                .join(", ")
+                            stats.iter().map(BatterStat::unparse).format(", ")
                         )
                     }
-                    NowBattingStats::NoStats => String::new(),
-                };
-                format!("Now batting: {batter}{stats}")
+                    NowBattingStats::NoStats => Ok(()),
+                }
             }
             Self::InningEnd { number, side } => {
-                let ordinal = match number {
-                    0 => panic!("Should not have 0th innings"),
-                    11 => "11th".to_string(),
-                    12 => "12th".to_string(),
-                    13 => "13th".to_string(),
-                    _ => match number % 10 {
-                        1 => format!("{number}st"),
-                        2 => format!("{number}nd"),
-                        3 => format!("{number}rd"),
-                        _ => format!("{number}th"),
-                    },
-                };
-                format!("End of the {side} of the {ordinal}.")
+                let ordinal = ordinal(*number);
+                write!(w, "End of the {side} of the {ordinal}.")
             }
             Self::MoundVisit {
                 team,
                 mound_visit_type,
             } => match mound_visit_type {
                 MoundVisitType::MoundVisit => {
-                    format!("The {team} manager is making a mound visit.")
+                    write!(w, "The {team} manager is making a mound visit.")
                 }
                 MoundVisitType::PitchingChange => {
-                    format!("The {team} manager is making a pitching change.")
+                    write!(w, "The {team} manager is making a pitching change.")
                 }
             },
             Self::PitcherRemains { remaining_pitcher } => {
-                format!("{remaining_pitcher} remains in the game.")
+                write!(w, "{remaining_pitcher} remains in the game.")
             }
             Self::PitcherSwap {
                 leaving_pitcher_emoji,
@@ -486,19 +493,11 @@ impl<S: Display> ParsedEventMessage<S> {
                 arriving_pitcher_place,
                 arriving_pitcher_name,
             } => {
-                let arriving_pitcher_place = arriving_pitcher_place
-                    .map(|place| format!("{place} "))
-                    .unwrap_or_default();
-                let leaving_pitcher_emoji = leaving_pitcher_emoji
-                    .as_ref()
-                    .map(|emoji| format!("{emoji} "))
-                    .unwrap_or_default();
-                let arriving_pitcher_emoji = arriving_pitcher_emoji
-                    .as_ref()
-                    .map(|emoji| format!("{emoji} "))
-                    .unwrap_or_default();
+                let arriving_pitcher_place = with_space(arriving_pitcher_place);
+                let leaving_pitcher_emoji = with_space(leaving_pitcher_emoji);
+                let arriving_pitcher_emoji = with_space(arriving_pitcher_emoji);
 
-                format!("{leaving_pitcher_emoji}{leaving_pitcher} is leaving the game. {arriving_pitcher_emoji}{arriving_pitcher_place}{arriving_pitcher_name} takes the mound.")
+                write!(w, "{leaving_pitcher_emoji}{leaving_pitcher} is leaving the game. {arriving_pitcher_emoji}{arriving_pitcher_place}{arriving_pitcher_name} takes the mound.")
             }
 
             Self::Ball {
@@ -511,34 +510,17 @@ impl<S: Display> ParsedEventMessage<S> {
                 wither,
                 efflorescence,
             } => {
-                let steals = once(String::new())
-                    .chain(steals.iter().map(BaseSteal::to_string))
-                    .collect::<Vec<String>>()
-                    .join(" ");
+                let steals = prefixed(" ", steals);
                 let space = old_space(context, event_index);
 
-                let cheer = cheer
-                    .as_ref()
-                    .map(|c| c.unparse(context, event_index))
-                    .unwrap_or_default();
-                let aurora_photos = aurora_photos
-                    .as_ref()
-                    .map(|p| p.unparse())
-                    .unwrap_or_default();
-                let ejection = ejection.as_ref().map(|e| e.unparse()).unwrap_or_default();
-                let door_prizes = once(String::new())
-                    .chain(door_prizes.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>");
-                let wither = wither
-                    .as_ref()
-                    .map_or_else(String::new, |wither| format!(" {}", wither));
-                let efflorescence = once(String::new())
-                    .chain(efflorescence.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>🌹 ");
+                let cheer = or_empty(cheer.as_ref().map(|c| c.display(context, event_index)));
+                let aurora_photos = or_empty(aurora_photos.as_ref());
+                let ejection = or_empty(ejection.as_ref());
+                let door_prizes = prefixed("<br>", door_prizes);
+                let wither = prefixed(" ", wither);
+                let efflorescence = prefixed("<br>🌹 ", efflorescence);
 
-                format!("{space}Ball. {}-{}.{steals}{aurora_photos}{ejection}{cheer}{door_prizes}{wither}{efflorescence}", count.0, count.1)
+                write!(w, "{space}Ball. {}-{}.{steals}{aurora_photos}{ejection}{cheer}{door_prizes}{wither}{efflorescence}", count.0, count.1)
             }
             Self::Strike {
                 strike,
@@ -551,34 +533,17 @@ impl<S: Display> ParsedEventMessage<S> {
                 wither,
                 efflorescence,
             } => {
-                let steals: Vec<String> = once(String::new())
-                    .chain(steals.iter().map(|steal| steal.to_string()))
-                    .collect();
-                let steals = steals.join(" ");
+                let steals = prefixed(" ", steals);
                 let space = old_space(context, event_index);
 
-                let cheer = cheer
-                    .as_ref()
-                    .map(|c| c.unparse(context, event_index))
-                    .unwrap_or_default();
-                let aurora_photos = aurora_photos
-                    .as_ref()
-                    .map(|p| p.unparse())
-                    .unwrap_or_default();
-                let ejection = ejection.as_ref().map(|e| e.unparse()).unwrap_or_default();
-                let door_prizes = once(String::new())
-                    .chain(door_prizes.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>");
-                let wither = wither
-                    .as_ref()
-                    .map_or_else(String::new, |wither| format!(" {}", wither));
-                let efflorescence = once(String::new())
-                    .chain(efflorescence.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>🌹 ");
+                let cheer = or_empty(cheer.as_ref().map(|c| c.display(context, event_index)));
+                let aurora_photos = or_empty(aurora_photos.as_ref());
+                let ejection = or_empty(ejection.as_ref());
+                let door_prizes = prefixed("<br>", door_prizes);
+                let wither = prefixed(" ", wither);
+                let efflorescence = prefixed("<br>🌹 ", efflorescence);
 
-                format!("{space}Strike, {strike}. {}-{}.{steals}{aurora_photos}{ejection}{cheer}{door_prizes}{wither}{efflorescence}", count.0, count.1)
+                write!(w, "{space}Strike, {strike}. {}-{}.{steals}{aurora_photos}{ejection}{cheer}{door_prizes}{wither}{efflorescence}", count.0, count.1)
             }
             Self::Foul {
                 foul,
@@ -590,33 +555,16 @@ impl<S: Display> ParsedEventMessage<S> {
                 wither,
                 efflorescence,
             } => {
-                let steals: Vec<String> = once(String::new())
-                    .chain(steals.iter().map(|steal| steal.to_string()))
-                    .collect();
-                let steals = steals.join(" ");
+                let steals = prefixed(" ", steals);
                 let space = old_space(context, event_index);
 
-                let cheer = cheer
-                    .as_ref()
-                    .map(|c| c.unparse(context, event_index))
-                    .unwrap_or_default();
-                let aurora_photos = aurora_photos
-                    .as_ref()
-                    .map(|p| p.unparse())
-                    .unwrap_or_default();
-                let door_prizes = once(String::new())
-                    .chain(door_prizes.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>");
-                let wither = wither
-                    .as_ref()
-                    .map_or_else(String::new, |wither| format!(" {}", wither));
-                let efflorescence = once(String::new())
-                    .chain(efflorescence.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>🌹 ");
+                let cheer = or_empty(cheer.as_ref().map(|c| c.display(context, event_index)));
+                let aurora_photos = or_empty(aurora_photos.as_ref());
+                let door_prizes = prefixed("<br>", door_prizes);
+                let wither = prefixed(" ", wither);
+                let efflorescence = prefixed("<br>🌹 ", efflorescence);
 
-                format!("{space}Foul {foul}. {}-{}.{steals}{aurora_photos}{cheer}{door_prizes}{wither}{efflorescence}", count.0, count.1)
+                write!(w, "{space}Foul {foul}. {}-{}.{steals}{aurora_photos}{cheer}{door_prizes}{wither}{efflorescence}", count.0, count.1)
             }
             Self::Walk {
                 batter,
@@ -630,21 +578,13 @@ impl<S: Display> ParsedEventMessage<S> {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
                 let space = old_space(context, event_index);
 
-                let cheer = cheer
-                    .as_ref()
-                    .map(|c| c.unparse(context, event_index))
-                    .unwrap_or_default();
-                let aurora_photos = aurora_photos
-                    .as_ref()
-                    .map(|p| p.unparse())
-                    .unwrap_or_default();
-                let ejection = ejection.as_ref().map(|e| e.unparse()).unwrap_or_default();
-                let wither = wither
-                    .as_ref()
-                    .map_or_else(String::new, |wither| format!(" {}", wither));
+                let cheer = or_empty(cheer.as_ref().map(|c| c.display(context, event_index)));
+                let aurora_photos = or_empty(aurora_photos.as_ref());
+                let ejection = or_empty(ejection.as_ref());
+                let wither = prefixed(" ", wither);
 
                 // Proof cheer is before ejection: https://mmolb.com/watch/6887e503f142e23550fc1254?event=369
-                format!("{space}Ball 4. {batter} walks.{scores_and_advances}{aurora_photos}{cheer}{ejection}{wither}")
+                write!(w, "{space}Ball 4. {batter} walks.{scores_and_advances}{aurora_photos}{cheer}{ejection}{wither}")
             }
             Self::HitByPitch {
                 batter,
@@ -660,28 +600,14 @@ impl<S: Display> ParsedEventMessage<S> {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
                 let space = old_space(context, event_index);
 
-                let cheer = cheer
-                    .as_ref()
-                    .map(|c| c.unparse(context, event_index))
-                    .unwrap_or_default();
-                let aurora_photos = aurora_photos
-                    .as_ref()
-                    .map(|p| p.unparse())
-                    .unwrap_or_default();
-                let ejection = ejection.as_ref().map(|e| e.unparse()).unwrap_or_default();
-                let door_prizes = once(String::new())
-                    .chain(door_prizes.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>");
+                let cheer = or_empty(cheer.as_ref().map(|c| c.display(context, event_index)));
+                let aurora_photos = or_empty(aurora_photos.as_ref());
+                let ejection = or_empty(ejection.as_ref());
+                let door_prizes = prefixed("<br>", door_prizes);
                 let hbp_text = hit_by_pitch_text(context.season, context.day, event_index);
-                let wither = wither
-                    .as_ref()
-                    .map_or_else(String::new, |wither| format!(" {}", wither));
-                let efflorescence = once(String::new())
-                    .chain(efflorescence.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>🌹 ");
-                format!("{space}{batter}{hbp_text}.{scores_and_advances}{aurora_photos}{cheer}{ejection}{door_prizes}{wither}{efflorescence}")
+                let wither = prefixed(" ", wither);
+                let efflorescence = prefixed("<br>🌹 ", efflorescence);
+                write!(w, "{space}{batter}{hbp_text}.{scores_and_advances}{aurora_photos}{cheer}{ejection}{door_prizes}{wither}{efflorescence}")
             }
             Self::FairBall {
                 batter,
@@ -694,24 +620,12 @@ impl<S: Display> ParsedEventMessage<S> {
             } => {
                 let space = old_space(context, event_index);
 
-                let cheer = cheer
-                    .as_ref()
-                    .map(|c| c.unparse(context, event_index))
-                    .unwrap_or_default();
-                let aurora_photos = aurora_photos
-                    .as_ref()
-                    .map(|p| p.unparse())
-                    .unwrap_or_default();
-                let door_prizes = once(String::new())
-                    .chain(door_prizes.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>");
-                let efflorescence = once(String::new())
-                    .chain(efflorescence.iter().map(|d| d.unparse()))
-                    .collect::<Vec<_>>()
-                    .join("<br>🌹 ");
+                let cheer = or_empty(cheer.as_ref().map(|c| c.display(context, event_index)));
+                let aurora_photos = or_empty(aurora_photos.as_ref());
+                let door_prizes = prefixed("<br>", door_prizes);
+                let efflorescence = prefixed("<br>🌹 ", efflorescence);
 
-                format!("{space}{batter} hits a {fair_ball_type} to {destination}.{aurora_photos}{cheer}{door_prizes}{efflorescence}")
+                write!(w, "{space}{batter} hits a {fair_ball_type} to {destination}.{aurora_photos}{cheer}{door_prizes}{efflorescence}")
             }
             Self::StrikeOut {
                 foul,
@@ -723,33 +637,22 @@ impl<S: Display> ParsedEventMessage<S> {
                 ejection,
                 wither,
             } => {
-                let foul = match foul {
-                    Some(foul) => format!("Foul {foul}. "),
-                    None => String::new(),
-                };
-                let steals: Vec<String> = once(String::new())
-                    .chain(steals.iter().map(|steal| steal.to_string()))
-                    .collect();
-                let steals = steals.join(" ");
+                let foul = DisplayFn(|f: &mut Formatter<'_>| match foul {
+                    Some(foul) => write!(f, "Foul {foul}. "),
+                    None => Ok(()),
+                });
+                let steals = prefixed(" ", steals);
                 let space = old_space(context, event_index);
 
-                let cheer = cheer
-                    .as_ref()
-                    .map(|c| c.unparse(context, event_index))
-                    .unwrap_or_default();
-                let aurora_photos = aurora_photos
-                    .as_ref()
-                    .map(|p| p.unparse())
-                    .unwrap_or_default();
-                let ejection = ejection.as_ref().map(|e| e.unparse()).unwrap_or_default();
-                let wither = wither
-                    .as_ref()
-                    .map_or_else(String::new, |wither| format!(" {}", wither));
+                let cheer = or_empty(cheer.as_ref().map(|c| c.display(context, event_index)));
+                let aurora_photos = or_empty(aurora_photos.as_ref());
+                let ejection = or_empty(ejection.as_ref());
+                let wither = prefixed(" ", wither);
 
                 // I do have proof that cheer is before ejection at least on this event
                 // (game 6887e4f9f142e23550fc1134 event 265)
                 let strike_out_text = strike_out_text(context.season, context.day, event_index);
-                format!("{space}{foul}{batter}{strike_out_text}{strike}.{steals}{aurora_photos}{cheer}{ejection}{wither}")
+                write!(w, "{space}{foul}{batter}{strike_out_text}{strike}.{steals}{aurora_photos}{cheer}{ejection}{wither}")
             }
             Self::BatterToBase {
                 batter,
@@ -761,8 +664,8 @@ impl<S: Display> ParsedEventMessage<S> {
                 ejection,
             } => {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
-                let ejection = ejection.as_ref().map(|e| e.unparse()).unwrap_or_default();
-                format!("{batter} {distance} on a {fair_ball_type} to {fielder}.{scores_and_advances}{ejection}")
+                let ejection = or_empty(ejection.as_ref());
+                write!(w, "{batter} {distance} on a {fair_ball_type} to {fielder}.{scores_and_advances}{ejection}")
             }
             Self::HomeRun {
                 batter,
@@ -772,20 +675,13 @@ impl<S: Display> ParsedEventMessage<S> {
                 grand_slam,
                 ejection,
             } => {
-                let scores = once(String::new())
-                    .chain(
-                        scores
-                            .iter()
-                            .map(|runner| format!("<strong>{runner} scores!</strong>")),
-                    )
-                    .collect::<Vec<String>>()
-                    .join(" ");
-                let ejection = ejection.as_ref().map(|e| e.unparse()).unwrap_or_default();
+                let scores = unparse_scores_and_advances(scores, &[]);
+                let ejection = or_empty(ejection.as_ref());
 
                 if !grand_slam {
-                    format!("<strong>{batter} homers on a {fair_ball_type} to {destination}!</strong>{scores}{ejection}")
+                    write!(w, "<strong>{batter} homers on a {fair_ball_type} to {destination}!</strong>{scores}{ejection}")
                 } else {
-                    format!("<strong>{batter} hits a grand slam on a {fair_ball_type} to {destination}!</strong>{scores}{ejection}")
+                    write!(w, "<strong>{batter} hits a grand slam on a {fair_ball_type} to {destination}!</strong>{scores}{ejection}")
                 }
             }
             Self::CaughtOut {
@@ -810,13 +706,9 @@ impl<S: Display> ParsedEventMessage<S> {
                 } else {
                     ""
                 };
-                let ejection = if let Some(ej) = ejection {
-                    ej.unparse()
-                } else {
-                    String::new()
-                };
+                let ejection = or_empty(ejection.as_ref());
 
-                format!("{batter} {fair_ball_type} out {sacrifice}to {catcher}.{scores_and_advances}{perfect}{ejection}")
+                write!(w, "{batter} {fair_ball_type} out {sacrifice}to {catcher}.{scores_and_advances}{perfect}{ejection}")
             }
             Self::GroundedOut {
                 batter,
@@ -837,12 +729,11 @@ impl<S: Display> ParsedEventMessage<S> {
                 } else {
                     ""
                 };
-                let ejection = if let Some(ej) = ejection {
-                    ej.unparse()
-                } else {
-                    String::new()
-                };
-                format!("{batter} grounds out{fielders}.{scores_and_advances}{perfect}{ejection}")
+                let ejection = or_empty(ejection.as_ref());
+                write!(
+                    w,
+                    "{batter} grounds out{fielders}.{scores_and_advances}{perfect}{ejection}"
+                )
             }
             Self::ForceOut {
                 batter,
@@ -856,12 +747,8 @@ impl<S: Display> ParsedEventMessage<S> {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
                 let fielders = unparse_fielders_for_play(fielders);
                 let fair_ball_type = fair_ball_type.verb_name();
-                let ejection = if let Some(ej) = ejection {
-                    ej.unparse()
-                } else {
-                    String::new()
-                };
-                format!("{batter} {fair_ball_type} into a force out{fielders}. {out}{scores_and_advances}{ejection}")
+                let ejection = or_empty(ejection.as_ref());
+                write!(w, "{batter} {fair_ball_type} into a force out{fielders}. {out}{scores_and_advances}{ejection}")
             }
             Self::ReachOnFieldersChoice {
                 batter,
@@ -872,21 +759,17 @@ impl<S: Display> ParsedEventMessage<S> {
                 ejection,
             } => {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
-                let ejection = if let Some(ej) = ejection {
-                    ej.unparse()
-                } else {
-                    String::new()
-                };
+                let ejection = or_empty(ejection.as_ref());
                 match result {
                     FieldingAttempt::Out { out } => {
                         let fielders = unparse_fielders_for_play(fielders);
 
-                        format!("{batter} reaches on a fielder's choice out{fielders}. {out}{scores_and_advances}{ejection}")
+                        write!(w, "{batter} reaches on a fielder's choice out{fielders}. {out}{scores_and_advances}{ejection}")
                     }
                     FieldingAttempt::Error { fielder, error } => {
                         let fielder_long = fielders.first().unwrap();
                         let error = error.uppercase();
-                        format!("{batter} reaches on a fielder's choice, fielded by {fielder_long}.{scores_and_advances} {error} error by {fielder}.{ejection}")
+                        write!(w, "{batter} reaches on a fielder's choice, fielded by {fielder_long}.{scores_and_advances} {error} error by {fielder}.{ejection}")
                     }
                 }
             }
@@ -904,11 +787,7 @@ impl<S: Display> ParsedEventMessage<S> {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
                 let sacrifice = if *sacrifice { "sacrifice " } else { "" };
 
-                let ejection = if let Some(ej) = ejection {
-                    ej.unparse()
-                } else {
-                    String::new()
-                };
+                let ejection = or_empty(ejection.as_ref());
 
                 let verb = if Breakpoints::Season5TenseChange.before(
                     context.season,
@@ -920,7 +799,7 @@ impl<S: Display> ParsedEventMessage<S> {
                     "grounds"
                 };
 
-                format!("{batter} {verb} into a {sacrifice}double play{fielders}. {out_one} {out_two}{scores_and_advances}{ejection}")
+                write!(w, "{batter} {verb} into a {sacrifice}double play{fielders}. {out_one} {out_two}{scores_and_advances}{ejection}")
             }
             Self::DoublePlayCaught {
                 batter,
@@ -935,13 +814,9 @@ impl<S: Display> ParsedEventMessage<S> {
                 let fielders = unparse_fielders_for_play(fielders);
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
 
-                let ejection = if let Some(ej) = ejection {
-                    ej.unparse()
-                } else {
-                    String::new()
-                };
+                let ejection = or_empty(ejection.as_ref());
 
-                format!("{batter} {fair_ball_type} into a double play{fielders}. {out_two}{scores_and_advances}{ejection}")
+                write!(w, "{batter} {fair_ball_type} into a double play{fielders}. {out_two}{scores_and_advances}{ejection}")
             }
             Self::ReachOnFieldingError {
                 batter,
@@ -953,42 +828,43 @@ impl<S: Display> ParsedEventMessage<S> {
             } => {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
                 let error = error.lowercase();
-                let ejection = if let Some(e) = ejection {
-                    e.unparse()
-                } else {
-                    String::new()
-                };
-                format!("{batter} reaches on a {error} error by {fielder}.{scores_and_advances}{ejection}")
+                let ejection = or_empty(ejection.as_ref());
+                write!(w, "{batter} reaches on a {error} error by {fielder}.{scores_and_advances}{ejection}")
             }
             Self::WeatherDelivery { delivery } => {
-                delivery.unparse(context, event_index, "Delivery")
+                w.write_str(&delivery.unparse(context, event_index, "Delivery"))
             }
             Self::FallingStar { player_name } => {
-                format!("<strong>🌠 {player_name} is hit by a Falling Star!</strong>")
+                write!(
+                    w,
+                    "<strong>🌠 {player_name} is hit by a Falling Star!</strong>"
+                )
             }
             Self::FallingStarOutcome {
                 deflection,
                 player_name,
                 outcome,
             } => {
-                let deflection_msg = if let Some(deflected_off_player_name) = deflection {
-                    format!("It deflected off {deflected_off_player_name} and struck {player_name}!</strong> <strong>")
-                } else {
-                    String::new()
-                };
+                w.write_str(" <strong>")?;
+                if let Some(deflected_off_player_name) = deflection {
+                    write!(w, "It deflected off {deflected_off_player_name} and struck {player_name}!</strong> <strong>")?;
+                }
 
                 let outcome_msg =
                     outcome.unparse(context, event_index, player_name.to_string().as_str());
 
-                format!(" <strong>{deflection_msg}{outcome_msg}</strong>")
+                write!(w, "{outcome_msg}</strong>")
             }
-            Self::WeatherShipment { deliveries } => deliveries
-                .iter()
-                .map(|d| d.unparse(context, event_index, "Shipment"))
-                .collect::<Vec<String>>()
-                .join(" "),
+            Self::WeatherShipment { deliveries } => write!(
+                w,
+                "{}",
+                deliveries
+                    .iter()
+                    .map(|d| d.unparse(context, event_index, "Shipment"))
+                    .format(" ")
+            ),
             Self::WeatherSpecialDelivery { delivery } => {
-                delivery.unparse(context, event_index, "Special Delivery")
+                w.write_str(&delivery.unparse(context, event_index, "Special Delivery"))
             }
             Self::Balk {
                 pitcher,
@@ -996,9 +872,9 @@ impl<S: Display> ParsedEventMessage<S> {
                 advances,
             } => {
                 let scores_and_advances = unparse_scores_and_advances(scores, advances);
-                format!("Balk. {pitcher} dropped the ball.{scores_and_advances}")
+                write!(w, "Balk. {pitcher} dropped the ball.{scores_and_advances}")
             }
-            Self::KnownBug { bug } => format!("{bug}"),
+            Self::KnownBug { bug } => write!(w, "{bug}"),
             Self::WeatherProsperity {
                 home_income,
                 away_income,
@@ -1036,11 +912,11 @@ impl<S: Display> ParsedEventMessage<S> {
                     context.day,
                     event_index,
                 ) {
-                    format!("{home}{gap}{away}")
+                    write!(w, "{home}{gap}{away}")
                 } else if home_income > away_income {
-                    format!("{away}{gap}{home}")
+                    write!(w, "{away}{gap}{home}")
                 } else {
-                    format!("{home}{gap}{away}")
+                    write!(w, "{home}{gap}{away}")
                 }
             }
             Self::PhotoContest {
@@ -1066,7 +942,7 @@ impl<S: Display> ParsedEventMessage<S> {
                     "earn"
                 };
 
-                format!("{winning_team} {earn} {winning_tokens} 🪙. {losing_team} {earn} {losing_tokens} 🪙.<br>Top scoring Photos:<br>{winning_emoji} {winning_player} - {winning_score} {losing_emoji} {losing_player} - {losing_score}")
+                write!(w, "{winning_team} {earn} {winning_tokens} 🪙. {losing_team} {earn} {losing_tokens} 🪙.<br>Top scoring Photos:<br>{winning_emoji} {winning_player} - {winning_score} {losing_emoji} {losing_player} - {losing_score}")
             }
             Self::Party {
                 pitcher_name,
@@ -1077,7 +953,8 @@ impl<S: Display> ParsedEventMessage<S> {
                 batter_attribute,
                 durability_loss,
             } => {
-                format!(
+                write!(
+                    w,
                     "<strong>🥳 {pitcher_name} and {batter_name} are Partying!</strong> \
                     {pitcher_name} gained +{pitcher_amount_gained} {pitcher_attribute}. \
                     {batter_name} gained +{batter_amount_gained} {batter_attribute}. \
@@ -1085,7 +962,10 @@ impl<S: Display> ParsedEventMessage<S> {
                 )
             }
             Self::WeatherReflection { team } => {
-                format!("🪞 The reflection shatters. {team} received a Fragment of Reflection.")
+                write!(
+                    w,
+                    "🪞 The reflection shatters. {team} received a Fragment of Reflection."
+                )
             }
             Self::WeatherWither {
                 team_emoji,
@@ -1122,16 +1002,16 @@ impl<S: Display> ParsedEventMessage<S> {
                             "resisted"
                         };
 
-                        format!("{team_emoji} {player} {resist} the effects of the 🥀 Wither{delim}{contained}")
+                        write!(w, "{team_emoji} {player} {resist} the effects of the 🥀 Wither{delim}{contained}")
                     }
                     WitherResult::ResistedEffloresced => {
-                        format!("{team_emoji} {player} resisted the effects of the 🥀 Wither while Effloresced{delim}{contained}")
+                        write!(w, "{team_emoji} {player} resisted the effects of the 🥀 Wither while Effloresced{delim}{contained}")
                     }
                     WitherResult::ResistedImmune => {
-                        format!("{team_emoji} {player} resisted the effects of the 🥀 Wither with 🦠 Immunity{delim}{contained}")
+                        write!(w, "{team_emoji} {player} resisted the effects of the 🥀 Wither with 🦠 Immunity{delim}{contained}")
                     }
                     WitherResult::Corrupted => {
-                        format!("{team_emoji} {player} was Corrupted by the 🥀 Wither{delim}{contained}")
+                        write!(w, "{team_emoji} {player} was Corrupted by the 🥀 Wither{delim}{contained}")
                     }
                 }
             }
@@ -1140,1378 +1020,3629 @@ impl<S: Display> ParsedEventMessage<S> {
                 claimed_from,
             } => {
                 if context.before(event_index, Breakpoints::Season10) {
-                    format!("➰ {claimed_by} claimed the Lineal Belt from {claimed_from}!")
+                    write!(
+                        w,
+                        "➰ {claimed_by} claimed the Lineal Belt from {claimed_from}!"
+                    )
                 } else {
-                    format!("{claimed_by} claimed the ➰ Lineal Belt from {claimed_from}!")
+                    write!(
+                        w,
+                        "{claimed_by} claimed the ➰ Lineal Belt from {claimed_from}!"
+                    )
                 }
             }
-            Self::WeatherConsumption(weather_consumption) => weather_consumption.unparse(),
+            Self::WeatherConsumption(weather_consumption) => {
+                w.write_str(&weather_consumption.unparse())
+            }
             Self::WeatherSimulacrum {
                 real_team,
                 simulacrum_team,
                 tokens_earnt,
             } => {
-                format!("{real_team} were defeated by the {simulacrum_team} and earned {tokens_earnt} 🪙.")
+                write!(w, "{real_team} were defeated by the {simulacrum_team} and earned {tokens_earnt} 🪙.")
             }
             Self::WeatherSimulacrumOffseason => {
-                format!("The Simulacrum yields no tokens during the Offseason.")
+                w.write_str("The Simulacrum yields no tokens during the Offseason.")
             }
         }
     }
 }
 
-fn unparse_fielders<S: Display>(fielders: &[PlacedPlayer<S>]) -> String {
-    match fielders.len() {
-        0 => panic!("0-fielders"),
-        1 => format!(" to {}", fielders.first().unwrap()),
-        _ => format!(
-            ", {}",
-            fielders
-                .iter()
-                .map(PlacedPlayer::to_string)
-                .collect::<Vec<_>>()
-                .join(" to ")
-        ),
+impl<S> ParsedEventMessage<S> {
+    /// The raw event type this event is parsed from. None for parse errors and known bugs,
+    /// which can come from more than one event type.
+    pub fn event_type(&self) -> Option<EventType> {
+        match self {
+            Self::ParseError { .. } | Self::KnownBug { .. } => None,
+            Self::LiveNow { .. } => Some(EventType::LiveNow),
+            Self::PitchingMatchup { .. } => Some(EventType::PitchingMatchup),
+            Self::Lineup { side, .. } => match side {
+                HomeAway::Away => Some(EventType::AwayLineup),
+                HomeAway::Home => Some(EventType::HomeLineup),
+            },
+            Self::PlayBall => Some(EventType::PlayBall),
+            Self::GameOver { .. } => Some(EventType::GameOver),
+            Self::Recordkeeping { .. } => Some(EventType::Recordkeeping),
+            Self::InningStart { .. } => Some(EventType::InningStart),
+            Self::NowBatting { .. } => Some(EventType::NowBatting),
+            Self::InningEnd { .. } => Some(EventType::InningEnd),
+            Self::MoundVisit { .. } | Self::PitcherRemains { .. } | Self::PitcherSwap { .. } => {
+                Some(EventType::MoundVisit)
+            }
+            Self::Ball { .. }
+            | Self::Strike { .. }
+            | Self::Foul { .. }
+            | Self::Walk { .. }
+            | Self::HitByPitch { .. }
+            | Self::FairBall { .. }
+            | Self::StrikeOut { .. } => Some(EventType::Pitch),
+            Self::BatterToBase { .. }
+            | Self::HomeRun { .. }
+            | Self::CaughtOut { .. }
+            | Self::GroundedOut { .. }
+            | Self::ForceOut { .. }
+            | Self::ReachOnFieldersChoice { .. }
+            | Self::DoublePlayGrounded { .. }
+            | Self::DoublePlayCaught { .. }
+            | Self::ReachOnFieldingError { .. } => Some(EventType::Field),
+            Self::WeatherDelivery { .. } => Some(EventType::WeatherDelivery),
+            Self::FallingStar { .. } => Some(EventType::FallingStar),
+            Self::FallingStarOutcome { .. } => Some(EventType::Weather),
+            Self::WeatherShipment { .. } => Some(EventType::WeatherShipment),
+            Self::WeatherSpecialDelivery { .. } => Some(EventType::WeatherSpecialDelivery),
+            Self::Balk { .. } => Some(EventType::Balk),
+            Self::WeatherProsperity { .. } => Some(EventType::WeatherProsperity),
+            Self::PhotoContest { .. } => Some(EventType::PhotoContest),
+            Self::Party { .. } => Some(EventType::Party),
+            Self::WeatherReflection { .. } => Some(EventType::WeatherReflection),
+            Self::WeatherWither { .. } => Some(EventType::WeatherWither),
+            Self::LinealBeltTransfer { .. } => Some(EventType::LinealBeltTransfer),
+            Self::WeatherConsumption(_) => Some(EventType::WeatherConsumption),
+            Self::WeatherSimulacrum { .. } | Self::WeatherSimulacrumOffseason => {
+                Some(EventType::WeatherSimulacrum)
+            }
+        }
     }
-}
 
-fn unparse_fielders_for_play<S: Display>(fielders: &[PlacedPlayer<S>]) -> String {
-    match fielders.len() {
-        0 => panic!("0-fielders"),
-        1 => format!(", {} unassisted", fielders.first().unwrap()),
-        _ => format!(
-            ", {}",
-            fielders
-                .iter()
-                .map(PlacedPlayer::to_string)
-                .collect::<Vec<_>>()
-                .join(" to ")
-        ),
+    /// The batter of an at-bat event. None for pitches and other events that don't name the batter.
+    pub fn batter(&self) -> Option<&S> {
+        match self {
+            Self::NowBatting { batter, .. }
+            | Self::Walk { batter, .. }
+            | Self::HitByPitch { batter, .. }
+            | Self::FairBall { batter, .. }
+            | Self::StrikeOut { batter, .. }
+            | Self::BatterToBase { batter, .. }
+            | Self::HomeRun { batter, .. }
+            | Self::CaughtOut { batter, .. }
+            | Self::GroundedOut { batter, .. }
+            | Self::ForceOut { batter, .. }
+            | Self::ReachOnFieldersChoice { batter, .. }
+            | Self::DoublePlayGrounded { batter, .. }
+            | Self::DoublePlayCaught { batter, .. }
+            | Self::ReachOnFieldingError { batter, .. }
+            | Self::KnownBug {
+                bug: KnownBug::FirstBasemanChoosesAGhost { batter, .. },
+            } => Some(batter),
+            _ => None,
+        }
     }
-}
-fn unparse_scores_and_advances<S: Display>(scores: &[S], advances: &[RunnerAdvance<S>]) -> String {
-    once(String::new())
-        .chain(
-            scores
-                .iter()
-                .map(|runner| format!("<strong>{runner} scores!</strong>"))
-                .chain(advances.iter().map(|advance| advance.to_string())),
-        )
-        .collect::<Vec<_>>()
-        .join(" ")
-}
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
-#[strum_discriminants(derive(Display))]
-pub enum StartOfInningPitcher<S> {
-    Same {
-        emoji: S,
-        name: S,
-    },
-    Different {
-        leaving_emoji: Option<S>,
-        leaving_pitcher: PlacedPlayer<S>,
-        arriving_emoji: Option<S>,
-        arriving_pitcher: PlacedPlayer<S>,
-    },
-}
+    /// The runners who scored during an at-bat event or balk. This doesn't include the batter on a home run,
+    /// or runners who stole home.
+    pub fn scoring_runners(&self) -> &[S] {
+        match self {
+            Self::Walk { scores, .. }
+            | Self::HitByPitch { scores, .. }
+            | Self::BatterToBase { scores, .. }
+            | Self::HomeRun { scores, .. }
+            | Self::CaughtOut { scores, .. }
+            | Self::GroundedOut { scores, .. }
+            | Self::ForceOut { scores, .. }
+            | Self::ReachOnFieldersChoice { scores, .. }
+            | Self::DoublePlayGrounded { scores, .. }
+            | Self::DoublePlayCaught { scores, .. }
+            | Self::ReachOnFieldingError { scores, .. }
+            | Self::Balk { scores, .. } => scores,
+            _ => &[],
+        }
+    }
 
-/// Either an Out or an Error - e.g. for a Fielder's Choice.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
-#[strum_discriminants(derive(Display))]
-pub enum FieldingAttempt<S> {
-    Out {
-        out: RunnerOut<S>,
-    },
-    Error {
-        fielder: S,
-        error: FieldingErrorType,
-    },
-}
-impl<S: Display> Display for FieldingAttempt<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// The runners who advanced without scoring during an at-bat event or balk. This doesn't include the batter,
+    /// or runners who stole a base.
+    pub fn advancing_runners(&self) -> &[RunnerAdvance<S>] {
         match self {
-            Self::Out { out } => {
-                write!(f, "{} out at {}.", out.runner, out.base)
-            }
-            Self::Error { fielder, error } => {
-                let error = error.uppercase();
-                write!(f, "{error} by {fielder}.")
+            Self::Walk { advances, .. }
+            | Self::HitByPitch { advances, .. }
+            | Self::BatterToBase { advances, .. }
+            | Self::CaughtOut { advances, .. }
+            | Self::GroundedOut { advances, .. }
+            | Self::ForceOut { advances, .. }
+            | Self::ReachOnFieldersChoice { advances, .. }
+            | Self::DoublePlayGrounded { advances, .. }
+            | Self::DoublePlayCaught { advances, .. }
+            | Self::ReachOnFieldingError { advances, .. }
+            | Self::Balk { advances, .. } => advances,
+            _ => &[],
+        }
+    }
+
+    /// The fielders involved in a fielded play, in the order the ball went through them (e.g. SS, 2B, 1B for a 6-4-3 double play).
+    /// Plays with a single fielder return just that fielder. Empty for everything else, including [`KnownBug::FirstBasemanChoosesAGhost`].
+    pub fn fielders(&self) -> &[PlacedPlayer<S>] {
+        match self {
+            Self::GroundedOut { fielders, .. }
+            | Self::ForceOut { fielders, .. }
+            | Self::ReachOnFieldersChoice { fielders, .. }
+            | Self::DoublePlayGrounded { fielders, .. }
+            | Self::DoublePlayCaught { fielders, .. } => fielders,
+            Self::BatterToBase { fielder, .. }
+            | Self::CaughtOut {
+                caught_by: fielder, ..
             }
+            | Self::ReachOnFieldingError { fielder, .. } => std::slice::from_ref(fielder),
+            _ => &[],
         }
     }
-}
 
-/// A team's emoji and name, which is how teams are usually presented in mmolb.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct EmojiTeam<S> {
-    pub emoji: S,
-    pub name: S,
-}
-impl<S: Display> Display for EmojiTeam<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.emoji, self.name)
+    /// The crowd's cheer after a pitch, if any.
+    pub fn cheer(&self) -> Option<&Cheer> {
+        match self {
+            Self::Ball { cheer, .. }
+            | Self::Strike { cheer, .. }
+            | Self::Foul { cheer, .. }
+            | Self::Walk { cheer, .. }
+            | Self::HitByPitch { cheer, .. }
+            | Self::FairBall { cheer, .. }
+            | Self::StrikeOut { cheer, .. } => cheer.as_ref(),
+            _ => None,
+        }
     }
-}
 
-impl<S: AsRef<str>> EmojiTeam<S> {
-    pub fn as_ref(&self) -> EmojiTeam<&str> {
-        EmojiTeam {
-            emoji: self.emoji.as_ref(),
-            name: self.name.as_ref(),
+    /// The players who snapped photos of the aurora when the Geomagnetic Storms intensified during a pitch.
+    pub fn aurora_photos(&self) -> Option<&SnappedPhotos<S>> {
+        match self {
+            Self::Ball { aurora_photos, .. }
+            | Self::Strike { aurora_photos, .. }
+            | Self::Foul { aurora_photos, .. }
+            | Self::Walk { aurora_photos, .. }
+            | Self::HitByPitch { aurora_photos, .. }
+            | Self::FairBall { aurora_photos, .. }
+            | Self::StrikeOut { aurora_photos, .. } => aurora_photos.as_ref(),
+            _ => None,
         }
     }
-}
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct EmojiPlayer<S> {
-    pub emoji: S,
-    pub name: S,
-}
-impl<S: Display> Display for EmojiPlayer<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.emoji, self.name)
+
+    /// Whether two events describe the same play, ignoring flavor that doesn't change its outcome:
+    /// cheers, aurora photos and door prizes.
+    pub fn eq_play(&self, other: &Self) -> bool
+    where
+        S: PartialEq,
+    {
+        // Exhaustive, with every field of the flavored variants listed, so that new fields and variants aren't
+        // silently compared (or ignored).
+        match (self, other) {
+            (
+                Self::Ball {
+                    steals,
+                    count,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection,
+                    door_prizes: _,
+                    wither,
+                    efflorescence,
+                },
+                Self::Ball {
+                    steals: other_steals,
+                    count: other_count,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection: other_ejection,
+                    door_prizes: _,
+                    wither: other_wither,
+                    efflorescence: other_efflorescence,
+                },
+            ) => {
+                steals == other_steals
+                    && count == other_count
+                    && ejection == other_ejection
+                    && wither == other_wither
+                    && efflorescence == other_efflorescence
+            }
+            (
+                Self::Strike {
+                    strike,
+                    steals,
+                    count,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection,
+                    door_prizes: _,
+                    wither,
+                    efflorescence,
+                },
+                Self::Strike {
+                    strike: other_strike,
+                    steals: other_steals,
+                    count: other_count,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection: other_ejection,
+                    door_prizes: _,
+                    wither: other_wither,
+                    efflorescence: other_efflorescence,
+                },
+            ) => {
+                strike == other_strike
+                    && steals == other_steals
+                    && count == other_count
+                    && ejection == other_ejection
+                    && wither == other_wither
+                    && efflorescence == other_efflorescence
+            }
+            (
+                Self::Foul {
+                    foul,
+                    steals,
+                    count,
+                    cheer: _,
+                    aurora_photos: _,
+                    door_prizes: _,
+                    wither,
+                    efflorescence,
+                },
+                Self::Foul {
+                    foul: other_foul,
+                    steals: other_steals,
+                    count: other_count,
+                    cheer: _,
+                    aurora_photos: _,
+                    door_prizes: _,
+                    wither: other_wither,
+                    efflorescence: other_efflorescence,
+                },
+            ) => {
+                foul == other_foul
+                    && steals == other_steals
+                    && count == other_count
+                    && wither == other_wither
+                    && efflorescence == other_efflorescence
+            }
+            (
+                Self::Walk {
+                    batter,
+                    scores,
+                    advances,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection,
+                    wither,
+                },
+                Self::Walk {
+                    batter: other_batter,
+                    scores: other_scores,
+                    advances: other_advances,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection: other_ejection,
+                    wither: other_wither,
+                },
+            ) => {
+                batter == other_batter
+                    && scores == other_scores
+                    && advances == other_advances
+                    && ejection == other_ejection
+                    && wither == other_wither
+            }
+            (
+                Self::HitByPitch {
+                    batter,
+                    scores,
+                    advances,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection,
+                    door_prizes: _,
+                    wither,
+                    efflorescence,
+                },
+                Self::HitByPitch {
+                    batter: other_batter,
+                    scores: other_scores,
+                    advances: other_advances,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection: other_ejection,
+                    door_prizes: _,
+                    wither: other_wither,
+                    efflorescence: other_efflorescence,
+                },
+            ) => {
+                batter == other_batter
+                    && scores == other_scores
+                    && advances == other_advances
+                    && ejection == other_ejection
+                    && wither == other_wither
+                    && efflorescence == other_efflorescence
+            }
+            (
+                Self::FairBall {
+                    batter,
+                    fair_ball_type,
+                    destination,
+                    cheer: _,
+                    aurora_photos: _,
+                    door_prizes: _,
+                    efflorescence,
+                },
+                Self::FairBall {
+                    batter: other_batter,
+                    fair_ball_type: other_fair_ball_type,
+                    destination: other_destination,
+                    cheer: _,
+                    aurora_photos: _,
+                    door_prizes: _,
+                    efflorescence: other_efflorescence,
+                },
+            ) => {
+                batter == other_batter
+                    && fair_ball_type == other_fair_ball_type
+                    && destination == other_destination
+                    && efflorescence == other_efflorescence
+            }
+            (
+                Self::StrikeOut {
+                    foul,
+                    batter,
+                    strike,
+                    steals,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection,
+                    wither,
+                },
+                Self::StrikeOut {
+                    foul: other_foul,
+                    batter: other_batter,
+                    strike: other_strike,
+                    steals: other_steals,
+                    cheer: _,
+                    aurora_photos: _,
+                    ejection: other_ejection,
+                    wither: other_wither,
+                },
+            ) => {
+                foul == other_foul
+                    && batter == other_batter
+                    && strike == other_strike
+                    && steals == other_steals
+                    && ejection == other_ejection
+                    && wither == other_wither
+            }
+            (
+                Self::Ball { .. }
+                | Self::Strike { .. }
+                | Self::Foul { .. }
+                | Self::Walk { .. }
+                | Self::HitByPitch { .. }
+                | Self::FairBall { .. }
+                | Self::StrikeOut { .. },
+                _,
+            ) => false,
+            (
+                Self::ParseError { .. }
+                | Self::KnownBug { .. }
+                | Self::LiveNow { .. }
+                | Self::PitchingMatchup { .. }
+                | Self::Lineup { .. }
+                | Self::PlayBall
+                | Self::GameOver { .. }
+                | Self::Recordkeeping { .. }
+                | Self::InningStart { .. }
+                | Self::NowBatting { .. }
+                | Self::InningEnd { .. }
+                | Self::MoundVisit { .. }
+                | Self::PitcherRemains { .. }
+                | Self::PitcherSwap { .. }
+                | Self::BatterToBase { .. }
+                | Self::HomeRun { .. }
+                | Self::CaughtOut { .. }
+                | Self::GroundedOut { .. }
+                | Self::ForceOut { .. }
+                | Self::ReachOnFieldersChoice { .. }
+                | Self::DoublePlayGrounded { .. }
+                | Self::DoublePlayCaught { .. }
+                | Self::ReachOnFieldingError { .. }
+                | Self::WeatherDelivery { .. }
+                | Self::FallingStar { .. }
+                | Self::FallingStarOutcome { .. }
+                | Self::WeatherShipment { .. }
+                | Self::WeatherSpecialDelivery { .. }
+                | Self::Balk { .. }
+                | Self::WeatherProsperity { .. }
+                | Self::PhotoContest { .. }
+                | Self::Party { .. }
+                | Self::WeatherReflection { .. }
+                | Self::WeatherWither { .. }
+                | Self::LinealBeltTransfer { .. }
+                | Self::WeatherConsumption(_)
+                | Self::WeatherSimulacrum { .. }
+                | Self::WeatherSimulacrumOffseason,
+                _,
+            ) => self == other,
+        }
     }
-}
 
-impl<S: AsRef<str>> EmojiPlayer<S> {
-    pub fn as_ref(&self) -> EmojiPlayer<&str> {
-        EmojiPlayer {
-            emoji: self.emoji.as_ref(),
-            name: self.name.as_ref(),
+    /// The ROBO-UMP ejection (or failed ejection) that happened during this event, if any.
+    pub fn ejection(&self) -> Option<&Ejection<S>> {
+        // Exhaustive so that new variants carrying an ejection aren't silently missed.
+        match self {
+            Self::Ball { ejection, .. }
+            | Self::Strike { ejection, .. }
+            | Self::Walk { ejection, .. }
+            | Self::HitByPitch { ejection, .. }
+            | Self::StrikeOut { ejection, .. }
+            | Self::BatterToBase { ejection, .. }
+            | Self::HomeRun { ejection, .. }
+            | Self::CaughtOut { ejection, .. }
+            | Self::GroundedOut { ejection, .. }
+            | Self::ForceOut { ejection, .. }
+            | Self::ReachOnFieldersChoice { ejection, .. }
+            | Self::DoublePlayGrounded { ejection, .. }
+            | Self::DoublePlayCaught { ejection, .. }
+            | Self::ReachOnFieldingError { ejection, .. } => ejection.as_ref(),
+            Self::ParseError { .. }
+            | Self::KnownBug { .. }
+            | Self::LiveNow { .. }
+            | Self::PitchingMatchup { .. }
+            | Self::Lineup { .. }
+            | Self::PlayBall
+            | Self::GameOver { .. }
+            | Self::Recordkeeping { .. }
+            | Self::InningStart { .. }
+            | Self::NowBatting { .. }
+            | Self::InningEnd { .. }
+            | Self::MoundVisit { .. }
+            | Self::PitcherRemains { .. }
+            | Self::PitcherSwap { .. }
+            | Self::Foul { .. }
+            | Self::FairBall { .. }
+            | Self::WeatherDelivery { .. }
+            | Self::FallingStar { .. }
+            | Self::FallingStarOutcome { .. }
+            | Self::WeatherShipment { .. }
+            | Self::WeatherSpecialDelivery { .. }
+            | Self::Balk { .. }
+            | Self::WeatherProsperity { .. }
+            | Self::PhotoContest { .. }
+            | Self::Party { .. }
+            | Self::WeatherReflection { .. }
+            | Self::WeatherWither { .. }
+            | Self::LinealBeltTransfer { .. }
+            | Self::WeatherConsumption(_)
+            | Self::WeatherSimulacrum { .. }
+            | Self::WeatherSimulacrumOffseason => None,
         }
     }
-}
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct PlacedPlayer<S> {
-    pub name: S,
-    pub place: Place,
-}
-impl<S: Display> Display for PlacedPlayer<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.place, self.name)
+    /// Whether the batter got a hit: reaching base on a single, double or triple, or a home run.
+    pub fn is_hit(&self) -> bool {
+        matches!(self, Self::BatterToBase { .. } | Self::HomeRun { .. })
     }
-}
 
-impl<S: AsRef<str>> PlacedPlayer<S> {
-    pub fn as_ref(&self) -> PlacedPlayer<&str> {
-        PlacedPlayer {
-            name: self.name.as_ref(),
-            place: self.place,
-        }
+    /// Whether any runs scored on the play, including the batter on a home run and runners who stole home.
+    pub fn is_scoring_play(&self) -> bool {
+        let steals = match self {
+            Self::HomeRun { .. } => return true,
+            Self::Ball { steals, .. }
+            | Self::Strike { steals, .. }
+            | Self::Foul { steals, .. }
+            | Self::StrikeOut { steals, .. } => steals.as_slice(),
+            _ => &[],
+        };
+        !self.scoring_runners().is_empty()
+            || steals
+                .iter()
+                .any(|steal| steal.base == Base::Home && !steal.caught)
     }
-}
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct RunnerOut<S> {
-    pub runner: S,
-    pub base: BaseNameVariant,
-}
-impl<S: Display> Display for RunnerOut<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} out at {}.", self.runner, self.base)
+    /// Whether the play ends the batter's plate appearance: walks, hit by pitches, strike outs and
+    /// every outcome of a ball in play. Pitches that keep the plate appearance going, such as a
+    /// [`Self::FairBall`] whose outcome is the next event, are false.
+    pub fn is_plate_appearance_ending(&self) -> bool {
+        matches!(
+            self,
+            Self::Walk { .. }
+                | Self::HitByPitch { .. }
+                | Self::StrikeOut { .. }
+                | Self::BatterToBase { .. }
+                | Self::HomeRun { .. }
+                | Self::CaughtOut { .. }
+                | Self::GroundedOut { .. }
+                | Self::ForceOut { .. }
+                | Self::ReachOnFieldersChoice { .. }
+                | Self::DoublePlayGrounded { .. }
+                | Self::DoublePlayCaught { .. }
+                | Self::ReachOnFieldingError { .. }
+                | Self::KnownBug {
+                    bug: KnownBug::FirstBasemanChoosesAGhost { .. },
+                }
+        )
     }
-}
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct RunnerAdvance<S> {
-    pub runner: S,
-    pub base: Base,
-}
-impl<S: Display> Display for RunnerAdvance<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} to {} base.", self.runner, self.base)
+
+    /// The number of outs made on the play, including runners caught stealing.
+    /// The [`KnownBug::FirstBasemanChoosesAGhost`] counts as an out, even though the batter ends up on base.
+    pub fn outs_recorded(&self) -> u8 {
+        let caught_stealing =
+            |steals: &[BaseSteal<S>]| steals.iter().filter(|steal| steal.caught).count() as u8;
+        match self {
+            Self::Ball { steals, .. } | Self::Strike { steals, .. } | Self::Foul { steals, .. } => {
+                caught_stealing(steals)
+            }
+            Self::StrikeOut { steals, .. } => 1 + caught_stealing(steals),
+            Self::CaughtOut { .. }
+            | Self::GroundedOut { .. }
+            | Self::ForceOut { .. }
+            | Self::ReachOnFieldersChoice {
+                result: FieldingAttempt::Out { .. },
+                ..
+            }
+            | Self::KnownBug {
+                bug: KnownBug::FirstBasemanChoosesAGhost { .. },
+            } => 1,
+            Self::DoublePlayGrounded { .. } | Self::DoublePlayCaught { .. } => 2,
+            _ => 0,
+        }
     }
-}
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct BaseSteal<S> {
-    pub runner: S,
-    pub base: Base,
-    pub caught: bool,
-}
-impl<S: Display> Display for BaseSteal<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.caught {
-            true => write!(
-                f,
-                "{} is caught stealing {}.",
-                self.runner,
-                self.base.to_base_str()
-            ),
-            false => match self.base {
-                Base::Home => write!(
-                    f,
-                    "<strong>{} steals {}!</strong>",
-                    self.runner,
-                    self.base.to_base_str()
-                ),
-                _ => write!(f, "{} steals {}!", self.runner, self.base.to_base_str()),
-            },
-        }
-    }
-}
-impl<S> TryFrom<BaseSteal<S>> for RunnerOut<S> {
-    type Error = ();
-    fn try_from(value: BaseSteal<S>) -> Result<Self, Self::Error> {
-        if value.caught {
-            Ok(RunnerOut {
-                runner: value.runner,
-                base: BaseNameVariant::basic_name(value.base),
-            })
-        } else {
-            Err(())
+
+    /// The (balls, strikes) count after a pitch that keeps the plate appearance going.
+    ///
+    /// None for every other event, including walks and strike outs: their count is implied by the
+    /// previous pitch's count plus the ball or strike that ended the plate appearance.
+    pub fn pitch_count(&self) -> Option<(u8, u8)> {
+        match self {
+            Self::Ball { count, .. } | Self::Strike { count, .. } | Self::Foul { count, .. } => {
+                Some(*count)
+            }
+            _ => None,
         }
     }
-}
-impl<S> TryFrom<BaseSteal<S>> for RunnerAdvance<S> {
-    type Error = ();
-    fn try_from(value: BaseSteal<S>) -> Result<Self, Self::Error> {
-        if !value.caught {
-            Ok(RunnerAdvance {
-                runner: value.runner,
-                base: value.base,
+
+    /// Every base running movement in the event, in the order they should be applied: steals, outs,
+    /// runs scored, runners advancing, and finally the batter reaching base (or scoring, on a home run).
+    /// The automatic runner placed on second at the start of an extra inning counts as an advance.
+    ///
+    /// Batters who are put out without becoming a runner (e.g. a strike out or a fly out) aren't included.
+    pub fn base_running_events(&self) -> Vec<BaseRunningEvent<&S>>
+    where
+        S: PartialEq,
+    {
+        let advance = |runner, base| BaseRunningEvent::Advance(RunnerAdvance { runner, base });
+        fn out<S>(out: &RunnerOut<S>) -> BaseRunningEvent<&S> {
+            BaseRunningEvent::Out(RunnerOut {
+                runner: &out.runner,
+                base: out.base,
             })
-        } else {
-            Err(())
         }
-    }
-}
-
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
-#[strum_discriminants(derive(Display))]
-pub enum FallingStarOutcome<S> {
-    Injury,
-    Retired(Option<S>),
-    InfusionI,
-    InfusionII,
-    InfusionIII,
-    DeflectedHarmlessly,
-}
-
-impl<S: Display> FallingStarOutcome<S> {
-    pub fn unparse<'a>(
-        &self,
-        context: impl Into<UnparsingContext<'a>>,
-        event_index: Option<u16>,
-        player_name: &str,
-    ) -> String {
-        let context = context.into();
-        let was_is =
-            if Breakpoints::Season5TenseChange.after(context.season, context.day, event_index) {
-                "is"
-            } else {
-                "was"
-            };
 
+        let mut events = Vec::new();
         match self {
-            FallingStarOutcome::Injury => {
-                format!("{player_name} {was_is} injured by the extreme force of the impact!")
-            }
-            FallingStarOutcome::Retired(None) => format!("😇 {player_name} retired from MMOLB!"),
-            FallingStarOutcome::Retired(Some(replacement_player_name)) => {
-                format!("😇 {player_name} retired from MMOLB! {replacement_player_name} {was_is} called up to take their place.")
+            Self::InningStart {
+                automatic_runner: Some(runner),
+                ..
+            } => events.push(advance(runner, Base::Second)),
+            Self::Ball { steals, .. }
+            | Self::Strike { steals, .. }
+            | Self::Foul { steals, .. }
+            | Self::StrikeOut { steals, .. } => {
+                events.extend(steals.iter().map(|steal| {
+                    BaseRunningEvent::Steal(BaseSteal {
+                        runner: &steal.runner,
+                        base: steal.base,
+                        caught: steal.caught,
+                    })
+                }));
             }
-            FallingStarOutcome::InfusionI => {
-                format!("{player_name} {was_is} infused with a glimmer of celestial energy!")
+            Self::ForceOut {
+                out: runner_out, ..
             }
-            FallingStarOutcome::InfusionII => {
-                let began_begins = if Breakpoints::Season5TenseChange.after(
-                    context.season,
-                    context.day,
-                    event_index,
-                ) {
-                    "begins"
-                } else {
-                    "began"
-                };
-
-                format!("{player_name} {began_begins} to glow brightly with celestial energy!")
+            | Self::ReachOnFieldersChoice {
+                result: FieldingAttempt::Out { out: runner_out },
+                ..
             }
-            FallingStarOutcome::InfusionIII => format!(
-                "{player_name} {was_is} fully charged with an abundance of celestial energy!"
-            ),
-            FallingStarOutcome::DeflectedHarmlessly => {
-                let deflected_deflects = if Breakpoints::Season5TenseChange.after(
-                    context.season,
-                    context.day,
-                    event_index,
-                ) {
-                    "deflects"
-                } else {
-                    "deflected"
-                };
-
-                format!("It {deflected_deflects} off {player_name} harmlessly.")
+            | Self::DoublePlayCaught {
+                out_two: runner_out,
+                ..
+            } => events.push(out(runner_out)),
+            Self::DoublePlayGrounded {
+                out_one, out_two, ..
+            } => {
+                events.push(out(out_one));
+                events.push(out(out_two));
             }
+            _ => (),
         }
-    }
-}
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum ItemAffixes<S> {
-    None,
-    PrefixSuffix(Vec<ItemPrefix>, Vec<ItemSuffix>),
-    RareName(S),
-}
+        events.extend(self.scoring_runners().iter().map(BaseRunningEvent::Score));
+        events.extend(
+            self.advancing_runners()
+                .iter()
+                .map(|runner| advance(&runner.runner, runner.base)),
+        );
 
-impl<S: AsRef<str>> ItemAffixes<S> {
-    pub fn to_ref(&self) -> ItemAffixes<&str> {
         match self {
-            ItemAffixes::RareName(s) => ItemAffixes::RareName(s.as_ref()),
-            ItemAffixes::PrefixSuffix(prefix, suffix) => {
-                ItemAffixes::PrefixSuffix(prefix.clone(), suffix.clone())
+            Self::Walk { batter, .. }
+            | Self::HitByPitch { batter, .. }
+            | Self::ForceOut { batter, .. }
+            | Self::ReachOnFieldersChoice { batter, .. }
+            | Self::ReachOnFieldingError { batter, .. }
+            | Self::KnownBug {
+                bug: KnownBug::FirstBasemanChoosesAGhost { batter, .. },
+            } => events.push(advance(batter, Base::First)),
+            Self::BatterToBase {
+                batter, distance, ..
+            } => {
+                let base = match distance {
+                    Distance::Single => Base::First,
+                    Distance::Double => Base::Second,
+                    Distance::Triple => Base::Third,
+                };
+                events.push(advance(batter, base));
             }
-            ItemAffixes::None => ItemAffixes::None,
+            Self::HomeRun { batter, .. } => events.push(BaseRunningEvent::Score(batter)),
+            // The batter is safe at first if both outs were other runners
+            Self::DoublePlayGrounded {
+                batter,
+                out_one,
+                out_two,
+                ..
+            } if out_one.runner != *batter && out_two.runner != *batter => {
+                events.push(advance(batter, Base::First))
+            }
+            _ => (),
         }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Item<S> {
-    pub item_emoji: S,
-    pub item: ItemName,
-    pub affixes: ItemAffixes<S>,
-}
 
-impl<S: AsRef<str>> Item<S> {
-    pub fn to_ref(&self) -> Item<&str> {
-        Item {
-            item_emoji: self.item_emoji.as_ref(),
-            item: self.item,
-            affixes: self.affixes.to_ref(),
-        }
+        events
     }
-}
-
-impl<S: Display> Display for Item<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let Item {
-            item_emoji,
-            item,
-            affixes,
-        } = self;
 
-        match affixes {
-            ItemAffixes::None => write!(f, "{item_emoji} {item}"),
-            ItemAffixes::PrefixSuffix(prefix, suffix) => {
-                let prefix = prefix.iter().map(|p| format!("{p} ")).collect::<String>();
-                let suffix = suffix.iter().map(|s| format!(" {s}")).collect::<String>();
-                write!(f, "{item_emoji} {prefix}{item}{suffix}")
+    /// Every distinct player mentioned by the event: batters, fielders, runners, ejections,
+    /// photographers, prize winners and so on. Team names and emojis aren't included.
+    pub fn mentioned_players(&self) -> impl Iterator<Item = &S>
+    where
+        S: PartialEq,
+    {
+        let mut players = Vec::new();
+        for name in self.player_names() {
+            if !players.contains(&name) {
+                players.push(name);
             }
-            ItemAffixes::RareName(rare_name) => write!(f, "{item_emoji} {rare_name} {item}"),
         }
+        players.into_iter()
     }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum Delivery<S> {
-    Successful {
-        team: EmojiTeam<S>,
-        player: Option<S>,
-        item: Item<S>,
-        equipped: bool,
-        discarded: Option<Item<S>>,
-    },
-    NoSpace {
-        item: Item<S>,
-    },
-}
 
-impl<S: Display> Delivery<S> {
-    pub fn unparse<'a>(
-        &self,
-        context: impl Into<UnparsingContext<'a>>,
-        event_index: Option<u16>,
-        delivery_label: &str,
-    ) -> String {
-        let context = context.into();
+    /// Every player name in the event, in no particular order and possibly with repeats.
+    fn player_names(&self) -> Vec<&S> {
+        let mut names = Vec::new();
         match self {
-            Self::Successful {
-                team,
-                player,
-                item,
-                equipped,
-                discarded,
+            Self::ParseError { .. }
+            | Self::PlayBall
+            | Self::GameOver { .. }
+            | Self::Recordkeeping { .. }
+            | Self::InningEnd { .. }
+            | Self::MoundVisit { .. }
+            | Self::WeatherProsperity { .. }
+            | Self::WeatherReflection { .. }
+            | Self::LinealBeltTransfer { .. }
+            | Self::WeatherSimulacrum { .. }
+            | Self::WeatherSimulacrumOffseason
+            | Self::LiveNow { .. } => (),
+            Self::KnownBug { bug } => match bug {
+                KnownBug::FirstBasemanChoosesAGhost {
+                    batter,
+                    first_baseman,
+                } => names.extend([batter, first_baseman]),
+                KnownBug::NoOneProspers => (),
+            },
+            Self::PitchingMatchup {
+                home_pitcher,
+                away_pitcher,
+                ..
+            } => names.extend([home_pitcher, away_pitcher]),
+            Self::Lineup { players, .. } => names.extend(players.iter().map(|player| &player.name)),
+            Self::InningStart {
+                automatic_runner,
+                pitcher_status,
+                ..
             } => {
-                let received_text = if *equipped {
-                    " equips "
-                } else {
-                    received_text(context.season, context.day, event_index)
-                };
-                let discarded_text = discarded_text(context.season, context.day, event_index);
-
-                let discarded = match discarded {
-                    Some(discarded) => format!("{discarded_text}{discarded}."),
-                    None => String::new(),
-                };
-
-                let player = player
-                    .as_ref()
-                    .map(|player| format!(" {player}"))
-                    .unwrap_or_default();
-
-                // There's this extra word "from" in the equipped message
-                let from_text = if *equipped { "from " } else { "" };
-
-                format!(
-                    "{team}{player}{received_text}{item} {from_text}{delivery_label}.{discarded}"
-                )
+                names.extend(automatic_runner);
+                match pitcher_status {
+                    Some(StartOfInningPitcher::Same { name, .. }) => names.push(name),
+                    Some(StartOfInningPitcher::Different {
+                        leaving_pitcher,
+                        arriving_pitcher,
+                        ..
+                    }) => names.extend([&leaving_pitcher.name, &arriving_pitcher.name]),
+                    None => (),
+                }
             }
-            Self::NoSpace { item } => {
-                let discard_text = if Breakpoints::Season8ItemDiscardedMessageChange.after(
-                    context.season,
-                    context.day,
-                    event_index,
-                ) {
-                    " is discarded as no player can use it."
-                } else if Breakpoints::Season5TenseChange.after(
-                    context.season,
-                    context.day,
-                    event_index,
-                ) {
-                    " is discarded as no player has space."
-                } else {
-                    " was discarded as no player had space."
-                };
-
-                format!("{item}{discard_text}")
+            Self::NowBatting { batter, .. } => names.push(batter),
+            Self::PitcherRemains { remaining_pitcher } => names.push(&remaining_pitcher.name),
+            Self::PitcherSwap {
+                leaving_pitcher,
+                arriving_pitcher_name,
+                ..
+            } => names.extend([&leaving_pitcher.name, arriving_pitcher_name]),
+            Self::Ball {
+                steals,
+                aurora_photos,
+                ejection,
+                door_prizes,
+                wither,
+                efflorescence,
+                ..
             }
-        }
-    }
+            | Self::Strike {
+                steals,
+                aurora_photos,
+                ejection,
+                door_prizes,
+                wither,
+                efflorescence,
+                ..
+            } => {
+                names.extend(steals.iter().map(|steal| &steal.runner));
+                push_pitch_player_names(&mut names, aurora_photos, ejection, wither);
+                push_prize_player_names(&mut names, door_prizes, efflorescence);
+            }
+            Self::Foul {
+                steals,
+                aurora_photos,
+                door_prizes,
+                wither,
+                efflorescence,
+                ..
+            } => {
+                names.extend(steals.iter().map(|steal| &steal.runner));
+                push_pitch_player_names(&mut names, aurora_photos, &None, wither);
+                push_prize_player_names(&mut names, door_prizes, efflorescence);
+            }
+            Self::Walk {
+                batter,
+                scores,
+                advances,
+                aurora_photos,
+                ejection,
+                wither,
+                ..
+            } => {
+                names.push(batter);
+                push_scores_and_advances(&mut names, scores, advances);
+                push_pitch_player_names(&mut names, aurora_photos, ejection, wither);
+            }
+            Self::HitByPitch {
+                batter,
+                scores,
+                advances,
+                aurora_photos,
+                ejection,
+                door_prizes,
+                wither,
+                efflorescence,
+                ..
+            } => {
+                names.push(batter);
+                push_scores_and_advances(&mut names, scores, advances);
+                push_pitch_player_names(&mut names, aurora_photos, ejection, wither);
+                push_prize_player_names(&mut names, door_prizes, efflorescence);
+            }
+            Self::FairBall {
+                batter,
+                aurora_photos,
+                door_prizes,
+                efflorescence,
+                ..
+            } => {
+                names.push(batter);
+                push_pitch_player_names(&mut names, aurora_photos, &None, &None);
+                push_prize_player_names(&mut names, door_prizes, efflorescence);
+            }
+            Self::StrikeOut {
+                batter,
+                steals,
+                aurora_photos,
+                ejection,
+                wither,
+                ..
+            } => {
+                names.push(batter);
+                names.extend(steals.iter().map(|steal| &steal.runner));
+                push_pitch_player_names(&mut names, aurora_photos, ejection, wither);
+            }
+            Self::BatterToBase {
+                batter,
+                fielder,
+                scores,
+                advances,
+                ejection,
+                ..
+            }
+            | Self::ReachOnFieldingError {
+                batter,
+                fielder,
+                scores,
+                advances,
+                ejection,
+                ..
+            }
+            | Self::CaughtOut {
+                batter,
+                caught_by: fielder,
+                scores,
+                advances,
+                ejection,
+                ..
+            } => {
+                names.extend([batter, &fielder.name]);
+                push_scores_and_advances(&mut names, scores, advances);
+                push_pitch_player_names(&mut names, &None, ejection, &None);
+            }
+            Self::HomeRun {
+                batter,
+                scores,
+                ejection,
+                ..
+            } => {
+                names.push(batter);
+                names.extend(scores);
+                push_pitch_player_names(&mut names, &None, ejection, &None);
+            }
+            Self::GroundedOut {
+                batter,
+                fielders,
+                scores,
+                advances,
+                ejection,
+                ..
+            } => {
+                names.push(batter);
+                names.extend(fielders.iter().map(|fielder| &fielder.name));
+                push_scores_and_advances(&mut names, scores, advances);
+                push_pitch_player_names(&mut names, &None, ejection, &None);
+            }
+            Self::ForceOut {
+                batter,
+                fielders,
+                out,
+                scores,
+                advances,
+                ejection,
+                ..
+            }
+            | Self::DoublePlayCaught {
+                batter,
+                fielders,
+                out_two: out,
+                scores,
+                advances,
+                ejection,
+                ..
+            } => {
+                names.extend([batter, &out.runner]);
+                names.extend(fielders.iter().map(|fielder| &fielder.name));
+                push_scores_and_advances(&mut names, scores, advances);
+                push_pitch_player_names(&mut names, &None, ejection, &None);
+            }
+            Self::ReachOnFieldersChoice {
+                batter,
+                fielders,
+                result,
+                scores,
+                advances,
+                ejection,
+            } => {
+                names.push(batter);
+                names.extend(fielders.iter().map(|fielder| &fielder.name));
+                match result {
+                    FieldingAttempt::Out { out } => names.push(&out.runner),
+                    FieldingAttempt::Error { fielder, .. } => names.push(fielder),
+                }
+                push_scores_and_advances(&mut names, scores, advances);
+                push_pitch_player_names(&mut names, &None, ejection, &None);
+            }
+            Self::DoublePlayGrounded {
+                batter,
+                fielders,
+                out_one,
+                out_two,
+                scores,
+                advances,
+                ejection,
+                ..
+            } => {
+                names.extend([batter, &out_one.runner, &out_two.runner]);
+                names.extend(fielders.iter().map(|fielder| &fielder.name));
+                push_scores_and_advances(&mut names, scores, advances);
+                push_pitch_player_names(&mut names, &None, ejection, &None);
+            }
+            Self::WeatherDelivery { delivery } | Self::WeatherSpecialDelivery { delivery } => {
+                names.extend(delivery.recipient())
+            }
+            Self::WeatherShipment { deliveries } => {
+                names.extend(deliveries.iter().filter_map(Delivery::recipient))
+            }
+            Self::FallingStar { player_name } => names.push(player_name),
+            Self::FallingStarOutcome {
+                deflection,
+                player_name,
+                outcome,
+            } => {
+                names.extend(deflection);
+                names.push(player_name);
+                if let FallingStarOutcome::Retired(Some(replacement)) = outcome {
+                    names.push(replacement);
+                }
+            }
+            Self::Balk {
+                pitcher,
+                scores,
+                advances,
+            } => {
+                names.push(pitcher);
+                push_scores_and_advances(&mut names, scores, advances);
+            }
+            Self::PhotoContest {
+                winning_player,
+                losing_player,
+                ..
+            } => names.extend([winning_player, losing_player]),
+            Self::Party {
+                pitcher_name,
+                batter_name,
+                ..
+            } => names.extend([pitcher_name, batter_name]),
+            Self::WeatherWither {
+                player, contained, ..
+            } => {
+                names.push(&player.name);
+                match contained {
+                    ContainResult::NoContain => (),
+                    ContainResult::SuccessfulContain {
+                        contained_player_name,
+                        replacement_player_name,
+                    } => names.extend([contained_player_name, replacement_player_name]),
+                    ContainResult::FailedContain { target_player_name } => {
+                        names.push(target_player_name)
+                    }
+                }
+            }
+            Self::WeatherConsumption(consumption) => match consumption {
+                WeatherConsumptionEvents::StartContest {
+                    batting_team_player,
+                    pitching_team_player,
+                    ..
+                }
+                | WeatherConsumptionEvents::Consumes {
+                    batting_team_player,
+                    pitching_team_player,
+                    ..
+                } => names.extend([&batting_team_player.name, &pitching_team_player.name]),
+                WeatherConsumptionEvents::EndContest { winning_player, .. } => {
+                    names.push(&winning_player.name)
+                }
+                WeatherConsumptionEvents::EndContestTie { .. } => (),
+            },
+        }
+        names
+    }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub enum WitherResult {
-    Resisted,
-    ResistedEffloresced,
-    ResistedImmune,
-    Corrupted,
+impl<S: AsRef<str>> MentionsPlayer for ParsedEventMessage<S> {
+    fn mentions(&self, name: &str) -> bool {
+        self.player_names()
+            .into_iter()
+            .any(|player| player.as_ref() == name)
+    }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub enum ContainResult<S> {
-    NoContain,
-    SuccessfulContain {
-        contained_player_name: S,
-        replacement_player_name: S,
-    },
-    FailedContain {
-        target_player_name: S,
-    },
+fn push_scores_and_advances<'a, S>(
+    names: &mut Vec<&'a S>,
+    scores: &'a [S],
+    advances: &'a [RunnerAdvance<S>],
+) {
+    names.extend(scores);
+    names.extend(advances.iter().map(|advance| &advance.runner));
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub enum PartyDurabilityLoss<S> {
-    Both(u8),
-    OneProtected {
-        protected_player_name: S,
-        unprotected_player_name: S,
-        durability_loss: u8,
-    },
+fn push_pitch_player_names<'a, S>(
+    names: &mut Vec<&'a S>,
+    aurora_photos: &'a Option<SnappedPhotos<S>>,
+    ejection: &'a Option<Ejection<S>>,
+    wither: &'a Option<WitherStruggle<S>>,
+) {
+    if let Some(photos) = aurora_photos {
+        names.extend([&photos.first_player.name, &photos.second_player.name]);
+    }
+    match ejection {
+        Some(Ejection::Ejection {
+            ejected_player,
+            replacement,
+            ..
+        }) => names.extend([&ejected_player.name, replacement.player_name()]),
+        Some(Ejection::FailedEjection { player_names }) => names.extend(player_names),
+        None => (),
+    }
+    if let Some(wither) = wither {
+        names.push(&wither.target.name);
+        names.extend(&wither.source_name);
+    }
 }
 
-impl<S: Display> Display for PartyDurabilityLoss<S> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            PartyDurabilityLoss::Both(durability_loss) => {
-                write!(f, "Both players lose {durability_loss} Durability.")
-            }
-            PartyDurabilityLoss::OneProtected {
-                protected_player_name,
-                unprotected_player_name,
-                durability_loss,
-            } => {
-                write!(
-                    f,
-                    "{unprotected_player_name} loses {durability_loss} Durability, but \
-                    {protected_player_name}'s Prolific Greater Boon protects them from harm."
-                )
-            }
+fn push_prize_player_names<'a, S>(
+    names: &mut Vec<&'a S>,
+    door_prizes: &'a [DoorPrize<S>],
+    efflorescence: &'a [Efflorescence<S>],
+) {
+    for door_prize in door_prizes {
+        names.push(&door_prize.player);
+        if let Some(prize) = &door_prize.prize {
+            names.extend(prize.equipping_players());
         }
     }
+    names.extend(
+        efflorescence
+            .iter()
+            .map(|efflorescence| &efflorescence.player),
+    );
 }
 
-impl<S: Display> Display for ContainResult<S> {
+/// Lets a closure be used as a [`Display`], so pieces of an event message can be formatted
+/// lazily instead of being built up as Strings first.
+struct DisplayFn<F>(F);
+
+impl<F: Fn(&mut Formatter<'_>) -> std::fmt::Result> Display for DisplayFn<F> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ContainResult::NoContain => Ok(()),
-            ContainResult::SuccessfulContain {
-                replacement_player_name,
-                contained_player_name,
-            } => {
-                write!(f, ", and Contained {contained_player_name}. They were replaced by {replacement_player_name}.")
-            }
-            ContainResult::FailedContain { target_player_name } => {
-                write!(
-                    f,
-                    ", and tried to Contain {target_player_name}, but they wouldn't budge."
-                )
-            }
-        }
+        (self.0)(f)
     }
 }
 
-fn old_space(context: UnparsingContext, event_index: Option<u16>) -> &'static str {
-    if Breakpoints::S2D169.before(context.season, context.day, event_index) {
-        " "
-    } else {
-        ""
+/// Each item with the separator before it, e.g. " a b" for a " " separator.
+fn prefixed<'a, I>(separator: &'a str, items: I) -> impl Display + 'a
+where
+    I: IntoIterator + Clone + 'a,
+    I::Item: Display,
+{
+    DisplayFn(move |f: &mut Formatter<'_>| {
+        items
+            .clone()
+            .into_iter()
+            .try_for_each(|item| write!(f, "{separator}{item}"))
+    })
+}
+
+/// The value, or nothing.
+fn or_empty<T: Display>(value: Option<T>) -> impl Display {
+    DisplayFn(move |f: &mut Formatter<'_>| match &value {
+        Some(value) => value.fmt(f),
+        None => Ok(()),
+    })
+}
+
+/// The value followed by a space, or nothing.
+fn with_space<T: Display>(value: &Option<T>) -> impl Display + '_ {
+    DisplayFn(move |f: &mut Formatter<'_>| match value {
+        Some(value) => write!(f, "{value} "),
+        None => Ok(()),
+    })
+}
+
+fn ordinal(number: u8) -> impl Display {
+    let suffix = match number {
+        0 => panic!("Should not have 0th innings"),
+        11..=13 => "th",
+        _ => match number % 10 {
+            1 => "st",
+            2 => "nd",
+            3 => "rd",
+            _ => "th",
+        },
+    };
+    DisplayFn(move |f: &mut Formatter<'_>| write!(f, "{number}{suffix}"))
+}
+
+/// Keeps generated messages within the invariants the parser guarantees and [`ParsedEventMessage::unparse`]
+/// relies on: innings start at 1, counts never go past 3-2, and plays always have at least one fielder.
+#[cfg(feature = "arbitrary")]
+mod arbitrary_constraints {
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    use super::PlacedPlayer;
+
+    pub(super) fn inning_number(u: &mut Unstructured<'_>) -> Result<u8> {
+        u.int_in_range(1..=u8::MAX)
+    }
+
+    pub(super) fn count(u: &mut Unstructured<'_>) -> Result<(u8, u8)> {
+        Ok((u.int_in_range(0..=3)?, u.int_in_range(0..=2)?))
+    }
+
+    pub(super) fn fielders<'a, S: Arbitrary<'a>>(
+        u: &mut Unstructured<'a>,
+    ) -> Result<Vec<PlacedPlayer<S>>> {
+        let first = u.arbitrary()?;
+        let rest: Vec<PlacedPlayer<S>> = u.arbitrary()?;
+        Ok(std::iter::once(first).chain(rest).collect())
     }
 }
 
-/// See individual variant documentation for an example of each bug, and the known properties of their events.
+fn unparse_fielders<S: Display>(fielders: &[PlacedPlayer<S>]) -> impl Display + '_ {
+    if fielders.is_empty() {
+        panic!("0-fielders")
+    }
+    DisplayFn(move |f: &mut Formatter<'_>| match fielders {
+        [fielder] => write!(f, " to {fielder}"),
+        _ => write!(f, ", {}", fielders.iter().format(" to ")),
+    })
+}
+
+fn unparse_fielders_for_play<S: Display>(fielders: &[PlacedPlayer<S>]) -> impl Display + '_ {
+    if fielders.is_empty() {
+        panic!("0-fielders")
+    }
+    DisplayFn(move |f: &mut Formatter<'_>| match fielders {
+        [fielder] => write!(f, ", {fielder} unassisted"),
+        _ => write!(f, ", {}", fielders.iter().format(" to ")),
+    })
+}
+
+fn unparse_scores_and_advances<'a, S: Display>(
+    scores: &'a [S],
+    advances: &'a [RunnerAdvance<S>],
+) -> impl Display + 'a {
+    DisplayFn(move |f: &mut Formatter<'_>| {
+        for runner in scores {
+            write!(f, " <strong>{runner} scores!</strong>")?;
+        }
+        prefixed(" ", advances).fmt(f)
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[strum_discriminants(derive(Display))]
-pub enum KnownBug<S> {
-    /// https://mmolb.com/watch/6851bb34f419fdc04f9d0ed5 "Genevieve Hirose reaches on a fielder's choice out, 1B N. Kitagawa"
-    ///
-    /// Potentially fixed on S2D152
-    ///
-    /// Properties:
-    /// - They are called fielders choice outs
-    /// - They (so far) always occur when no runners are on base
-    /// - They count as an out for the count
-    /// - the batter does end up on base
-    FirstBasemanChoosesAGhost { batter: S, first_baseman: S },
-    /// https://mmolb.com/watch/68758c796154982c31f5d803?event=412 ""
-    ///
-    /// Potentially fixed as a side effect of the Season 3 Pre-Superstar Break Update, s3d112
-    ///
-    /// NPC teams cannot earn Tokens. Before s3d112, teams that got shutout didn't prosper.
-    /// Thus, if an npc team shut out a player team, then an empty prosperity message would be displayed.
-    ///
-    /// Properties
-    /// - It displays as an empty string
-    /// - It is a prosperity event in which neither team earned any tokens.
-    NoOneProspers,
+pub enum StartOfInningPitcher<S> {
+    Same {
+        emoji: S,
+        name: S,
+    },
+    Different {
+        leaving_emoji: Option<S>,
+        leaving_pitcher: PlacedPlayer<S>,
+        arriving_emoji: Option<S>,
+        arriving_pitcher: PlacedPlayer<S>,
+    },
 }
 
-impl<S: Display> Display for KnownBug<S> {
+/// Either an Out or an Error - e.g. for a Fielder's Choice.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum_discriminants(derive(Display))]
+pub enum FieldingAttempt<S> {
+    Out {
+        out: RunnerOut<S>,
+    },
+    Error {
+        fielder: S,
+        error: FieldingErrorType,
+    },
+}
+impl<S> FieldingAttempt<S> {
+    /// The fielder who made the error. None for an out, since out messages only name the runner.
+    pub fn fielder(&self) -> Option<&S> {
+        match self {
+            Self::Out { .. } => None,
+            Self::Error { fielder, .. } => Some(fielder),
+        }
+    }
+
+    /// The player named in the attempt: the runner who was put out, or the fielder who made the error.
+    pub fn player(&self) -> &S {
+        match self {
+            Self::Out { out } => &out.runner,
+            Self::Error { fielder, .. } => fielder,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Self::Error { .. })
+    }
+}
+
+impl<S: Display> Display for FieldingAttempt<S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            KnownBug::FirstBasemanChoosesAGhost {
-                batter,
-                first_baseman,
-            } => {
-                write!(
-                    f,
-                    "{batter} reaches on a fielder's choice out, 1B {first_baseman}"
-                )
+            Self::Out { out } => {
+                write!(f, "{} out at {}.", out.runner, out.base)
             }
-            KnownBug::NoOneProspers => {
-                write!(f, "")
+            Self::Error { fielder, error } => {
+                let error = error.uppercase();
+                write!(f, "{error} by {fielder}.")
             }
         }
     }
 }
 
-fn _check(_: &str) -> Infallible {
-    unreachable!("This is dead code that exists for a strum parse_err_fn")
+/// A team's emoji and name, which is how teams are usually presented in mmolb.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EmojiTeam<S> {
+    pub emoji: S,
+    pub name: S,
+}
+impl<S: Display> Display for EmojiTeam<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.emoji, self.name)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumString, IntoStaticStr, Display)]
-#[strum(
-    parse_err_fn = check,
-    parse_err_ty = Infallible
-)]
-pub enum Cheer {
-    #[strum(to_string = "A tremendous cheer fills the air")]
-    ATremendousCheerFillsTheAir,
-    #[strum(to_string = "A thunderous cheer echoes across the field")]
-    AThunderousCheerEchoesAcrossTheField,
-    #[strum(to_string = "Chants thunder around the stadium")]
-    ChantsThunderAroundTheStadium,
-    #[strum(to_string = "Everyone cheers at once")]
-    EveryoneCheersAtOnce,
-    #[strum(to_string = "Everyone is chanting the team's name")]
-    EveryoneIsChantingTheTeamsName,
-    #[strum(to_string = "The cheers drown out everything else")]
-    TheCheersDrownOutEverythingElse,
-    #[strum(to_string = "Cheers roll through the stadium")]
-    CheersRollThroughTheStadium,
-    #[strum(to_string = "Supporters pound on the railings")]
-    SupportersPoundOnTheRailings,
-    #[strum(to_string = "Hands clap and whistles pierce the air")]
-    HandsClapAndWhistlesPierceTheAir,
-    #[strum(to_string = "Home fans stomp their feet in unison")]
-    HomeFansStompTheirFeetInUnison,
-    #[strum(to_string = "No one is sitting anymore")]
-    NoOneIsSittingAnymore,
-    #[strum(to_string = "The hometown supporters give a roar")]
-    TheHometownSupportersGiveARoar,
-    #[strum(to_string = "The hometown section is deafening")]
-    TheHomeTownSectionIsDeafening,
-    #[strum(to_string = "The home faithful go berserk")]
-    TheHomeFaithfulGoBerserk,
-    #[strum(to_string = "Fans are losing their minds")]
-    TheFansAreLosingTheirMinds,
-    #[strum(to_string = "The stands explode in noise")]
-    TheStandsExplodeInNoise,
-    #[strum(to_string = "It's a wall of sound")]
-    ItsAWallOfSound,
-    #[strum(to_string = "The entire ballpark is rocking")]
-    TheEntireBallparkIsRocking,
-    #[strum(to_string = "The ballpark comes alive")]
-    TheBallparkComesAlive,
-    #[strum(to_string = "Excitement pours from the crowd")]
-    ExcitementPoursFromTheCrowd,
-    #[strum(to_string = "The home supporters can't contain themselves")]
-    TheHomeSupportersCantContainThemselves,
-    #[strum(to_string = "The crowd lets out a collective howl")]
-    TheCrowdLetsOutACollectiveHowl,
-    #[strum(to_string = "A rumble of excitement rolls through the stadium")]
-    ARumbleOfExcitementRollsThroughTheStadium,
-    #[strum(to_string = "Banners wave wildly")]
-    BannersWaveWildly,
-    #[strum(to_string = "The noise level skyrockets")]
-    TheNoiseLevelSkyrockets,
-    #[strum(to_string = "The energy in here is palpable")]
-    TheEnergyInHereIsPalpable,
-    #[strum(to_string = "The ballpark erupts in applause")]
-    TheBallparkEruptsInApplause,
-    #[strum(to_string = "The bleachers are booming")]
-    TheBleachersAreBooming,
-    #[strum(to_string = "Fans slap the walls in rhythm")]
-    FansSlapTheWallsInRhythm,
-    #[strum(to_string = "The hometown fans roar their support")]
-    TheHometownFansRoarTheirSupport,
-    #[strum(to_string = "Noise rains down from the seats")]
-    NoiseRainsDownFromTheSeats,
-    #[strum(to_string = "Fans wave their arms wildly")]
-    FansWaveTheirArmsWildly,
-    #[strum(to_string = "Energy pulses from the stands")]
-    EnergyPulsesFromTheStands,
-    #[strum(to_string = "The stands shake with noise")]
-    TheStandsShakeWithNoise,
-    #[strum(to_string = "The ballpark is in an uproar")]
-    TheBallparkIsInAnUproar,
-    #[strum(to_string = "The stadium swell with cheers")]
-    TheStadiumSwellWithCheers,
-    #[strum(to_string = "The stadium erupts")]
-    TheStadiumErupts,
-    #[strum(to_string = "Fans roar in support")]
-    FansRoarInSupport,
-    #[strum(to_string = "A chant grows louder and louder")]
-    AChantGrowsLouderAndLouder,
-    #[strum(to_string = "Fans shout encouragement")]
-    FansShoutEncouragement,
-    #[strum(to_string = "The stadium is buzzing")]
-    TheStadiumIsBuzzing,
-    #[strum(to_string = "The hometown faithful are loving this")]
-    TheHometownFaithfulAreLovingThis,
-    #[strum(to_string = "Fans whoop and holler")]
-    FansWhoopAndHoller,
-    #[strum(to_string = "The home crowd is fired up")]
-    TheHomeCrowdIsFiredUp,
-    #[strum(to_string = "It's pandemonium in the stands")]
-    ItsPandemoniumInTheStands,
-    #[strum(to_string = "The noise is overwhelming")]
-    TheNoiseIsOverwhelming,
-    #[strum(to_string = "Home supporters whistle and cheer")]
-    HomeSupportersWhistleAndCheer,
-    #[strum(to_string = "The home fans make themselves heard")]
-    TheHomeFansMakeThemselvesHeard,
-    #[strum(to_string = "The crowd rallies behind the team")]
-    TheCrowdRalliesBehindTheTeam,
-    #[strum(to_string = "The stands are rumbling")]
-    TheStandsAreRumbling,
-    #[strum(to_string = "It's pure pandemonium")]
-    ItsPurePandemonium,
-    #[strum(to_string = "The place is rocking")]
-    ThePlaceIsRocking,
-    #[strum(to_string = "A wave of cheers sweeps the stadium")]
-    AWaveOfCheersSweepsTheStadium,
-    #[strum(to_string = "The hometown fans roar")]
-    TheHometownFansRoar,
-    #[strum(to_string = "The fans thunder their approval")]
-    TheFansThunderTheirApproval,
-    #[strum(to_string = "Supporters yell at full volume")]
-    SupportersYellAtFullVolume,
-    #[strum(to_string = "The crowd goes absolutely bonkers")]
-    TheCrowdGoesAbsolutelyBonkers,
-    #[strum(to_string = "The cheering is relentless")]
-    TheCheeringIsRelentless,
-    #[strum(to_string = "Fans pump their fists in the air")]
-    FansPumpTheirFistsInTheAir,
-    #[strum(to_string = "The home crowd won't stop cheering")]
-    TheHomeCrowdWontStopCheering,
-    #[strum(to_string = "The crowd erupts into cheers")]
-    TheCrowdEruptsIntoCheers,
-    #[strum(to_string = "A roar builds from the seats")]
-    ARoarBuildsFromTheSeats,
-    #[strum(to_string = "Everyone in the stands is on their feet")]
-    EveryoneInTheStandsIsOnTheirFeet,
-    #[strum(to_string = "It's a roar from the rafters")]
-    ItsARoarFromTheRafters,
-    #[strum(to_string = "Chants rise from the bleachers")]
-    ChantsRiseFromTheBleachers,
-    #[strum(to_string = "A cheer rips through the park")]
-    ACheerRipsThroughThePark,
-    #[strum(to_string = "Everyone's clapping in rhythm")]
-    EveryonesClappingInRhythm,
-    #[strum(to_string = "The crowd is pumped up")]
-    TheCrowdIsPumpedUp,
-    #[strum(to_string = "The stadium swells with cheers")]
-    TheStadiumSwellsWithCheers,
-    #[strum(to_string = "You can barely hear the announcer")]
-    YouCanBarelyHearTheAnnouncer,
-    #[strum(to_string = "The fans are fired up")]
-    TheFansAreFiredUp,
-    #[strum(to_string = "The decibels rise to a frenzy")]
-    TheDecibelsRiseToAFrenzy,
-    #[strum(to_string = "The energy in the park surges")]
-    TheEnergyInTheParkSurges,
-    #[strum(to_string = "Fans yell themselves hoarse")]
-    FansYellThemselvesHoarse,
-    #[strum(to_string = "A mighty cheer erupts")]
-    AMightyCheerErupts,
-    #[strum(to_string = "The stands are electric")]
-    TheStandsAreElectric,
-    #[strum(to_string = "Fans jump and shout")]
-    FansJumpAndShout,
-    #[strum(to_string = "The cheers echo off the walls")]
-    TheCheersEchoOffTheWalls,
-    #[strum(to_string = "You can feel the stands vibrating")]
-    YouCanFeelTheStandsVibrating,
-    #[strum(to_string = "Noise levels spike")]
-    NoiseLevelsSpike,
-    #[strum(to_string = "Everyone is cheering at once")]
-    EveryoneIsCheeringAtOnce,
-    #[strum(to_string = "A huge cheer erupts")]
-    AHugeCheerErupts,
-    #[strum(to_string = "The noise just keeps building")]
-    TheNoiseJustKeepsBuilding,
-    #[strum(to_string = "The faithful are shouting at the top of their lungs")]
-    TheFaithfulAreShoutingAtTheTopOfTheirLungs,
-    #[strum(to_string = "They cheer like there's no tomorrow")]
-    TheyCheerLikeTheresNoTomorrow,
-    #[strum(to_string = "You can feel the hype building")]
-    YouCanFeelTheHypeBuilding,
-    #[strum(to_string = "The excitement is off the charts")]
-    TheExcitementIsOffTheCharts,
-    #[strum(to_string = "Excitement surges through the park")]
-    ExcitementSurgesThroughThePark,
-    #[strum(to_string = "Every fan is making noise")]
-    EveryFanIsMakingNoise,
-    #[strum(to_string = "The supporters fuel their team")]
-    TheSupportersFuelTheirTeam,
-    #[strum(to_string = "The park vibrates with noise")]
-    TheParkVibratesWithNoise,
-    #[strum(to_string = "The stands are shaking")]
-    TheStandsAreShaking,
-    #[strum(to_string = "The fans bellow encouragement")]
-    TheFansBellowEncouragement,
-    #[strum(to_string = "The stadium shakes with excitement")]
-    TheStadiumShakesWithExcitement,
-    #[strum(to_string = "Supporters wave their banners high")]
-    SupportersWaveTheirBannersHigh,
-    #[strum(to_string = "The faithful rise as one")]
-    TheFaithfulRiseAsOne,
-    #[strum(to_string = "Cheers cascade from every section")]
-    CheersCascadeFromEverySection,
-    #[strum(to_string = "The crowd belts out the team's chant")]
-    TheCrowdBeltsOutTheTeamsChant,
-    #[strum(to_string = "The fans scream for their heroes")]
-    TheFansScreamForTheirHeroes,
-    #[strum(to_string = "The crowd is ecstatic")]
-    TheCrowdIsEcstatic,
-    #[strum(to_string = "The crowd is pumped")]
-    TheCrowdIsPumped,
-
-    #[strum(default)]
-    Unknown(String),
-}
-
-impl Cheer {
-    pub fn new(value: &str) -> Self {
-        let r = Cheer::from_str(value).expect("This error type is infallible");
-
-        if matches!(r, Cheer::Unknown(_)) {
-            tracing::warn!("Failed to match cheer '{value}'");
-        }
-
-        r
-    }
-
-    pub fn as_str(&self) -> &str {
-        match self {
-            Self::Unknown(unknown) => unknown,
-            _ => self.into(),
+impl<S: AsRef<str>> EmojiTeam<S> {
+    pub fn as_ref(&self) -> EmojiTeam<&str> {
+        EmojiTeam {
+            emoji: self.emoji.as_ref(),
+            name: self.name.as_ref(),
         }
     }
 
-    pub fn unparse<'a>(
-        &self,
-        context: impl Into<UnparsingContext<'a>>,
-        event_index: Option<u16>,
-    ) -> String {
-        let context = context.into();
-        if Breakpoints::CheersGetEmoji.before(context.season, context.day, event_index) {
-            format!(" {self}!")
-        } else {
-            format!(" 📣 {self}!")
-        }
+    /// Whether both are the same team, ignoring whitespace in their names. Emojis must match exactly.
+    ///
+    /// Early in season 1 some events were missing the spaces between words of team names
+    /// (see [`crate::team_feed::ParsedTeamFeedEventText::GameResult`]), so exact comparison fails for them.
+    ///
+    /// ```
+    /// use mmolb_parsing::parsed_event::EmojiTeam;
+    ///
+    /// let team = EmojiTeam { emoji: "🦜", name: "Cabo Verde Parrots" };
+    /// assert!(team.matches(&EmojiTeam { emoji: "🦜", name: "CaboVerdeParrots" }));
+    /// ```
+    pub fn matches(&self, other: &EmojiTeam<impl AsRef<str>>) -> bool {
+        let without_whitespace = |name: &str| {
+            name.chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+        };
+
+        self.emoji.as_ref() == other.emoji.as_ref()
+            && without_whitespace(self.name.as_ref()) == without_whitespace(other.name.as_ref())
     }
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumString, IntoStaticStr, Display)]
-#[strum(
-    parse_err_fn = check,
-    parse_err_ty = Infallible
-)]
-pub enum EjectionReason {
-    // Sportsmanship violations
-    #[strum(to_string = "eating a hotdog")]
-    EatingAHotdog,
-    #[strum(to_string = "spitting")]
-    Spitting,
-    #[strum(to_string = "looking at them the wrong way")]
-    LookingAtThemTheWrongWay,
-    #[strum(to_string = "whispering something to another player")]
-    WhisperingSomethingToAnotherPlayer,
-    #[strum(to_string = "dancing")]
-    Dancing,
-    #[strum(to_string = "not looking excited enough")]
-    NotLookingExcitedEnough,
-    #[strum(to_string = "picking their nose")]
-    PickingTheirNose,
-    #[strum(to_string = "drinking beer")]
-    DrinkingBeer,
-    #[strum(to_string = "taking a phone call")]
-    TakingAPhoneCall,
-    #[strum(to_string = "using a foreign substance")]
-    UsingAForeignSubstance,
-    #[strum(to_string = "eating nachos")]
-    EatingNachos,
-    #[strum(to_string = "chewing gum too loud")]
-    ChewingGumTooLoud,
-    #[strum(to_string = "texting during play")]
-    TextingDuringPlay,
-
-    // Uniform violations
-    #[strum(to_string = "hat worn at improper rotational value")]
-    HatWornAtImproperRotationalValue,
-    #[strum(to_string = "mismatched socks")]
-    MismatchedSocks,
-    #[strum(to_string = "wrinkled shirt")]
-    WrinkledShirt,
-    #[strum(to_string = "shoe untied")]
-    ShoeUntied,
-
-    // Communication violations
-    #[strum(to_string = "making weird hand signals")]
-    MakingWeirdHandSignals,
-    #[strum(to_string = "laughing")]
-    Laughing,
-    #[strum(to_string = "something they said earlier in the locker room")]
-    SomethingTheySaidEarlierInTheLockerRoom,
-    #[strum(to_string = "telling a bad joke")]
-    TellingABadJoke,
-    #[strum(to_string = "winking at someone in the crowd")]
-    WinkingAtSomeoneInTheCrowd,
-    #[strum(to_string = "saying a bad word")]
-    SayingABadWord,
-    #[strum(to_string = "humming")]
-    Humming,
-
-    #[strum(default)]
-    Unknown(String),
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EmojiPlayer<S> {
+    pub emoji: S,
+    pub name: S,
 }
-
-impl EjectionReason {
-    pub fn new(value: &str) -> Self {
-        let r = EjectionReason::from_str(value).expect("This error type is infallible");
-
-        if matches!(r, EjectionReason::Unknown(_)) {
-            tracing::warn!("Failed to match ejection reason '{value}'");
-        }
-
-        r
-    }
-
-    pub fn unparse(&self) -> String {
-        format!("{self}")
+impl<S: Display> Display for EmojiPlayer<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.emoji, self.name)
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumString, IntoStaticStr, Display)]
-#[strum(
-    parse_err_fn = check,
-    parse_err_ty = Infallible
-)]
-pub enum ViolationType {
-    Sportsmanship,
-    Uniform,
-    Communication,
-
-    #[strum(default)]
-    Unknown(String),
-}
-
-impl ViolationType {
-    pub fn new(value: &str) -> Self {
-        let r = ViolationType::from_str(value).expect("This error type is infallible");
-
-        if matches!(r, ViolationType::Unknown(_)) {
-            tracing::warn!("Failed to match violation type '{value}'");
+impl<S: AsRef<str>> EmojiPlayer<S> {
+    pub fn as_ref(&self) -> EmojiPlayer<&str> {
+        EmojiPlayer {
+            emoji: self.emoji.as_ref(),
+            name: self.name.as_ref(),
         }
-
-        r
-    }
-
-    pub fn unparse(&self) -> String {
-        format!("{self}")
     }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct SnappedPhotos<S> {
-    pub first_team_emoji: S,
-    pub first_player: PlacedPlayer<S>,
-    pub second_team_emoji: S,
-    pub second_player: PlacedPlayer<S>,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PlacedPlayer<S> {
+    pub name: S,
+    pub place: Place,
 }
-
-impl<S: Display> SnappedPhotos<S> {
-    pub fn unparse(&self) -> String {
-        format!(
-            " The Geomagnetic Storms Intensify! {} {} and {} {} snapped photos of the aurora.",
-            self.first_team_emoji, self.first_player, self.second_team_emoji, self.second_player,
-        )
+impl<S: Display> Display for PlacedPlayer<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.place, self.name)
     }
 }
 
-impl<S: AsRef<str>> SnappedPhotos<S> {
-    pub fn as_ref(&self) -> SnappedPhotos<&str> {
-        SnappedPhotos {
-            first_team_emoji: self.first_team_emoji.as_ref(),
-            first_player: self.first_player.as_ref(),
-            second_team_emoji: self.second_team_emoji.as_ref(),
-            second_player: self.second_player.as_ref(),
+impl<S: AsRef<str>> PlacedPlayer<S> {
+    pub fn as_ref(&self) -> PlacedPlayer<&str> {
+        PlacedPlayer {
+            name: self.name.as_ref(),
+            place: self.place,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
-pub enum EjectionReplacement<S> {
-    BenchPlayer { player_name: S },
-    RosterPlayer { player: PlacedPlayer<S> },
+impl<'a> PlacedPlayer<&'a str> {
+    /// Splits a string like "1B Geo Kerr" into the player's place and name,
+    /// applying the same checks on the name as when parsing events.
+    ///
+    /// ```
+    /// use mmolb_parsing::{enums::Place, parsed_event::PlacedPlayer};
+    ///
+    /// let player = PlacedPlayer::parse("1B Geo Kerr").unwrap();
+    /// assert_eq!(player, PlacedPlayer { name: "Geo Kerr", place: Place::FirstBaseman });
+    /// ```
+    pub fn parse(input: &'a str) -> Option<Self> {
+        placed_player_eof(input).ok().map(|(_, player)| player)
+    }
 }
 
-impl<S: AsRef<str>> EjectionReplacement<S> {
-    pub fn as_ref(&self) -> EjectionReplacement<&str> {
-        match self {
-            EjectionReplacement::BenchPlayer { player_name } => EjectionReplacement::BenchPlayer {
-                player_name: player_name.as_ref(),
-            },
-            EjectionReplacement::RosterPlayer { player } => EjectionReplacement::RosterPlayer {
-                player: player.as_ref(),
-            },
-        }
+impl FromStr for PlacedPlayer<String> {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PlacedPlayer::parse(s)
+            .map(|player| player.map_string(str::to_string))
+            .ok_or("Couldn't split into a place and player name")
     }
 }
 
-impl<S> EjectionReplacement<S> {
-    pub fn player_name(&self) -> &S {
-        match self {
-            EjectionReplacement::BenchPlayer { player_name } => player_name,
-            EjectionReplacement::RosterPlayer { player } => &player.name,
-        }
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RunnerOut<S> {
+    pub runner: S,
+    pub base: BaseNameVariant,
+}
+impl<S: Display> Display for RunnerOut<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} out at {}.", self.runner, self.base)
     }
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum Ejection<S> {
-    Ejection {
-        team: EmojiTeam<S>,
-        ejected_player: PlacedPlayer<S>,
-        violation_type: ViolationType,
-        reason: EjectionReason,
-        replacement: EjectionReplacement<S>,
-    },
-    FailedEjection {
-        player_names: [S; 2],
-    },
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RunnerAdvance<S> {
+    pub runner: S,
+    pub base: Base,
 }
-
-impl<S: Display> Ejection<S> {
-    pub fn unparse(&self) -> String {
-        match self {
-            Ejection::Ejection { team, ejected_player, violation_type, reason, replacement } => {
-                match replacement {
-                    EjectionReplacement::BenchPlayer { player_name } => format!(
-                        " 🤖 ROBO-UMP ejected {} {} for a {} Violation ({}). Bench Player {} takes their place.",
-                        team,
-                        ejected_player,
-                        violation_type,
-                        reason,
-                        player_name,
-                    ),
-                    EjectionReplacement::RosterPlayer { player } => format!(
-                        " 🤖 ROBO-UMP ejected {} {} for a {} Violation ({}). {} {} takes the mound.",
-                        team,
-                        ejected_player,
-                        violation_type,
-                        reason,
-                        team.emoji,
-                        player,
-                    ),
-                }
-            }
-            Ejection::FailedEjection { player_names: [n1, n2] } => format!(
-                " 🤖 ROBO-UMP attempted an ejection, but {}, {} would not budge.", n1, n2,
-            )
-        }
+impl<S: Display> Display for RunnerAdvance<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} to {} base.", self.runner, self.base)
     }
 }
-
-impl<S: AsRef<str>> Ejection<S> {
-    // This does clone violation_type and reason, which may (rarely) hold strings.
-    // Perhaps it should be named something other than as_ref?
-    pub fn as_ref(&self) -> Ejection<&str> {
-        match self {
-            Ejection::Ejection {
-                team,
-                ejected_player,
-                violation_type,
-                reason,
-                replacement,
-            } => Ejection::Ejection {
-                team: team.as_ref(),
-                ejected_player: ejected_player.as_ref(),
-                violation_type: violation_type.clone(),
-                reason: reason.clone(),
-                replacement: replacement.as_ref(),
-            },
-            Ejection::FailedEjection {
-                player_names: [n1, n2],
-            } => Ejection::FailedEjection {
-                player_names: [n1.as_ref(), n2.as_ref()],
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BaseSteal<S> {
+    pub runner: S,
+    pub base: Base,
+    pub caught: bool,
+}
+impl<S: Display> Display for BaseSteal<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.caught {
+            true => write!(
+                f,
+                "{} is caught stealing {}.",
+                self.runner,
+                self.base.to_base_str()
+            ),
+            false => match self.base {
+                Base::Home => write!(
+                    f,
+                    "<strong>{} steals {}!</strong>",
+                    self.runner,
+                    self.base.to_base_str()
+                ),
+                _ => write!(f, "{} steals {}!", self.runner, self.base.to_base_str()),
             },
         }
     }
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum ItemEquip<S> {
-    None,
-    Discarded,
-    Equipped {
-        player_name: S,
-        discarded_item: Option<Item<S>>,
-    },
-}
-
-impl<S> ItemEquip<S> {
-    pub fn is_none(&self) -> bool {
-        matches!(self, ItemEquip::None)
+impl<S> TryFrom<BaseSteal<S>> for RunnerOut<S> {
+    type Error = ();
+    fn try_from(value: BaseSteal<S>) -> Result<Self, Self::Error> {
+        if value.caught {
+            Ok(RunnerOut {
+                runner: value.runner,
+                base: BaseNameVariant::basic_name(value.base),
+            })
+        } else {
+            Err(())
+        }
     }
 }
-
-impl<S: AsRef<str>> ItemEquip<S> {
-    pub fn to_ref(&self) -> ItemEquip<&str> {
-        match self {
-            ItemEquip::None => ItemEquip::None,
-            ItemEquip::Discarded => ItemEquip::Discarded,
-            ItemEquip::Equipped {
-                player_name,
-                discarded_item,
-            } => ItemEquip::Equipped {
-                player_name: player_name.as_ref(),
-                discarded_item: discarded_item.as_ref().map(|i| i.to_ref()),
-            },
+impl<S> TryFrom<BaseSteal<S>> for RunnerAdvance<S> {
+    type Error = ();
+    fn try_from(value: BaseSteal<S>) -> Result<Self, Self::Error> {
+        if !value.caught {
+            Ok(RunnerAdvance {
+                runner: value.runner,
+                base: value.base,
+            })
+        } else {
+            Err(())
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct ItemPrize<S> {
-    pub item: Item<S>,
-    pub equip: ItemEquip<S>,
+/// Any movement of a runner on the bases, so that base state can be tracked with a single type.
+/// See [`ParsedEventMessage::base_running_events`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BaseRunningEvent<S> {
+    /// A runner (or the batter) reached a base without scoring.
+    Advance(RunnerAdvance<S>),
+    Out(RunnerOut<S>),
+    /// A stolen base attempt, whether or not the runner was caught.
+    Steal(BaseSteal<S>),
+    /// A runner (or the batter, on a home run) scored.
+    Score(S),
 }
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum Prize<S> {
-    Tokens(u16),
-    Items(Vec<ItemPrize<S>>),
+impl<S> From<RunnerAdvance<S>> for BaseRunningEvent<S> {
+    fn from(value: RunnerAdvance<S>) -> Self {
+        BaseRunningEvent::Advance(value)
+    }
 }
-
-impl<S: Display> Prize<S> {
-    pub fn unparse(&self) -> String {
-        match self {
-            Prize::Tokens(tokens) => format!("{tokens} 🪙"),
-            Prize::Items(items) => {
-                let has_equip = items.iter().any(|i| !i.equip.is_none());
-
-                items
-                    .iter()
-                    .map(|prize| match &prize.equip {
-                        ItemEquip::None => prize.item.to_string(),
-                        ItemEquip::Discarded => {
-                            format!("{} is discarded; nobody can use it", prize.item)
-                        }
-                        ItemEquip::Equipped {
-                            player_name,
-                            discarded_item,
-                        } => {
-                            let discard = match discarded_item {
-                                None => String::new(),
-                                Some(discarded_item) => {
-                                    format!(". They discard their {discarded_item}")
-                                }
-                            };
-                            format!(
-                                "{player_name} equips {} from the Door Prize{discard}",
-                                prize.item
-                            )
-                        }
-                    })
-                    .collect::<Vec<_>>()
-                    .join(if has_equip { ". " } else { ", " })
-            }
-        }
+impl<S> From<RunnerOut<S>> for BaseRunningEvent<S> {
+    fn from(value: RunnerOut<S>) -> Self {
+        BaseRunningEvent::Out(value)
     }
 }
-
-impl<S: AsRef<str>> Prize<S> {
-    pub fn to_ref(&self) -> Prize<&str> {
-        match self {
-            Prize::Items(items) => Prize::Items(
-                items
-                    .iter()
-                    .map(|prize| ItemPrize {
-                        item: prize.item.to_ref(),
-                        equip: prize.equip.to_ref(),
-                    })
-                    .collect(),
-            ),
-            Prize::Tokens(t) => Prize::Tokens(*t),
-        }
+impl<S> From<BaseSteal<S>> for BaseRunningEvent<S> {
+    fn from(value: BaseSteal<S>) -> Self {
+        BaseRunningEvent::Steal(value)
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct DoorPrize<S> {
-    pub player: S,
-    /// None when they don't win.
-    pub prize: Option<Prize<S>>,
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum_discriminants(derive(Display))]
+pub enum FallingStarOutcome<S> {
+    Injury,
+    Retired(Option<S>),
+    InfusionI,
+    InfusionII,
+    InfusionIII,
+    DeflectedHarmlessly,
 }
 
-impl<S: Display> DoorPrize<S> {
-    pub fn unparse(&self) -> String {
-        match &self.prize {
-            Some(prize) => {
-                let punct = match prize {
-                    Prize::Items(items) if items.iter().any(|item| !item.equip.is_none()) => "!",
-                    _ => ":",
-                };
-                format!(
-                    "🥳 {} won a Door Prize{punct} {}.",
-                    self.player,
-                    prize.unparse()
-                )
-            }
-            None => format!("🥳 {} didn't win a Door Prize.", self.player),
-        }
+impl<S> FallingStarOutcome<S> {
+    /// ```
+    /// use mmolb_parsing::parsed_event::FallingStarOutcome;
+    ///
+    /// assert!(FallingStarOutcome::<&str>::Injury.is_injury());
+    /// assert!(!FallingStarOutcome::<&str>::InfusionI.is_injury());
+    /// ```
+    pub fn is_injury(&self) -> bool {
+        matches!(self, FallingStarOutcome::Injury)
     }
-}
 
-impl<S: AsRef<str>> DoorPrize<S> {
-    pub fn to_ref(&self) -> DoorPrize<&str> {
-        DoorPrize {
-            player: self.player.as_ref(),
-            prize: self.prize.as_ref().map(Prize::to_ref),
-        }
+    /// Whether the player retired. See [`FallingStarOutcome::replacement`] for who replaced them.
+    ///
+    /// ```
+    /// use mmolb_parsing::parsed_event::FallingStarOutcome;
+    ///
+    /// assert!(FallingStarOutcome::Retired(Some("Ivan Ivanov")).is_retirement());
+    /// assert!(FallingStarOutcome::<&str>::Retired(None).is_retirement());
+    /// assert!(!FallingStarOutcome::<&str>::Injury.is_retirement());
+    /// ```
+    pub fn is_retirement(&self) -> bool {
+        matches!(self, FallingStarOutcome::Retired(_))
     }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct WitherStruggle<S> {
-    pub team_emoji: S,
-    pub target: PlacedPlayer<S>,
-    // Source was not named in s6
-    pub source_name: Option<S>,
-}
 
-impl<S: Display> Display for WitherStruggle<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.source_name {
-            None => write!(
-                f,
-                "{} {} struggles against the 🥀 Wither.",
-                self.team_emoji, self.target
-            ),
-            Some(source_name) => write!(
-                f,
-                "{source_name} is trying to spread the 🥀 Wither to {} {}!",
-                self.team_emoji, self.target
-            ),
+    /// The name of the player who replaced a retired player. None if there was no retirement,
+    /// or if the replacement wasn't named.
+    ///
+    /// ```
+    /// use mmolb_parsing::parsed_event::FallingStarOutcome;
+    ///
+    /// assert_eq!(FallingStarOutcome::Retired(Some("Ivan Ivanov")).replacement(), Some(&"Ivan Ivanov"));
+    /// assert_eq!(FallingStarOutcome::<&str>::Retired(None).replacement(), None);
+    /// assert_eq!(FallingStarOutcome::<&str>::InfusionII.replacement(), None);
+    /// ```
+    pub fn replacement(&self) -> Option<&S> {
+        match self {
+            FallingStarOutcome::Retired(replacement) => replacement.as_ref(),
+            _ => None,
         }
     }
-}
 
-impl<S: AsRef<str>> WitherStruggle<S> {
-    pub fn to_ref(&self) -> WitherStruggle<&str> {
-        WitherStruggle {
-            team_emoji: self.team_emoji.as_ref(),
-            source_name: self.source_name.as_ref().map(AsRef::as_ref),
-            target: self.target.as_ref(),
-        }
+    /// ```
+    /// use mmolb_parsing::parsed_event::FallingStarOutcome;
+    ///
+    /// assert!(FallingStarOutcome::<&str>::DeflectedHarmlessly.deflected());
+    /// assert!(!FallingStarOutcome::<&str>::InfusionIII.deflected());
+    /// ```
+    pub fn deflected(&self) -> bool {
+        matches!(self, FallingStarOutcome::DeflectedHarmlessly)
     }
-}
-
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub enum EfflorescenceOutcome {
-    Grow([GrowAttributeChange; 2]),
-    Effloresce,
-}
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Efflorescence<S> {
-    pub player: S,
-    pub outcome: EfflorescenceOutcome,
+    /// ```
+    /// use mmolb_parsing::parsed_event::FallingStarOutcome;
+    ///
+    /// assert_eq!(FallingStarOutcome::<&str>::InfusionI.infusion_tier(), Some(1));
+    /// assert_eq!(FallingStarOutcome::<&str>::InfusionII.infusion_tier(), Some(2));
+    /// assert_eq!(FallingStarOutcome::<&str>::InfusionIII.infusion_tier(), Some(3));
+    /// assert_eq!(FallingStarOutcome::<&str>::Injury.infusion_tier(), None);
+    /// assert_eq!(FallingStarOutcome::<&str>::Retired(None).infusion_tier(), None);
+    /// assert_eq!(FallingStarOutcome::<&str>::DeflectedHarmlessly.infusion_tier(), None);
+    /// ```
+    pub fn infusion_tier(&self) -> Option<u8> {
+        match self {
+            FallingStarOutcome::InfusionI => Some(1),
+            FallingStarOutcome::InfusionII => Some(2),
+            FallingStarOutcome::InfusionIII => Some(3),
+            FallingStarOutcome::Injury
+            | FallingStarOutcome::Retired(_)
+            | FallingStarOutcome::DeflectedHarmlessly => None,
+        }
+    }
 }
 
-impl<S: Display> Efflorescence<S> {
-    pub fn unparse(&self) -> String {
-        match &self.outcome {
-            EfflorescenceOutcome::Grow(changes) => {
-                let mut s = String::new();
-                write!(s, "{} grew: ", self.player).unwrap();
-                for (i, change) in changes.iter().enumerate() {
-                    let prefix = if i == 0 { "" } else { ", " };
-                    write!(s, "{prefix}{:+} {}", change.amount, change.attribute).unwrap();
-                }
-                write!(s, ".").unwrap();
-                s
-            }
-            EfflorescenceOutcome::Effloresce => {
-                format!(
-                    "{} Effloresced, shedding their Corrupted Modification.",
-                    self.player
-                )
-            }
+/// The energy tier of an infusion. None for outcomes that aren't infusions.
+/// Note that the first tier of infusion is "infused with a glimmer", and the second is "began to glow".
+impl<S> From<FallingStarOutcome<S>> for Option<CelestialEnergyTier> {
+    fn from(value: FallingStarOutcome<S>) -> Self {
+        match value {
+            FallingStarOutcome::InfusionI => Some(CelestialEnergyTier::Infused),
+            FallingStarOutcome::InfusionII => Some(CelestialEnergyTier::BeganToGlow),
+            FallingStarOutcome::InfusionIII => Some(CelestialEnergyTier::FullyCharged),
+            FallingStarOutcome::Injury
+            | FallingStarOutcome::Retired(_)
+            | FallingStarOutcome::DeflectedHarmlessly => None,
         }
     }
 }
 
-impl<S: AsRef<str>> Efflorescence<S> {
-    pub fn to_ref(&self) -> Efflorescence<&str> {
-        Efflorescence {
-            player: self.player.as_ref(),
-            outcome: self.outcome,
+impl<S> From<CelestialEnergyTier> for FallingStarOutcome<S> {
+    fn from(value: CelestialEnergyTier) -> Self {
+        match value {
+            CelestialEnergyTier::Infused => FallingStarOutcome::InfusionI,
+            CelestialEnergyTier::BeganToGlow => FallingStarOutcome::InfusionII,
+            CelestialEnergyTier::FullyCharged => FallingStarOutcome::InfusionIII,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
-pub struct EmojiFood<S> {
-    pub food_emoji: S,
-    pub food: FoodName,
+impl<S: Display> FallingStarOutcome<S> {
+    pub fn unparse<'a>(
+        &self,
+        context: impl Into<UnparsingContext<'a>>,
+        event_index: Option<u16>,
+        player_name: &str,
+    ) -> String {
+        let context = context.into();
+        let was_is =
+            if Breakpoints::Season5TenseChange.after(context.season, context.day, event_index) {
+                "is"
+            } else {
+                "was"
+            };
+
+        match self {
+            FallingStarOutcome::Injury => {
+                format!("{player_name} {was_is} injured by the extreme force of the impact!")
+            }
+            FallingStarOutcome::Retired(None) => format!("😇 {player_name} retired from MMOLB!"),
+            FallingStarOutcome::Retired(Some(replacement_player_name)) => {
+                format!("😇 {player_name} retired from MMOLB! {replacement_player_name} {was_is} called up to take their place.")
+            }
+            FallingStarOutcome::InfusionI => {
+                format!("{player_name} {was_is} infused with a glimmer of celestial energy!")
+            }
+            FallingStarOutcome::InfusionII => {
+                let began_begins = if Breakpoints::Season5TenseChange.after(
+                    context.season,
+                    context.day,
+                    event_index,
+                ) {
+                    "begins"
+                } else {
+                    "began"
+                };
+
+                format!("{player_name} {began_begins} to glow brightly with celestial energy!")
+            }
+            FallingStarOutcome::InfusionIII => format!(
+                "{player_name} {was_is} fully charged with an abundance of celestial energy!"
+            ),
+            FallingStarOutcome::DeflectedHarmlessly => {
+                let deflected_deflects = if Breakpoints::Season5TenseChange.after(
+                    context.season,
+                    context.day,
+                    event_index,
+                ) {
+                    "deflects"
+                } else {
+                    "deflected"
+                };
+
+                format!("It {deflected_deflects} off {player_name} harmlessly.")
+            }
+        }
+    }
 }
 
-impl<S: AsRef<str>> EmojiFood<S> {
-    pub fn as_ref(&self) -> EmojiFood<&str> {
-        EmojiFood {
-            food_emoji: self.food_emoji.as_ref(),
-            food: self.food,
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ItemAffixes<S> {
+    None,
+    PrefixSuffix(Vec<ItemPrefix>, Vec<ItemSuffix>),
+    RareName(S),
+}
+
+impl<S: AsRef<str>> ItemAffixes<S> {
+    pub fn to_ref(&self) -> ItemAffixes<&str> {
+        match self {
+            ItemAffixes::RareName(s) => ItemAffixes::RareName(s.as_ref()),
+            ItemAffixes::PrefixSuffix(prefix, suffix) => {
+                ItemAffixes::PrefixSuffix(prefix.clone(), suffix.clone())
+            }
+            ItemAffixes::None => ItemAffixes::None,
         }
     }
 }
 
-impl<S: Display> EmojiFood<S> {
-    pub fn unparse(&self) -> String {
-        format!("{} {}", self.food_emoji, self.food)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Item<S> {
+    pub item_emoji: S,
+    pub item: ItemName,
+    pub affixes: ItemAffixes<S>,
+}
+
+impl<S: AsRef<str>> Item<S> {
+    pub fn to_ref(&self) -> Item<&str> {
+        Item {
+            item_emoji: self.item_emoji.as_ref(),
+            item: self.item,
+            affixes: self.affixes.to_ref(),
+        }
+    }
+}
+
+impl<S> Item<S> {
+    /// The attributes boosted by this item's prefixes and suffixes, prefixes first.
+    /// Rare items and items without affixes have none.
+    pub fn attribute_bonuses(&self) -> Vec<Attribute> {
+        match &self.affixes {
+            ItemAffixes::PrefixSuffix(prefixes, suffixes) => prefixes
+                .iter()
+                .flat_map(|prefix| prefix.attributes())
+                .chain(suffixes.iter().flat_map(|suffix| suffix.attributes()))
+                .copied()
+                .collect(),
+            ItemAffixes::RareName(_) | ItemAffixes::None => Vec::new(),
+        }
+    }
+}
+
+impl<S: Display> Display for Item<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Item {
+            item_emoji,
+            item,
+            affixes,
+        } = self;
+
+        match affixes {
+            ItemAffixes::None => write!(f, "{item_emoji} {item}"),
+            ItemAffixes::PrefixSuffix(prefix, suffix) => {
+                let prefix = prefix.iter().map(|p| format!("{p} ")).collect::<String>();
+                let suffix = suffix.iter().map(|s| format!(" {s}")).collect::<String>();
+                write!(f, "{item_emoji} {prefix}{item}{suffix}")
+            }
+            ItemAffixes::RareName(rare_name) => write!(f, "{item_emoji} {rare_name} {item}"),
+        }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum WeatherConsumptionEvents<S> {
-    StartContest {
-        batting_team_player: EmojiPlayer<S>,
-        pitching_team_player: EmojiPlayer<S>,
-        emoji_food: EmojiFood<S>,
-    },
-    Consumes {
-        batting_team_player: EmojiPlayer<S>,
-        batting_team_progress: u32,
-        pitching_team_player: EmojiPlayer<S>,
-        pitching_team_progress: u32,
-        food_emoji: Option<S>,
-        food: FoodName,
-        batting_team_score: u32,
-        pitching_team_score: u32,
-    },
-    EndContest {
-        winning_score: u32,
-        food_emoji: Option<S>,
-        food: FoodName,
-        winning_player: EmojiPlayer<S>,
-        winning_team: EmojiTeam<S>,
-        winning_tokens: u32,
-        winning_prize: Item<S>,
-        losing_team: EmojiTeam<S>,
-        losing_tokens: u32,
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Delivery<S> {
+    Successful {
+        team: EmojiTeam<S>,
+        player: Option<S>,
+        item: Item<S>,
+        equipped: bool,
+        discarded: Option<Item<S>>,
     },
-    EndContestTie {
-        final_score: u32,
-        food_emoji: Option<S>,
-        food: FoodName,
-        batting_team: EmojiTeam<S>,
-        batting_team_tokens: u32,
-        batting_team_prize: Item<S>,
-        pitching_team: EmojiTeam<S>,
-        pitching_team_tokens: u32,
-        pitching_team_prize: Item<S>,
+    NoSpace {
+        item: Item<S>,
     },
 }
 
-impl<S: Display> WeatherConsumptionEvents<S> {
-    pub fn unparse(&self) -> String {
-        match &self {
-            WeatherConsumptionEvents::StartContest {
-                batting_team_player,
-                pitching_team_player,
-                emoji_food: food,
-            } => {
-                format!("🍽️ Consumption Contest! {batting_team_player} vs. {pitching_team_player}.<br>Who can Consume the most {food}? Go!", food = food.unparse())
-            }
-            WeatherConsumptionEvents::Consumes {
-                batting_team_player,
-                batting_team_progress,
-                pitching_team_player,
-                pitching_team_progress,
-                food_emoji,
-                food,
-                batting_team_score,
-                pitching_team_score,
+impl<S> Delivery<S> {
+    /// The item that was delivered, whether or not there was space for it.
+    pub fn item(&self) -> &Item<S> {
+        match self {
+            Delivery::Successful { item, .. } | Delivery::NoSpace { item } => item,
+        }
+    }
+
+    /// The item thrown away to make room for the delivered one, if any.
+    pub fn discarded(&self) -> Option<&Item<S>> {
+        match self {
+            Delivery::Successful { discarded, .. } => discarded.as_ref(),
+            Delivery::NoSpace { .. } => None,
+        }
+    }
+
+    /// The player who received the item. None if it went to the team, or was discarded.
+    pub fn recipient(&self) -> Option<&S> {
+        match self {
+            Delivery::Successful { player, .. } => player.as_ref(),
+            Delivery::NoSpace { .. } => None,
+        }
+    }
+}
+
+impl<S: Display> Delivery<S> {
+    pub fn unparse<'a>(
+        &self,
+        context: impl Into<UnparsingContext<'a>>,
+        event_index: Option<u16>,
+        delivery_label: &str,
+    ) -> String {
+        let context = context.into();
+        match self {
+            Self::Successful {
+                team,
+                player,
+                item,
+                equipped,
+                discarded,
             } => {
-                let food = if let Some(emoji) = food_emoji {
-                    format!("{emoji} {food}")
+                let received_text = if *equipped {
+                    " equips "
                 } else {
-                    food.to_string()
+                    received_text(context.season, context.day, event_index)
                 };
-                format!("{batting_team_player} Consumes {batting_team_progress} {food}!<br>{pitching_team_player} Consumes {pitching_team_progress} {food}!<br>Score: {batting_team_score} - {pitching_team_score}")
-            }
-            WeatherConsumptionEvents::EndContest {
-                winning_score,
-                food_emoji,
-                food,
-                winning_player,
-                winning_team,
-                winning_prize,
-                winning_tokens,
-                losing_team,
-                losing_tokens,
-            } => {
-                let food = if let Some(emoji) = food_emoji {
-                    format!("{emoji} {food}")
-                } else {
-                    food.to_string()
+                let discarded_text = discarded_text(context.season, context.day, event_index);
+
+                let discarded = match discarded {
+                    Some(discarded) => format!("{discarded_text}{discarded}."),
+                    None => String::new(),
                 };
-                format!("The winner with {winning_score} {food} Consumed is {winning_player}!<br>{winning_team} receives 🪙 {winning_tokens}, and win a {winning_prize}.<br>{losing_team} receives 🪙 {losing_tokens}.")
+
+                let player = player
+                    .as_ref()
+                    .map(|player| format!(" {player}"))
+                    .unwrap_or_default();
+
+                // There's this extra word "from" in the equipped message
+                let from_text = if *equipped { "from " } else { "" };
+
+                format!(
+                    "{team}{player}{received_text}{item} {from_text}{delivery_label}.{discarded}"
+                )
             }
-            WeatherConsumptionEvents::EndContestTie {
-                final_score,
+            Self::NoSpace { item } => {
+                let discard_text = if Breakpoints::Season8ItemDiscardedMessageChange.after(
+                    context.season,
+                    context.day,
+                    event_index,
+                ) {
+                    " is discarded as no player can use it."
+                } else if Breakpoints::Season5TenseChange.after(
+                    context.season,
+                    context.day,
+                    event_index,
+                ) {
+                    " is discarded as no player has space."
+                } else {
+                    " was discarded as no player had space."
+                };
+
+                format!("{item}{discard_text}")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WitherResult {
+    Resisted,
+    ResistedEffloresced,
+    ResistedImmune,
+    Corrupted,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ContainResult<S> {
+    NoContain,
+    SuccessfulContain {
+        contained_player_name: S,
+        replacement_player_name: S,
+    },
+    FailedContain {
+        target_player_name: S,
+    },
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PartyDurabilityLoss<S> {
+    Both(u8),
+    OneProtected {
+        protected_player_name: S,
+        unprotected_player_name: S,
+        durability_loss: u8,
+    },
+}
+
+impl<S: Display> Display for PartyDurabilityLoss<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartyDurabilityLoss::Both(durability_loss) => {
+                write!(f, "Both players lose {durability_loss} Durability.")
+            }
+            PartyDurabilityLoss::OneProtected {
+                protected_player_name,
+                unprotected_player_name,
+                durability_loss,
+            } => {
+                write!(
+                    f,
+                    "{unprotected_player_name} loses {durability_loss} Durability, but \
+                    {protected_player_name}'s Prolific Greater Boon protects them from harm."
+                )
+            }
+        }
+    }
+}
+
+impl<S: Display> Display for ContainResult<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainResult::NoContain => Ok(()),
+            ContainResult::SuccessfulContain {
+                replacement_player_name,
+                contained_player_name,
+            } => {
+                write!(f, ", and Contained {contained_player_name}. They were replaced by {replacement_player_name}.")
+            }
+            ContainResult::FailedContain { target_player_name } => {
+                write!(
+                    f,
+                    ", and tried to Contain {target_player_name}, but they wouldn't budge."
+                )
+            }
+        }
+    }
+}
+
+fn old_space(context: UnparsingContext, event_index: Option<u16>) -> &'static str {
+    if Breakpoints::S2D169.before(context.season, context.day, event_index) {
+        " "
+    } else {
+        ""
+    }
+}
+
+/// See individual variant documentation for an example of each bug, and the known properties of their events.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum_discriminants(derive(Display))]
+pub enum KnownBug<S> {
+    /// https://mmolb.com/watch/6851bb34f419fdc04f9d0ed5 "Genevieve Hirose reaches on a fielder's choice out, 1B N. Kitagawa"
+    ///
+    /// Potentially fixed on S2D152
+    ///
+    /// Properties:
+    /// - They are called fielders choice outs
+    /// - They (so far) always occur when no runners are on base
+    /// - They count as an out for the count
+    /// - the batter does end up on base
+    FirstBasemanChoosesAGhost { batter: S, first_baseman: S },
+    /// https://mmolb.com/watch/68758c796154982c31f5d803?event=412 ""
+    ///
+    /// Potentially fixed as a side effect of the Season 3 Pre-Superstar Break Update, s3d112
+    ///
+    /// NPC teams cannot earn Tokens. Before s3d112, teams that got shutout didn't prosper.
+    /// Thus, if an npc team shut out a player team, then an empty prosperity message would be displayed.
+    ///
+    /// Properties
+    /// - It displays as an empty string
+    /// - It is a prosperity event in which neither team earned any tokens.
+    NoOneProspers,
+}
+
+impl<S: Display> Display for KnownBug<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KnownBug::FirstBasemanChoosesAGhost {
+                batter,
+                first_baseman,
+            } => {
+                write!(
+                    f,
+                    "{batter} reaches on a fielder's choice out, 1B {first_baseman}"
+                )
+            }
+            KnownBug::NoOneProspers => {
+                write!(f, "")
+            }
+        }
+    }
+}
+
+fn _check(_: &str) -> Infallible {
+    unreachable!("This is dead code that exists for a strum parse_err_fn")
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumString, IntoStaticStr, Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum(
+    parse_err_fn = check,
+    parse_err_ty = Infallible
+)]
+pub enum Cheer {
+    #[strum(to_string = "A tremendous cheer fills the air")]
+    ATremendousCheerFillsTheAir,
+    #[strum(to_string = "A thunderous cheer echoes across the field")]
+    AThunderousCheerEchoesAcrossTheField,
+    #[strum(to_string = "Chants thunder around the stadium")]
+    ChantsThunderAroundTheStadium,
+    #[strum(to_string = "Everyone cheers at once")]
+    EveryoneCheersAtOnce,
+    #[strum(to_string = "Everyone is chanting the team's name")]
+    EveryoneIsChantingTheTeamsName,
+    #[strum(to_string = "The cheers drown out everything else")]
+    TheCheersDrownOutEverythingElse,
+    #[strum(to_string = "Cheers roll through the stadium")]
+    CheersRollThroughTheStadium,
+    #[strum(to_string = "Supporters pound on the railings")]
+    SupportersPoundOnTheRailings,
+    #[strum(to_string = "Hands clap and whistles pierce the air")]
+    HandsClapAndWhistlesPierceTheAir,
+    #[strum(to_string = "Home fans stomp their feet in unison")]
+    HomeFansStompTheirFeetInUnison,
+    #[strum(to_string = "No one is sitting anymore")]
+    NoOneIsSittingAnymore,
+    #[strum(to_string = "The hometown supporters give a roar")]
+    TheHometownSupportersGiveARoar,
+    #[strum(to_string = "The hometown section is deafening")]
+    TheHomeTownSectionIsDeafening,
+    #[strum(to_string = "The home faithful go berserk")]
+    TheHomeFaithfulGoBerserk,
+    #[strum(to_string = "Fans are losing their minds")]
+    TheFansAreLosingTheirMinds,
+    #[strum(to_string = "The stands explode in noise")]
+    TheStandsExplodeInNoise,
+    #[strum(to_string = "It's a wall of sound")]
+    ItsAWallOfSound,
+    #[strum(to_string = "The entire ballpark is rocking")]
+    TheEntireBallparkIsRocking,
+    #[strum(to_string = "The ballpark comes alive")]
+    TheBallparkComesAlive,
+    #[strum(to_string = "Excitement pours from the crowd")]
+    ExcitementPoursFromTheCrowd,
+    #[strum(to_string = "The home supporters can't contain themselves")]
+    TheHomeSupportersCantContainThemselves,
+    #[strum(to_string = "The crowd lets out a collective howl")]
+    TheCrowdLetsOutACollectiveHowl,
+    #[strum(to_string = "A rumble of excitement rolls through the stadium")]
+    ARumbleOfExcitementRollsThroughTheStadium,
+    #[strum(to_string = "Banners wave wildly")]
+    BannersWaveWildly,
+    #[strum(to_string = "The noise level skyrockets")]
+    TheNoiseLevelSkyrockets,
+    #[strum(to_string = "The energy in here is palpable")]
+    TheEnergyInHereIsPalpable,
+    #[strum(to_string = "The ballpark erupts in applause")]
+    TheBallparkEruptsInApplause,
+    #[strum(to_string = "The bleachers are booming")]
+    TheBleachersAreBooming,
+    #[strum(to_string = "Fans slap the walls in rhythm")]
+    FansSlapTheWallsInRhythm,
+    #[strum(to_string = "The hometown fans roar their support")]
+    TheHometownFansRoarTheirSupport,
+    #[strum(to_string = "Noise rains down from the seats")]
+    NoiseRainsDownFromTheSeats,
+    #[strum(to_string = "Fans wave their arms wildly")]
+    FansWaveTheirArmsWildly,
+    #[strum(to_string = "Energy pulses from the stands")]
+    EnergyPulsesFromTheStands,
+    #[strum(to_string = "The stands shake with noise")]
+    TheStandsShakeWithNoise,
+    #[strum(to_string = "The ballpark is in an uproar")]
+    TheBallparkIsInAnUproar,
+    #[strum(to_string = "The stadium swell with cheers")]
+    TheStadiumSwellWithCheers,
+    #[strum(to_string = "The stadium erupts")]
+    TheStadiumErupts,
+    #[strum(to_string = "Fans roar in support")]
+    FansRoarInSupport,
+    #[strum(to_string = "A chant grows louder and louder")]
+    AChantGrowsLouderAndLouder,
+    #[strum(to_string = "Fans shout encouragement")]
+    FansShoutEncouragement,
+    #[strum(to_string = "The stadium is buzzing")]
+    TheStadiumIsBuzzing,
+    #[strum(to_string = "The hometown faithful are loving this")]
+    TheHometownFaithfulAreLovingThis,
+    #[strum(to_string = "Fans whoop and holler")]
+    FansWhoopAndHoller,
+    #[strum(to_string = "The home crowd is fired up")]
+    TheHomeCrowdIsFiredUp,
+    #[strum(to_string = "It's pandemonium in the stands")]
+    ItsPandemoniumInTheStands,
+    #[strum(to_string = "The noise is overwhelming")]
+    TheNoiseIsOverwhelming,
+    #[strum(to_string = "Home supporters whistle and cheer")]
+    HomeSupportersWhistleAndCheer,
+    #[strum(to_string = "The home fans make themselves heard")]
+    TheHomeFansMakeThemselvesHeard,
+    #[strum(to_string = "The crowd rallies behind the team")]
+    TheCrowdRalliesBehindTheTeam,
+    #[strum(to_string = "The stands are rumbling")]
+    TheStandsAreRumbling,
+    #[strum(to_string = "It's pure pandemonium")]
+    ItsPurePandemonium,
+    #[strum(to_string = "The place is rocking")]
+    ThePlaceIsRocking,
+    #[strum(to_string = "A wave of cheers sweeps the stadium")]
+    AWaveOfCheersSweepsTheStadium,
+    #[strum(to_string = "The hometown fans roar")]
+    TheHometownFansRoar,
+    #[strum(to_string = "The fans thunder their approval")]
+    TheFansThunderTheirApproval,
+    #[strum(to_string = "Supporters yell at full volume")]
+    SupportersYellAtFullVolume,
+    #[strum(to_string = "The crowd goes absolutely bonkers")]
+    TheCrowdGoesAbsolutelyBonkers,
+    #[strum(to_string = "The cheering is relentless")]
+    TheCheeringIsRelentless,
+    #[strum(to_string = "Fans pump their fists in the air")]
+    FansPumpTheirFistsInTheAir,
+    #[strum(to_string = "The home crowd won't stop cheering")]
+    TheHomeCrowdWontStopCheering,
+    #[strum(to_string = "The crowd erupts into cheers")]
+    TheCrowdEruptsIntoCheers,
+    #[strum(to_string = "A roar builds from the seats")]
+    ARoarBuildsFromTheSeats,
+    #[strum(to_string = "Everyone in the stands is on their feet")]
+    EveryoneInTheStandsIsOnTheirFeet,
+    #[strum(to_string = "It's a roar from the rafters")]
+    ItsARoarFromTheRafters,
+    #[strum(to_string = "Chants rise from the bleachers")]
+    ChantsRiseFromTheBleachers,
+    #[strum(to_string = "A cheer rips through the park")]
+    ACheerRipsThroughThePark,
+    #[strum(to_string = "Everyone's clapping in rhythm")]
+    EveryonesClappingInRhythm,
+    #[strum(to_string = "The crowd is pumped up")]
+    TheCrowdIsPumpedUp,
+    #[strum(to_string = "The stadium swells with cheers")]
+    TheStadiumSwellsWithCheers,
+    #[strum(to_string = "You can barely hear the announcer")]
+    YouCanBarelyHearTheAnnouncer,
+    #[strum(to_string = "The fans are fired up")]
+    TheFansAreFiredUp,
+    #[strum(to_string = "The decibels rise to a frenzy")]
+    TheDecibelsRiseToAFrenzy,
+    #[strum(to_string = "The energy in the park surges")]
+    TheEnergyInTheParkSurges,
+    #[strum(to_string = "Fans yell themselves hoarse")]
+    FansYellThemselvesHoarse,
+    #[strum(to_string = "A mighty cheer erupts")]
+    AMightyCheerErupts,
+    #[strum(to_string = "The stands are electric")]
+    TheStandsAreElectric,
+    #[strum(to_string = "Fans jump and shout")]
+    FansJumpAndShout,
+    #[strum(to_string = "The cheers echo off the walls")]
+    TheCheersEchoOffTheWalls,
+    #[strum(to_string = "You can feel the stands vibrating")]
+    YouCanFeelTheStandsVibrating,
+    #[strum(to_string = "Noise levels spike")]
+    NoiseLevelsSpike,
+    #[strum(to_string = "Everyone is cheering at once")]
+    EveryoneIsCheeringAtOnce,
+    #[strum(to_string = "A huge cheer erupts")]
+    AHugeCheerErupts,
+    #[strum(to_string = "The noise just keeps building")]
+    TheNoiseJustKeepsBuilding,
+    #[strum(to_string = "The faithful are shouting at the top of their lungs")]
+    TheFaithfulAreShoutingAtTheTopOfTheirLungs,
+    #[strum(to_string = "They cheer like there's no tomorrow")]
+    TheyCheerLikeTheresNoTomorrow,
+    #[strum(to_string = "You can feel the hype building")]
+    YouCanFeelTheHypeBuilding,
+    #[strum(to_string = "The excitement is off the charts")]
+    TheExcitementIsOffTheCharts,
+    #[strum(to_string = "Excitement surges through the park")]
+    ExcitementSurgesThroughThePark,
+    #[strum(to_string = "Every fan is making noise")]
+    EveryFanIsMakingNoise,
+    #[strum(to_string = "The supporters fuel their team")]
+    TheSupportersFuelTheirTeam,
+    #[strum(to_string = "The park vibrates with noise")]
+    TheParkVibratesWithNoise,
+    #[strum(to_string = "The stands are shaking")]
+    TheStandsAreShaking,
+    #[strum(to_string = "The fans bellow encouragement")]
+    TheFansBellowEncouragement,
+    #[strum(to_string = "The stadium shakes with excitement")]
+    TheStadiumShakesWithExcitement,
+    #[strum(to_string = "Supporters wave their banners high")]
+    SupportersWaveTheirBannersHigh,
+    #[strum(to_string = "The faithful rise as one")]
+    TheFaithfulRiseAsOne,
+    #[strum(to_string = "Cheers cascade from every section")]
+    CheersCascadeFromEverySection,
+    #[strum(to_string = "The crowd belts out the team's chant")]
+    TheCrowdBeltsOutTheTeamsChant,
+    #[strum(to_string = "The fans scream for their heroes")]
+    TheFansScreamForTheirHeroes,
+    #[strum(to_string = "The crowd is ecstatic")]
+    TheCrowdIsEcstatic,
+    #[strum(to_string = "The crowd is pumped")]
+    TheCrowdIsPumped,
+
+    #[strum(default)]
+    Unknown(String),
+}
+
+static ALL_CHEERS: &[Cheer] = &[
+    Cheer::ATremendousCheerFillsTheAir,
+    Cheer::AThunderousCheerEchoesAcrossTheField,
+    Cheer::ChantsThunderAroundTheStadium,
+    Cheer::EveryoneCheersAtOnce,
+    Cheer::EveryoneIsChantingTheTeamsName,
+    Cheer::TheCheersDrownOutEverythingElse,
+    Cheer::CheersRollThroughTheStadium,
+    Cheer::SupportersPoundOnTheRailings,
+    Cheer::HandsClapAndWhistlesPierceTheAir,
+    Cheer::HomeFansStompTheirFeetInUnison,
+    Cheer::NoOneIsSittingAnymore,
+    Cheer::TheHometownSupportersGiveARoar,
+    Cheer::TheHomeTownSectionIsDeafening,
+    Cheer::TheHomeFaithfulGoBerserk,
+    Cheer::TheFansAreLosingTheirMinds,
+    Cheer::TheStandsExplodeInNoise,
+    Cheer::ItsAWallOfSound,
+    Cheer::TheEntireBallparkIsRocking,
+    Cheer::TheBallparkComesAlive,
+    Cheer::ExcitementPoursFromTheCrowd,
+    Cheer::TheHomeSupportersCantContainThemselves,
+    Cheer::TheCrowdLetsOutACollectiveHowl,
+    Cheer::ARumbleOfExcitementRollsThroughTheStadium,
+    Cheer::BannersWaveWildly,
+    Cheer::TheNoiseLevelSkyrockets,
+    Cheer::TheEnergyInHereIsPalpable,
+    Cheer::TheBallparkEruptsInApplause,
+    Cheer::TheBleachersAreBooming,
+    Cheer::FansSlapTheWallsInRhythm,
+    Cheer::TheHometownFansRoarTheirSupport,
+    Cheer::NoiseRainsDownFromTheSeats,
+    Cheer::FansWaveTheirArmsWildly,
+    Cheer::EnergyPulsesFromTheStands,
+    Cheer::TheStandsShakeWithNoise,
+    Cheer::TheBallparkIsInAnUproar,
+    Cheer::TheStadiumSwellWithCheers,
+    Cheer::TheStadiumErupts,
+    Cheer::FansRoarInSupport,
+    Cheer::AChantGrowsLouderAndLouder,
+    Cheer::FansShoutEncouragement,
+    Cheer::TheStadiumIsBuzzing,
+    Cheer::TheHometownFaithfulAreLovingThis,
+    Cheer::FansWhoopAndHoller,
+    Cheer::TheHomeCrowdIsFiredUp,
+    Cheer::ItsPandemoniumInTheStands,
+    Cheer::TheNoiseIsOverwhelming,
+    Cheer::HomeSupportersWhistleAndCheer,
+    Cheer::TheHomeFansMakeThemselvesHeard,
+    Cheer::TheCrowdRalliesBehindTheTeam,
+    Cheer::TheStandsAreRumbling,
+    Cheer::ItsPurePandemonium,
+    Cheer::ThePlaceIsRocking,
+    Cheer::AWaveOfCheersSweepsTheStadium,
+    Cheer::TheHometownFansRoar,
+    Cheer::TheFansThunderTheirApproval,
+    Cheer::SupportersYellAtFullVolume,
+    Cheer::TheCrowdGoesAbsolutelyBonkers,
+    Cheer::TheCheeringIsRelentless,
+    Cheer::FansPumpTheirFistsInTheAir,
+    Cheer::TheHomeCrowdWontStopCheering,
+    Cheer::TheCrowdEruptsIntoCheers,
+    Cheer::ARoarBuildsFromTheSeats,
+    Cheer::EveryoneInTheStandsIsOnTheirFeet,
+    Cheer::ItsARoarFromTheRafters,
+    Cheer::ChantsRiseFromTheBleachers,
+    Cheer::ACheerRipsThroughThePark,
+    Cheer::EveryonesClappingInRhythm,
+    Cheer::TheCrowdIsPumpedUp,
+    Cheer::TheStadiumSwellsWithCheers,
+    Cheer::YouCanBarelyHearTheAnnouncer,
+    Cheer::TheFansAreFiredUp,
+    Cheer::TheDecibelsRiseToAFrenzy,
+    Cheer::TheEnergyInTheParkSurges,
+    Cheer::FansYellThemselvesHoarse,
+    Cheer::AMightyCheerErupts,
+    Cheer::TheStandsAreElectric,
+    Cheer::FansJumpAndShout,
+    Cheer::TheCheersEchoOffTheWalls,
+    Cheer::YouCanFeelTheStandsVibrating,
+    Cheer::NoiseLevelsSpike,
+    Cheer::EveryoneIsCheeringAtOnce,
+    Cheer::AHugeCheerErupts,
+    Cheer::TheNoiseJustKeepsBuilding,
+    Cheer::TheFaithfulAreShoutingAtTheTopOfTheirLungs,
+    Cheer::TheyCheerLikeTheresNoTomorrow,
+    Cheer::YouCanFeelTheHypeBuilding,
+    Cheer::TheExcitementIsOffTheCharts,
+    Cheer::ExcitementSurgesThroughThePark,
+    Cheer::EveryFanIsMakingNoise,
+    Cheer::TheSupportersFuelTheirTeam,
+    Cheer::TheParkVibratesWithNoise,
+    Cheer::TheStandsAreShaking,
+    Cheer::TheFansBellowEncouragement,
+    Cheer::TheStadiumShakesWithExcitement,
+    Cheer::SupportersWaveTheirBannersHigh,
+    Cheer::TheFaithfulRiseAsOne,
+    Cheer::CheersCascadeFromEverySection,
+    Cheer::TheCrowdBeltsOutTheTeamsChant,
+    Cheer::TheFansScreamForTheirHeroes,
+    Cheer::TheCrowdIsEcstatic,
+    Cheer::TheCrowdIsPumped,
+];
+
+impl Cheer {
+    /// Every recognized cheer, not including [`Cheer::Unknown`].
+    pub fn all() -> &'static [Cheer] {
+        ALL_CHEERS
+    }
+
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Self::Unknown(_))
+    }
+
+    pub fn new(value: &str) -> Self {
+        let r = Cheer::from_str(value).expect("This error type is infallible");
+
+        if matches!(r, Cheer::Unknown(_)) {
+            tracing::warn!("Failed to match cheer '{value}'");
+        }
+
+        r
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Unknown(unknown) => unknown,
+            _ => self.into(),
+        }
+    }
+
+    pub fn unparse<'a>(
+        &self,
+        context: impl Into<UnparsingContext<'a>>,
+        event_index: Option<u16>,
+    ) -> String {
+        self.display(context.into(), event_index).to_string()
+    }
+
+    fn display(&self, context: UnparsingContext, event_index: Option<u16>) -> impl Display + '_ {
+        let emoji = if Breakpoints::CheersGetEmoji.before(context.season, context.day, event_index)
+        {
+            ""
+        } else {
+            "📣 "
+        };
+        DisplayFn(move |f: &mut Formatter<'_>| write!(f, " {emoji}{self}!"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumString, IntoStaticStr, Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum(
+    parse_err_fn = check,
+    parse_err_ty = Infallible
+)]
+pub enum EjectionReason {
+    // Sportsmanship violations
+    #[strum(to_string = "eating a hotdog")]
+    EatingAHotdog,
+    #[strum(to_string = "spitting")]
+    Spitting,
+    #[strum(to_string = "looking at them the wrong way")]
+    LookingAtThemTheWrongWay,
+    #[strum(to_string = "whispering something to another player")]
+    WhisperingSomethingToAnotherPlayer,
+    #[strum(to_string = "dancing")]
+    Dancing,
+    #[strum(to_string = "not looking excited enough")]
+    NotLookingExcitedEnough,
+    #[strum(to_string = "picking their nose")]
+    PickingTheirNose,
+    #[strum(to_string = "drinking beer")]
+    DrinkingBeer,
+    #[strum(to_string = "taking a phone call")]
+    TakingAPhoneCall,
+    #[strum(to_string = "using a foreign substance")]
+    UsingAForeignSubstance,
+    #[strum(to_string = "eating nachos")]
+    EatingNachos,
+    #[strum(to_string = "chewing gum too loud")]
+    ChewingGumTooLoud,
+    #[strum(to_string = "texting during play")]
+    TextingDuringPlay,
+
+    // Uniform violations
+    #[strum(to_string = "hat worn at improper rotational value")]
+    HatWornAtImproperRotationalValue,
+    #[strum(to_string = "mismatched socks")]
+    MismatchedSocks,
+    #[strum(to_string = "wrinkled shirt")]
+    WrinkledShirt,
+    #[strum(to_string = "shoe untied")]
+    ShoeUntied,
+
+    // Communication violations
+    #[strum(to_string = "making weird hand signals")]
+    MakingWeirdHandSignals,
+    #[strum(to_string = "laughing")]
+    Laughing,
+    #[strum(to_string = "something they said earlier in the locker room")]
+    SomethingTheySaidEarlierInTheLockerRoom,
+    #[strum(to_string = "telling a bad joke")]
+    TellingABadJoke,
+    #[strum(to_string = "winking at someone in the crowd")]
+    WinkingAtSomeoneInTheCrowd,
+    #[strum(to_string = "saying a bad word")]
+    SayingABadWord,
+    #[strum(to_string = "humming")]
+    Humming,
+
+    #[strum(default)]
+    Unknown(String),
+}
+
+static ALL_EJECTION_REASONS: &[EjectionReason] = &[
+    EjectionReason::EatingAHotdog,
+    EjectionReason::Spitting,
+    EjectionReason::LookingAtThemTheWrongWay,
+    EjectionReason::WhisperingSomethingToAnotherPlayer,
+    EjectionReason::Dancing,
+    EjectionReason::NotLookingExcitedEnough,
+    EjectionReason::PickingTheirNose,
+    EjectionReason::DrinkingBeer,
+    EjectionReason::TakingAPhoneCall,
+    EjectionReason::UsingAForeignSubstance,
+    EjectionReason::EatingNachos,
+    EjectionReason::ChewingGumTooLoud,
+    EjectionReason::TextingDuringPlay,
+    EjectionReason::HatWornAtImproperRotationalValue,
+    EjectionReason::MismatchedSocks,
+    EjectionReason::WrinkledShirt,
+    EjectionReason::ShoeUntied,
+    EjectionReason::MakingWeirdHandSignals,
+    EjectionReason::Laughing,
+    EjectionReason::SomethingTheySaidEarlierInTheLockerRoom,
+    EjectionReason::TellingABadJoke,
+    EjectionReason::WinkingAtSomeoneInTheCrowd,
+    EjectionReason::SayingABadWord,
+    EjectionReason::Humming,
+];
+
+impl EjectionReason {
+    /// Every recognized ejection reason, not including [`EjectionReason::Unknown`].
+    pub fn all() -> &'static [EjectionReason] {
+        ALL_EJECTION_REASONS
+    }
+
+    /// The recognized ejection reasons given for this kind of violation.
+    pub fn of(violation: ViolationType) -> impl Iterator<Item = EjectionReason> {
+        ALL_EJECTION_REASONS
+            .iter()
+            .filter(move |reason| reason.violation_type().as_ref() == Some(&violation))
+            .cloned()
+    }
+
+    pub fn new(value: &str) -> Self {
+        let r = EjectionReason::from_str(value).expect("This error type is infallible");
+
+        if matches!(r, EjectionReason::Unknown(_)) {
+            tracing::warn!("Failed to match ejection reason '{value}'");
+        }
+
+        r
+    }
+
+    pub fn unparse(&self) -> String {
+        format!("{self}")
+    }
+
+    /// The kind of violation this reason is given for. None for unknown reasons.
+    pub fn violation_type(&self) -> Option<ViolationType> {
+        match self {
+            Self::EatingAHotdog
+            | Self::Spitting
+            | Self::LookingAtThemTheWrongWay
+            | Self::WhisperingSomethingToAnotherPlayer
+            | Self::Dancing
+            | Self::NotLookingExcitedEnough
+            | Self::PickingTheirNose
+            | Self::DrinkingBeer
+            | Self::TakingAPhoneCall
+            | Self::UsingAForeignSubstance
+            | Self::EatingNachos
+            | Self::ChewingGumTooLoud
+            | Self::TextingDuringPlay => Some(ViolationType::Sportsmanship),
+            Self::HatWornAtImproperRotationalValue
+            | Self::MismatchedSocks
+            | Self::WrinkledShirt
+            | Self::ShoeUntied => Some(ViolationType::Uniform),
+            Self::MakingWeirdHandSignals
+            | Self::Laughing
+            | Self::SomethingTheySaidEarlierInTheLockerRoom
+            | Self::TellingABadJoke
+            | Self::WinkingAtSomeoneInTheCrowd
+            | Self::SayingABadWord
+            | Self::Humming => Some(ViolationType::Communication),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumString, IntoStaticStr, Display)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum(
+    parse_err_fn = check,
+    parse_err_ty = Infallible
+)]
+pub enum ViolationType {
+    Sportsmanship,
+    Uniform,
+    Communication,
+
+    #[strum(default)]
+    Unknown(String),
+}
+
+static ALL_VIOLATION_TYPES: &[ViolationType] = &[
+    ViolationType::Sportsmanship,
+    ViolationType::Uniform,
+    ViolationType::Communication,
+];
+
+impl ViolationType {
+    /// Every recognized violation type, not including [`ViolationType::Unknown`].
+    pub fn all() -> &'static [ViolationType] {
+        ALL_VIOLATION_TYPES
+    }
+
+    pub fn new(value: &str) -> Self {
+        let r = ViolationType::from_str(value).expect("This error type is infallible");
+
+        if matches!(r, ViolationType::Unknown(_)) {
+            tracing::warn!("Failed to match violation type '{value}'");
+        }
+
+        r
+    }
+
+    pub fn unparse(&self) -> String {
+        format!("{self}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SnappedPhotos<S> {
+    pub first_team_emoji: S,
+    pub first_player: PlacedPlayer<S>,
+    pub second_team_emoji: S,
+    pub second_player: PlacedPlayer<S>,
+}
+
+impl<S: Display> SnappedPhotos<S> {
+    pub fn unparse(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<S: Display> Display for SnappedPhotos<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            " The Geomagnetic Storms Intensify! {} {} and {} {} snapped photos of the aurora.",
+            self.first_team_emoji, self.first_player, self.second_team_emoji, self.second_player,
+        )
+    }
+}
+
+impl<S: AsRef<str>> SnappedPhotos<S> {
+    pub fn as_ref(&self) -> SnappedPhotos<&str> {
+        SnappedPhotos {
+            first_team_emoji: self.first_team_emoji.as_ref(),
+            first_player: self.first_player.as_ref(),
+            second_team_emoji: self.second_team_emoji.as_ref(),
+            second_player: self.second_player.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum EjectionReplacement<S> {
+    BenchPlayer { player_name: S },
+    RosterPlayer { player: PlacedPlayer<S> },
+}
+
+impl<S: AsRef<str>> EjectionReplacement<S> {
+    pub fn as_ref(&self) -> EjectionReplacement<&str> {
+        match self {
+            EjectionReplacement::BenchPlayer { player_name } => EjectionReplacement::BenchPlayer {
+                player_name: player_name.as_ref(),
+            },
+            EjectionReplacement::RosterPlayer { player } => EjectionReplacement::RosterPlayer {
+                player: player.as_ref(),
+            },
+        }
+    }
+}
+
+impl<S> EjectionReplacement<S> {
+    pub fn player_name(&self) -> &S {
+        match self {
+            EjectionReplacement::BenchPlayer { player_name } => player_name,
+            EjectionReplacement::RosterPlayer { player } => &player.name,
+        }
+    }
+}
+
+/// A ROBO-UMP ejection. `V` and `R` are only overridden by the borrowed form from [`Ejection::as_ref`],
+/// which holds `&ViolationType` and `&EjectionReason`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Ejection<S, V = ViolationType, R = EjectionReason> {
+    Ejection {
+        team: EmojiTeam<S>,
+        ejected_player: PlacedPlayer<S>,
+        violation_type: V,
+        reason: R,
+        replacement: EjectionReplacement<S>,
+    },
+    FailedEjection {
+        player_names: [S; 2],
+    },
+}
+
+impl<S: Display, V: Display, R: Display> Ejection<S, V, R> {
+    pub fn unparse(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<S: Display, V: Display, R: Display> Display for Ejection<S, V, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ejection::Ejection { team, ejected_player, violation_type, reason, replacement } => {
+                match replacement {
+                    EjectionReplacement::BenchPlayer { player_name } => write!(
+                        f,
+                        " 🤖 ROBO-UMP ejected {} {} for a {} Violation ({}). Bench Player {} takes their place.",
+                        team,
+                        ejected_player,
+                        violation_type,
+                        reason,
+                        player_name,
+                    ),
+                    EjectionReplacement::RosterPlayer { player } => write!(
+                        f,
+                        " 🤖 ROBO-UMP ejected {} {} for a {} Violation ({}). {} {} takes the mound.",
+                        team,
+                        ejected_player,
+                        violation_type,
+                        reason,
+                        team.emoji,
+                        player,
+                    ),
+                }
+            }
+            Ejection::FailedEjection { player_names: [n1, n2] } => write!(
+                f,
+                " 🤖 ROBO-UMP attempted an ejection, but {}, {} would not budge.", n1, n2,
+            )
+        }
+    }
+}
+
+impl<S: AsRef<str>> Ejection<S> {
+    /// Borrows every field, including the violation type and reason, so this never allocates.
+    pub fn as_ref(&self) -> Ejection<&str, &ViolationType, &EjectionReason> {
+        match self {
+            Ejection::Ejection {
+                team,
+                ejected_player,
+                violation_type,
+                reason,
+                replacement,
+            } => Ejection::Ejection {
+                team: team.as_ref(),
+                ejected_player: ejected_player.as_ref(),
+                violation_type,
+                reason,
+                replacement: replacement.as_ref(),
+            },
+            Ejection::FailedEjection {
+                player_names: [n1, n2],
+            } => Ejection::FailedEjection {
+                player_names: [n1.as_ref(), n2.as_ref()],
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ItemEquip<S> {
+    None,
+    Discarded,
+    Equipped {
+        player_name: S,
+        discarded_item: Option<Item<S>>,
+    },
+}
+
+impl<S> ItemEquip<S> {
+    pub fn is_none(&self) -> bool {
+        matches!(self, ItemEquip::None)
+    }
+}
+
+impl<S: AsRef<str>> ItemEquip<S> {
+    pub fn to_ref(&self) -> ItemEquip<&str> {
+        match self {
+            ItemEquip::None => ItemEquip::None,
+            ItemEquip::Discarded => ItemEquip::Discarded,
+            ItemEquip::Equipped {
+                player_name,
+                discarded_item,
+            } => ItemEquip::Equipped {
+                player_name: player_name.as_ref(),
+                discarded_item: discarded_item.as_ref().map(|i| i.to_ref()),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ItemPrize<S> {
+    pub item: Item<S>,
+    pub equip: ItemEquip<S>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Prize<S> {
+    Tokens(u16),
+    Items(Vec<ItemPrize<S>>),
+}
+
+impl<S> Prize<S> {
+    /// Tokens won. 0 for item prizes.
+    pub fn total_tokens(&self) -> u16 {
+        match self {
+            Prize::Tokens(tokens) => *tokens,
+            Prize::Items(_) => 0,
+        }
+    }
+
+    /// Items won. Empty for token prizes.
+    pub fn items(&self) -> &[ItemPrize<S>] {
+        match self {
+            Prize::Tokens(_) => &[],
+            Prize::Items(items) => items,
+        }
+    }
+
+    /// Whether nothing was actually won, i.e. 0 tokens or no items.
+    pub fn is_empty(&self) -> bool {
+        self.total_tokens() == 0 && self.items().is_empty()
+    }
+
+    /// "!" when the items were equipped or discarded straight away, ":" otherwise.
+    pub(crate) fn door_prize_punctuation(&self) -> &'static str {
+        if self.items().iter().any(|item| !item.equip.is_none()) {
+            "!"
+        } else {
+            ":"
+        }
+    }
+
+    /// The players who equipped an item from this prize, who might not be the winner.
+    pub fn equipping_players(&self) -> impl Iterator<Item = &S> {
+        self.items().iter().filter_map(|prize| match &prize.equip {
+            ItemEquip::Equipped { player_name, .. } => Some(player_name),
+            ItemEquip::None | ItemEquip::Discarded => None,
+        })
+    }
+}
+
+impl<S: Display> Prize<S> {
+    pub fn unparse(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<S: Display> Display for Prize<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Prize::Tokens(tokens) => write!(f, "{tokens} 🪙"),
+            Prize::Items(items) => {
+                let has_equip = items.iter().any(|i| !i.equip.is_none());
+                let separator = if has_equip { ". " } else { ", " };
+
+                for (i, prize) in items.iter().enumerate() {
+                    let prefix = if i == 0 { "" } else { separator };
+                    match &prize.equip {
+                        ItemEquip::None => write!(f, "{prefix}{}", prize.item)?,
+                        ItemEquip::Discarded => {
+                            write!(f, "{prefix}{} is discarded; nobody can use it", prize.item)?
+                        }
+                        ItemEquip::Equipped {
+                            player_name,
+                            discarded_item,
+                        } => {
+                            write!(
+                                f,
+                                "{prefix}{player_name} equips {} from the Door Prize",
+                                prize.item
+                            )?;
+                            if let Some(discarded_item) = discarded_item {
+                                write!(f, ". They discard their {discarded_item}")?;
+                            }
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<S: AsRef<str>> Prize<S> {
+    pub fn to_ref(&self) -> Prize<&str> {
+        match self {
+            Prize::Items(items) => Prize::Items(
+                items
+                    .iter()
+                    .map(|prize| ItemPrize {
+                        item: prize.item.to_ref(),
+                        equip: prize.equip.to_ref(),
+                    })
+                    .collect(),
+            ),
+            Prize::Tokens(t) => Prize::Tokens(*t),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DoorPrize<S> {
+    pub player: S,
+    /// None when they don't win.
+    pub prize: Option<Prize<S>>,
+}
+
+impl<S> DoorPrize<S> {
+    /// False for "didn't win a Door Prize".
+    pub fn won(&self) -> bool {
+        self.prize.is_some()
+    }
+}
+
+impl<S: Display> DoorPrize<S> {
+    pub fn unparse(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<S: Display> Display for DoorPrize<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.prize {
+            Some(prize) => write!(
+                f,
+                "🥳 {} won a Door Prize{} {prize}.",
+                self.player,
+                prize.door_prize_punctuation(),
+            ),
+            None => write!(f, "🥳 {} didn't win a Door Prize.", self.player),
+        }
+    }
+}
+
+impl<S: AsRef<str>> DoorPrize<S> {
+    pub fn to_ref(&self) -> DoorPrize<&str> {
+        DoorPrize {
+            player: self.player.as_ref(),
+            prize: self.prize.as_ref().map(Prize::to_ref),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct WitherStruggle<S> {
+    pub team_emoji: S,
+    pub target: PlacedPlayer<S>,
+    // Source was not named in s6
+    pub source_name: Option<S>,
+}
+
+impl<S: Display> Display for WitherStruggle<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.source_name {
+            None => write!(
+                f,
+                "{} {} struggles against the 🥀 Wither.",
+                self.team_emoji, self.target
+            ),
+            Some(source_name) => write!(
+                f,
+                "{source_name} is trying to spread the 🥀 Wither to {} {}!",
+                self.team_emoji, self.target
+            ),
+        }
+    }
+}
+
+impl<S: AsRef<str>> WitherStruggle<S> {
+    pub fn to_ref(&self) -> WitherStruggle<&str> {
+        WitherStruggle {
+            team_emoji: self.team_emoji.as_ref(),
+            source_name: self.source_name.as_ref().map(AsRef::as_ref),
+            target: self.target.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum EfflorescenceOutcome {
+    Grow([GrowAttributeChange; 2]),
+    Effloresce,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Efflorescence<S> {
+    pub player: S,
+    pub outcome: EfflorescenceOutcome,
+}
+
+impl<S: Display> Efflorescence<S> {
+    pub fn unparse(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl<S: Display> Display for Efflorescence<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.outcome {
+            EfflorescenceOutcome::Grow(changes) => {
+                write!(f, "{} grew: ", self.player)?;
+                for (i, change) in changes.iter().enumerate() {
+                    let prefix = if i == 0 { "" } else { ", " };
+                    write!(f, "{prefix}{:+} {}", change.amount, change.attribute)?;
+                }
+                write!(f, ".")
+            }
+            EfflorescenceOutcome::Effloresce => write!(
+                f,
+                "{} Effloresced, shedding their Corrupted Modification.",
+                self.player
+            ),
+        }
+    }
+}
+
+impl<S: AsRef<str>> Efflorescence<S> {
+    pub fn to_ref(&self) -> Efflorescence<&str> {
+        Efflorescence {
+            player: self.player.as_ref(),
+            outcome: self.outcome,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EmojiFood<S> {
+    pub food_emoji: S,
+    pub food: FoodName,
+}
+
+impl<S: AsRef<str>> EmojiFood<S> {
+    pub fn as_ref(&self) -> EmojiFood<&str> {
+        EmojiFood {
+            food_emoji: self.food_emoji.as_ref(),
+            food: self.food,
+        }
+    }
+}
+
+impl<S: Display> EmojiFood<S> {
+    pub fn unparse(&self) -> String {
+        format!("{} {}", self.food_emoji, self.food)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum WeatherConsumptionEvents<S> {
+    StartContest {
+        batting_team_player: EmojiPlayer<S>,
+        pitching_team_player: EmojiPlayer<S>,
+        emoji_food: EmojiFood<S>,
+    },
+    Consumes {
+        batting_team_player: EmojiPlayer<S>,
+        batting_team_progress: u32,
+        pitching_team_player: EmojiPlayer<S>,
+        pitching_team_progress: u32,
+        food_emoji: Option<S>,
+        food: FoodName,
+        batting_team_score: u32,
+        pitching_team_score: u32,
+    },
+    EndContest {
+        winning_score: u32,
+        food_emoji: Option<S>,
+        food: FoodName,
+        winning_player: EmojiPlayer<S>,
+        winning_team: EmojiTeam<S>,
+        winning_tokens: u32,
+        winning_prize: Item<S>,
+        losing_team: EmojiTeam<S>,
+        losing_tokens: u32,
+    },
+    EndContestTie {
+        final_score: u32,
+        food_emoji: Option<S>,
+        food: FoodName,
+        batting_team: EmojiTeam<S>,
+        batting_team_tokens: u32,
+        batting_team_prize: Item<S>,
+        pitching_team: EmojiTeam<S>,
+        pitching_team_tokens: u32,
+        pitching_team_prize: Item<S>,
+    },
+}
+
+impl<S: Display> WeatherConsumptionEvents<S> {
+    pub fn unparse(&self) -> String {
+        match &self {
+            WeatherConsumptionEvents::StartContest {
+                batting_team_player,
+                pitching_team_player,
+                emoji_food: food,
+            } => {
+                format!("🍽️ Consumption Contest! {batting_team_player} vs. {pitching_team_player}.<br>Who can Consume the most {food}? Go!", food = food.unparse())
+            }
+            WeatherConsumptionEvents::Consumes {
+                batting_team_player,
+                batting_team_progress,
+                pitching_team_player,
+                pitching_team_progress,
+                food_emoji,
+                food,
+                batting_team_score,
+                pitching_team_score,
+            } => {
+                let food = if let Some(emoji) = food_emoji {
+                    format!("{emoji} {food}")
+                } else {
+                    food.to_string()
+                };
+                format!("{batting_team_player} Consumes {batting_team_progress} {food}!<br>{pitching_team_player} Consumes {pitching_team_progress} {food}!<br>Score: {batting_team_score} - {pitching_team_score}")
+            }
+            WeatherConsumptionEvents::EndContest {
+                winning_score,
+                food_emoji,
+                food,
+                winning_player,
+                winning_team,
+                winning_prize,
+                winning_tokens,
+                losing_team,
+                losing_tokens,
+            } => {
+                let food = if let Some(emoji) = food_emoji {
+                    format!("{emoji} {food}")
+                } else {
+                    food.to_string()
+                };
+                format!("The winner with {winning_score} {food} Consumed is {winning_player}!<br>{winning_team} receives 🪙 {winning_tokens}, and win a {winning_prize}.<br>{losing_team} receives 🪙 {losing_tokens}.")
+            }
+            WeatherConsumptionEvents::EndContestTie {
+                final_score,
+                food_emoji,
+                food,
+                batting_team,
+                batting_team_tokens,
+                batting_team_prize,
+                pitching_team,
+                pitching_team_tokens,
+                pitching_team_prize,
+            } => {
+                let food = if let Some(emoji) = food_emoji {
+                    format!("{emoji} {food}")
+                } else {
+                    food.to_string()
+                };
+                format!("The Consumption Contest ends in a tie at {final_score} {food} Consumed!<br>{batting_team} receives 🪙 {batting_team_tokens} and a {batting_team_prize}.<br>{pitching_team} receives 🪙 {pitching_team_tokens} and a {pitching_team_prize}.")
+            }
+        }
+    }
+}
+
+impl<S: AsRef<str>> WeatherConsumptionEvents<S> {
+    pub fn to_ref(&self) -> WeatherConsumptionEvents<&str> {
+        match &self {
+            WeatherConsumptionEvents::StartContest {
+                batting_team_player,
+                pitching_team_player,
+                emoji_food: food,
+            } => WeatherConsumptionEvents::StartContest {
+                batting_team_player: batting_team_player.as_ref(),
+                pitching_team_player: pitching_team_player.as_ref(),
+                emoji_food: food.as_ref(),
+            },
+            WeatherConsumptionEvents::Consumes {
+                batting_team_player,
+                batting_team_progress,
+                pitching_team_player,
+                pitching_team_progress,
+                food_emoji,
+                food,
+                batting_team_score,
+                pitching_team_score,
+            } => WeatherConsumptionEvents::Consumes {
+                batting_team_player: batting_team_player.as_ref(),
+                batting_team_progress: *batting_team_progress,
+                pitching_team_player: pitching_team_player.as_ref(),
+                pitching_team_progress: *pitching_team_progress,
+                food_emoji: food_emoji.as_ref().map(|s| s.as_ref()),
+                food: *food,
+                batting_team_score: *batting_team_score,
+                pitching_team_score: *pitching_team_score,
+            },
+            WeatherConsumptionEvents::EndContest {
+                winning_score,
+                food_emoji,
+                food,
+                winning_player,
+                winning_team,
+                winning_tokens,
+                winning_prize,
+                losing_team,
+                losing_tokens,
+            } => WeatherConsumptionEvents::EndContest {
+                winning_score: *winning_score,
+                food_emoji: food_emoji.as_ref().map(|s| s.as_ref()),
+                food: *food,
+                winning_player: winning_player.as_ref(),
+                winning_team: winning_team.as_ref(),
+                winning_tokens: *winning_tokens,
+                winning_prize: winning_prize.to_ref(),
+                losing_team: losing_team.as_ref(),
+                losing_tokens: *losing_tokens,
+            },
+            WeatherConsumptionEvents::EndContestTie {
+                final_score,
+                food_emoji,
+                food,
+                batting_team,
+                batting_team_tokens,
+                batting_team_prize,
+                pitching_team,
+                pitching_team_tokens,
+                pitching_team_prize,
+            } => WeatherConsumptionEvents::EndContestTie {
+                final_score: *final_score,
+                food_emoji: food_emoji.as_ref().map(|s| s.as_ref()),
+                food: *food,
+                batting_team: batting_team.as_ref(),
+                batting_team_tokens: *batting_team_tokens,
+                batting_team_prize: batting_team_prize.to_ref(),
+                pitching_team: pitching_team.as_ref(),
+                pitching_team_tokens: *pitching_team_tokens,
+                pitching_team_prize: pitching_team_prize.to_ref(),
+            },
+        }
+    }
+}
+
+/// Converts every string in a parsed event (and the structs nested within it) from one type to another,
+/// e.g. to turn the borrowed strings the parser produces into owned ones, or to intern names.
+///
+/// Every string goes through the closure, including emojis and team names.
+///
+/// ```
+/// use mmolb_parsing::{parsed_event::{EmojiTeam, MapString}, ParsedEventMessage};
+///
+/// let event: ParsedEventMessage<&str> = ParsedEventMessage::LiveNow {
+///     away_team: EmojiTeam { emoji: "🛸", name: "Roswell Weather Balloons" },
+///     home_team: EmojiTeam { emoji: "🧹", name: "Boston Street Sweepers" },
+///     stadium: None,
+/// };
+/// let lengths: ParsedEventMessage<usize> = event.map_string(str::len);
+/// assert!(matches!(lengths, ParsedEventMessage::LiveNow { away_team: EmojiTeam { name: 24, .. }, .. }));
+/// ```
+pub trait MapString<S> {
+    type Mapped<T>;
+
+    fn map_string<T>(self, f: impl FnMut(S) -> T) -> Self::Mapped<T>;
+}
+
+impl ParsedEventMessage<&str> {
+    /// Copies every string, so that the event no longer borrows from the game it was parsed from.
+    pub fn into_owned(self) -> ParsedEventMessage<String> {
+        self.map_string(str::to_string)
+    }
+}
+
+impl<S: AsRef<str> + Clone> ParsedEventMessage<S> {
+    /// Like [`ParsedEventMessage::into_owned`], for events that are still needed afterwards.
+    pub fn to_owned(&self) -> ParsedEventMessage<String> {
+        self.clone().map_string(|s| s.as_ref().to_string())
+    }
+}
+
+impl<S> MapString<S> for EmojiTeam<S> {
+    type Mapped<T> = EmojiTeam<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> EmojiTeam<T> {
+        EmojiTeam {
+            emoji: f(self.emoji),
+            name: f(self.name),
+        }
+    }
+}
+
+impl<S> MapString<S> for EmojiPlayer<S> {
+    type Mapped<T> = EmojiPlayer<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> EmojiPlayer<T> {
+        EmojiPlayer {
+            emoji: f(self.emoji),
+            name: f(self.name),
+        }
+    }
+}
+
+impl<S> MapString<S> for PlacedPlayer<S> {
+    type Mapped<T> = PlacedPlayer<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> PlacedPlayer<T> {
+        PlacedPlayer {
+            name: f(self.name),
+            place: self.place,
+        }
+    }
+}
+
+impl<S> MapString<S> for RunnerOut<S> {
+    type Mapped<T> = RunnerOut<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> RunnerOut<T> {
+        RunnerOut {
+            runner: f(self.runner),
+            base: self.base,
+        }
+    }
+}
+
+impl<S> MapString<S> for RunnerAdvance<S> {
+    type Mapped<T> = RunnerAdvance<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> RunnerAdvance<T> {
+        RunnerAdvance {
+            runner: f(self.runner),
+            base: self.base,
+        }
+    }
+}
+
+impl<S> MapString<S> for BaseSteal<S> {
+    type Mapped<T> = BaseSteal<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> BaseSteal<T> {
+        BaseSteal {
+            runner: f(self.runner),
+            base: self.base,
+            caught: self.caught,
+        }
+    }
+}
+
+impl<S> MapString<S> for Item<S> {
+    type Mapped<T> = Item<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> Item<T> {
+        Item {
+            item_emoji: f(self.item_emoji),
+            item: self.item,
+            affixes: self.affixes.map_string(&mut f),
+        }
+    }
+}
+
+impl<S> MapString<S> for SnappedPhotos<S> {
+    type Mapped<T> = SnappedPhotos<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> SnappedPhotos<T> {
+        SnappedPhotos {
+            first_team_emoji: f(self.first_team_emoji),
+            first_player: self.first_player.map_string(&mut f),
+            second_team_emoji: f(self.second_team_emoji),
+            second_player: self.second_player.map_string(&mut f),
+        }
+    }
+}
+
+impl<S> MapString<S> for ItemPrize<S> {
+    type Mapped<T> = ItemPrize<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> ItemPrize<T> {
+        ItemPrize {
+            item: self.item.map_string(&mut f),
+            equip: self.equip.map_string(&mut f),
+        }
+    }
+}
+
+impl<S> MapString<S> for DoorPrize<S> {
+    type Mapped<T> = DoorPrize<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> DoorPrize<T> {
+        DoorPrize {
+            player: f(self.player),
+            prize: self.prize.map(|x| x.map_string(&mut f)),
+        }
+    }
+}
+
+impl<S> MapString<S> for WitherStruggle<S> {
+    type Mapped<T> = WitherStruggle<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> WitherStruggle<T> {
+        WitherStruggle {
+            team_emoji: f(self.team_emoji),
+            target: self.target.map_string(&mut f),
+            source_name: self.source_name.map(&mut f),
+        }
+    }
+}
+
+impl<S> MapString<S> for Efflorescence<S> {
+    type Mapped<T> = Efflorescence<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> Efflorescence<T> {
+        Efflorescence {
+            player: f(self.player),
+            outcome: self.outcome,
+        }
+    }
+}
+
+impl<S> MapString<S> for EmojiFood<S> {
+    type Mapped<T> = EmojiFood<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> EmojiFood<T> {
+        EmojiFood {
+            food_emoji: f(self.food_emoji),
+            food: self.food,
+        }
+    }
+}
+
+impl<S> MapString<S> for StartOfInningPitcher<S> {
+    type Mapped<T> = StartOfInningPitcher<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> StartOfInningPitcher<T> {
+        match self {
+            StartOfInningPitcher::Same { emoji, name } => StartOfInningPitcher::Same {
+                emoji: f(emoji),
+                name: f(name),
+            },
+            StartOfInningPitcher::Different {
+                leaving_emoji,
+                leaving_pitcher,
+                arriving_emoji,
+                arriving_pitcher,
+            } => StartOfInningPitcher::Different {
+                leaving_emoji: leaving_emoji.map(&mut f),
+                leaving_pitcher: leaving_pitcher.map_string(&mut f),
+                arriving_emoji: arriving_emoji.map(&mut f),
+                arriving_pitcher: arriving_pitcher.map_string(&mut f),
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for FieldingAttempt<S> {
+    type Mapped<T> = FieldingAttempt<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> FieldingAttempt<T> {
+        match self {
+            FieldingAttempt::Out { out } => FieldingAttempt::Out {
+                out: out.map_string(&mut f),
+            },
+            FieldingAttempt::Error { fielder, error } => FieldingAttempt::Error {
+                fielder: f(fielder),
+                error,
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for FallingStarOutcome<S> {
+    type Mapped<T> = FallingStarOutcome<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> FallingStarOutcome<T> {
+        match self {
+            FallingStarOutcome::Injury => FallingStarOutcome::Injury,
+            FallingStarOutcome::Retired(x) => FallingStarOutcome::Retired(x.map(&mut f)),
+            FallingStarOutcome::InfusionI => FallingStarOutcome::InfusionI,
+            FallingStarOutcome::InfusionII => FallingStarOutcome::InfusionII,
+            FallingStarOutcome::InfusionIII => FallingStarOutcome::InfusionIII,
+            FallingStarOutcome::DeflectedHarmlessly => FallingStarOutcome::DeflectedHarmlessly,
+        }
+    }
+}
+
+impl<S> MapString<S> for ItemAffixes<S> {
+    type Mapped<T> = ItemAffixes<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> ItemAffixes<T> {
+        match self {
+            ItemAffixes::None => ItemAffixes::None,
+            ItemAffixes::PrefixSuffix(x0, x1) => ItemAffixes::PrefixSuffix(x0, x1),
+            ItemAffixes::RareName(x) => ItemAffixes::RareName(f(x)),
+        }
+    }
+}
+
+impl<S> MapString<S> for Delivery<S> {
+    type Mapped<T> = Delivery<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> Delivery<T> {
+        match self {
+            Delivery::Successful {
+                team,
+                player,
+                item,
+                equipped,
+                discarded,
+            } => Delivery::Successful {
+                team: team.map_string(&mut f),
+                player: player.map(&mut f),
+                item: item.map_string(&mut f),
+                equipped,
+                discarded: discarded.map(|x| x.map_string(&mut f)),
+            },
+            Delivery::NoSpace { item } => Delivery::NoSpace {
+                item: item.map_string(&mut f),
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for ContainResult<S> {
+    type Mapped<T> = ContainResult<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> ContainResult<T> {
+        match self {
+            ContainResult::NoContain => ContainResult::NoContain,
+            ContainResult::SuccessfulContain {
+                contained_player_name,
+                replacement_player_name,
+            } => ContainResult::SuccessfulContain {
+                contained_player_name: f(contained_player_name),
+                replacement_player_name: f(replacement_player_name),
+            },
+            ContainResult::FailedContain { target_player_name } => ContainResult::FailedContain {
+                target_player_name: f(target_player_name),
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for PartyDurabilityLoss<S> {
+    type Mapped<T> = PartyDurabilityLoss<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> PartyDurabilityLoss<T> {
+        match self {
+            PartyDurabilityLoss::Both(x) => PartyDurabilityLoss::Both(x),
+            PartyDurabilityLoss::OneProtected {
+                protected_player_name,
+                unprotected_player_name,
+                durability_loss,
+            } => PartyDurabilityLoss::OneProtected {
+                protected_player_name: f(protected_player_name),
+                unprotected_player_name: f(unprotected_player_name),
+                durability_loss,
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for KnownBug<S> {
+    type Mapped<T> = KnownBug<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> KnownBug<T> {
+        match self {
+            KnownBug::FirstBasemanChoosesAGhost {
+                batter,
+                first_baseman,
+            } => KnownBug::FirstBasemanChoosesAGhost {
+                batter: f(batter),
+                first_baseman: f(first_baseman),
+            },
+            KnownBug::NoOneProspers => KnownBug::NoOneProspers,
+        }
+    }
+}
+
+impl<S> MapString<S> for EjectionReplacement<S> {
+    type Mapped<T> = EjectionReplacement<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> EjectionReplacement<T> {
+        match self {
+            EjectionReplacement::BenchPlayer { player_name } => EjectionReplacement::BenchPlayer {
+                player_name: f(player_name),
+            },
+            EjectionReplacement::RosterPlayer { player } => EjectionReplacement::RosterPlayer {
+                player: player.map_string(&mut f),
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for Ejection<S> {
+    type Mapped<T> = Ejection<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> Ejection<T> {
+        match self {
+            Ejection::Ejection {
+                team,
+                ejected_player,
+                violation_type,
+                reason,
+                replacement,
+            } => Ejection::Ejection {
+                team: team.map_string(&mut f),
+                ejected_player: ejected_player.map_string(&mut f),
+                violation_type,
+                reason,
+                replacement: replacement.map_string(&mut f),
+            },
+            Ejection::FailedEjection { player_names } => Ejection::FailedEjection {
+                player_names: player_names.map(&mut f),
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for ItemEquip<S> {
+    type Mapped<T> = ItemEquip<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> ItemEquip<T> {
+        match self {
+            ItemEquip::None => ItemEquip::None,
+            ItemEquip::Discarded => ItemEquip::Discarded,
+            ItemEquip::Equipped {
+                player_name,
+                discarded_item,
+            } => ItemEquip::Equipped {
+                player_name: f(player_name),
+                discarded_item: discarded_item.map(|x| x.map_string(&mut f)),
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for Prize<S> {
+    type Mapped<T> = Prize<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> Prize<T> {
+        match self {
+            Prize::Tokens(x) => Prize::Tokens(x),
+            Prize::Items(x) => Prize::Items(x.into_iter().map(|x| x.map_string(&mut f)).collect()),
+        }
+    }
+}
+
+impl<S> MapString<S> for WeatherConsumptionEvents<S> {
+    type Mapped<T> = WeatherConsumptionEvents<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> WeatherConsumptionEvents<T> {
+        match self {
+            WeatherConsumptionEvents::StartContest {
+                batting_team_player,
+                pitching_team_player,
+                emoji_food,
+            } => WeatherConsumptionEvents::StartContest {
+                batting_team_player: batting_team_player.map_string(&mut f),
+                pitching_team_player: pitching_team_player.map_string(&mut f),
+                emoji_food: emoji_food.map_string(&mut f),
+            },
+            WeatherConsumptionEvents::Consumes {
+                batting_team_player,
+                batting_team_progress,
+                pitching_team_player,
+                pitching_team_progress,
+                food_emoji,
+                food,
+                batting_team_score,
+                pitching_team_score,
+            } => WeatherConsumptionEvents::Consumes {
+                batting_team_player: batting_team_player.map_string(&mut f),
+                batting_team_progress,
+                pitching_team_player: pitching_team_player.map_string(&mut f),
+                pitching_team_progress,
+                food_emoji: food_emoji.map(&mut f),
+                food,
+                batting_team_score,
+                pitching_team_score,
+            },
+            WeatherConsumptionEvents::EndContest {
+                winning_score,
+                food_emoji,
+                food,
+                winning_player,
+                winning_team,
+                winning_tokens,
+                winning_prize,
+                losing_team,
+                losing_tokens,
+            } => WeatherConsumptionEvents::EndContest {
+                winning_score,
+                food_emoji: food_emoji.map(&mut f),
+                food,
+                winning_player: winning_player.map_string(&mut f),
+                winning_team: winning_team.map_string(&mut f),
+                winning_tokens,
+                winning_prize: winning_prize.map_string(&mut f),
+                losing_team: losing_team.map_string(&mut f),
+                losing_tokens,
+            },
+            WeatherConsumptionEvents::EndContestTie {
+                final_score,
                 food_emoji,
                 food,
                 batting_team,
@@ -2520,102 +4651,574 @@ impl<S: Display> WeatherConsumptionEvents<S> {
                 pitching_team,
                 pitching_team_tokens,
                 pitching_team_prize,
-            } => {
-                let food = if let Some(emoji) = food_emoji {
-                    format!("{emoji} {food}")
-                } else {
-                    food.to_string()
-                };
-                format!("The Consumption Contest ends in a tie at {final_score} {food} Consumed!<br>{batting_team} receives 🪙 {batting_team_tokens} and a {batting_team_prize}.<br>{pitching_team} receives 🪙 {pitching_team_tokens} and a {pitching_team_prize}.")
+            } => WeatherConsumptionEvents::EndContestTie {
+                final_score,
+                food_emoji: food_emoji.map(&mut f),
+                food,
+                batting_team: batting_team.map_string(&mut f),
+                batting_team_tokens,
+                batting_team_prize: batting_team_prize.map_string(&mut f),
+                pitching_team: pitching_team.map_string(&mut f),
+                pitching_team_tokens,
+                pitching_team_prize: pitching_team_prize.map_string(&mut f),
+            },
+        }
+    }
+}
+
+impl<S> MapString<S> for ParsedEventMessage<S> {
+    type Mapped<T> = ParsedEventMessage<T>;
+
+    fn map_string<T>(self, mut f: impl FnMut(S) -> T) -> ParsedEventMessage<T> {
+        match self {
+            ParsedEventMessage::ParseError { error, message } => ParsedEventMessage::ParseError {
+                error,
+                message: f(message),
+            },
+            ParsedEventMessage::KnownBug { bug } => ParsedEventMessage::KnownBug {
+                bug: bug.map_string(&mut f),
+            },
+            ParsedEventMessage::LiveNow {
+                away_team,
+                home_team,
+                stadium,
+            } => ParsedEventMessage::LiveNow {
+                away_team: away_team.map_string(&mut f),
+                home_team: home_team.map_string(&mut f),
+                stadium: stadium.map(&mut f),
+            },
+            ParsedEventMessage::PitchingMatchup {
+                away_team,
+                home_team,
+                home_pitcher,
+                away_pitcher,
+            } => ParsedEventMessage::PitchingMatchup {
+                away_team: away_team.map_string(&mut f),
+                home_team: home_team.map_string(&mut f),
+                home_pitcher: f(home_pitcher),
+                away_pitcher: f(away_pitcher),
+            },
+            ParsedEventMessage::Lineup { side, players } => ParsedEventMessage::Lineup {
+                side,
+                players: players.into_iter().map(|x| x.map_string(&mut f)).collect(),
+            },
+            ParsedEventMessage::PlayBall => ParsedEventMessage::PlayBall,
+            ParsedEventMessage::GameOver { message } => ParsedEventMessage::GameOver { message },
+            ParsedEventMessage::Recordkeeping {
+                winning_team,
+                losing_team,
+                winning_score,
+                losing_score,
+            } => ParsedEventMessage::Recordkeeping {
+                winning_team: winning_team.map_string(&mut f),
+                losing_team: losing_team.map_string(&mut f),
+                winning_score,
+                losing_score,
+            },
+            ParsedEventMessage::InningStart {
+                number,
+                side,
+                batting_team,
+                automatic_runner,
+                pitcher_status,
+            } => ParsedEventMessage::InningStart {
+                number,
+                side,
+                batting_team: batting_team.map_string(&mut f),
+                automatic_runner: automatic_runner.map(&mut f),
+                pitcher_status: pitcher_status.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::NowBatting { batter, stats } => ParsedEventMessage::NowBatting {
+                batter: f(batter),
+                stats,
+            },
+            ParsedEventMessage::InningEnd { number, side } => {
+                ParsedEventMessage::InningEnd { number, side }
+            }
+            ParsedEventMessage::MoundVisit {
+                team,
+                mound_visit_type,
+            } => ParsedEventMessage::MoundVisit {
+                team: team.map_string(&mut f),
+                mound_visit_type,
+            },
+            ParsedEventMessage::PitcherRemains { remaining_pitcher } => {
+                ParsedEventMessage::PitcherRemains {
+                    remaining_pitcher: remaining_pitcher.map_string(&mut f),
+                }
+            }
+            ParsedEventMessage::PitcherSwap {
+                leaving_pitcher_emoji,
+                leaving_pitcher,
+                arriving_pitcher_emoji,
+                arriving_pitcher_place,
+                arriving_pitcher_name,
+            } => ParsedEventMessage::PitcherSwap {
+                leaving_pitcher_emoji: leaving_pitcher_emoji.map(&mut f),
+                leaving_pitcher: leaving_pitcher.map_string(&mut f),
+                arriving_pitcher_emoji: arriving_pitcher_emoji.map(&mut f),
+                arriving_pitcher_place,
+                arriving_pitcher_name: f(arriving_pitcher_name),
+            },
+            ParsedEventMessage::Ball {
+                steals,
+                count,
+                cheer,
+                aurora_photos,
+                ejection,
+                door_prizes,
+                wither,
+                efflorescence,
+            } => ParsedEventMessage::Ball {
+                steals: steals.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                count,
+                cheer,
+                aurora_photos: aurora_photos.map(|x| x.map_string(&mut f)),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+                door_prizes: door_prizes
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+                wither: wither.map(|x| x.map_string(&mut f)),
+                efflorescence: efflorescence
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+            },
+            ParsedEventMessage::Strike {
+                strike,
+                steals,
+                count,
+                cheer,
+                aurora_photos,
+                ejection,
+                door_prizes,
+                wither,
+                efflorescence,
+            } => ParsedEventMessage::Strike {
+                strike,
+                steals: steals.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                count,
+                cheer,
+                aurora_photos: aurora_photos.map(|x| x.map_string(&mut f)),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+                door_prizes: door_prizes
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+                wither: wither.map(|x| x.map_string(&mut f)),
+                efflorescence: efflorescence
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+            },
+            ParsedEventMessage::Foul {
+                foul,
+                steals,
+                count,
+                cheer,
+                aurora_photos,
+                door_prizes,
+                wither,
+                efflorescence,
+            } => ParsedEventMessage::Foul {
+                foul,
+                steals: steals.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                count,
+                cheer,
+                aurora_photos: aurora_photos.map(|x| x.map_string(&mut f)),
+                door_prizes: door_prizes
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+                wither: wither.map(|x| x.map_string(&mut f)),
+                efflorescence: efflorescence
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+            },
+            ParsedEventMessage::Walk {
+                batter,
+                scores,
+                advances,
+                cheer,
+                aurora_photos,
+                ejection,
+                wither,
+            } => ParsedEventMessage::Walk {
+                batter: f(batter),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                cheer,
+                aurora_photos: aurora_photos.map(|x| x.map_string(&mut f)),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+                wither: wither.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::HitByPitch {
+                batter,
+                scores,
+                advances,
+                cheer,
+                aurora_photos,
+                ejection,
+                door_prizes,
+                wither,
+                efflorescence,
+            } => ParsedEventMessage::HitByPitch {
+                batter: f(batter),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                cheer,
+                aurora_photos: aurora_photos.map(|x| x.map_string(&mut f)),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+                door_prizes: door_prizes
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+                wither: wither.map(|x| x.map_string(&mut f)),
+                efflorescence: efflorescence
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+            },
+            ParsedEventMessage::FairBall {
+                batter,
+                fair_ball_type,
+                destination,
+                cheer,
+                aurora_photos,
+                door_prizes,
+                efflorescence,
+            } => ParsedEventMessage::FairBall {
+                batter: f(batter),
+                fair_ball_type,
+                destination,
+                cheer,
+                aurora_photos: aurora_photos.map(|x| x.map_string(&mut f)),
+                door_prizes: door_prizes
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+                efflorescence: efflorescence
+                    .into_iter()
+                    .map(|x| x.map_string(&mut f))
+                    .collect(),
+            },
+            ParsedEventMessage::StrikeOut {
+                foul,
+                batter,
+                strike,
+                steals,
+                cheer,
+                aurora_photos,
+                ejection,
+                wither,
+            } => ParsedEventMessage::StrikeOut {
+                foul,
+                batter: f(batter),
+                strike,
+                steals: steals.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                cheer,
+                aurora_photos: aurora_photos.map(|x| x.map_string(&mut f)),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+                wither: wither.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::BatterToBase {
+                batter,
+                distance,
+                fair_ball_type,
+                fielder,
+                scores,
+                advances,
+                ejection,
+            } => ParsedEventMessage::BatterToBase {
+                batter: f(batter),
+                distance,
+                fair_ball_type,
+                fielder: fielder.map_string(&mut f),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::HomeRun {
+                batter,
+                fair_ball_type,
+                destination,
+                scores,
+                grand_slam,
+                ejection,
+            } => ParsedEventMessage::HomeRun {
+                batter: f(batter),
+                fair_ball_type,
+                destination,
+                scores: scores.into_iter().map(&mut f).collect(),
+                grand_slam,
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::CaughtOut {
+                batter,
+                fair_ball_type,
+                caught_by,
+                scores,
+                advances,
+                sacrifice,
+                perfect,
+                ejection,
+            } => ParsedEventMessage::CaughtOut {
+                batter: f(batter),
+                fair_ball_type,
+                caught_by: caught_by.map_string(&mut f),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                sacrifice,
+                perfect,
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::GroundedOut {
+                batter,
+                fielders,
+                scores,
+                advances,
+                amazing,
+                ejection,
+            } => ParsedEventMessage::GroundedOut {
+                batter: f(batter),
+                fielders: fielders.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                amazing,
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::ForceOut {
+                batter,
+                fielders,
+                fair_ball_type,
+                out,
+                scores,
+                advances,
+                ejection,
+            } => ParsedEventMessage::ForceOut {
+                batter: f(batter),
+                fielders: fielders.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                fair_ball_type,
+                out: out.map_string(&mut f),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::ReachOnFieldersChoice {
+                batter,
+                fielders,
+                result,
+                scores,
+                advances,
+                ejection,
+            } => ParsedEventMessage::ReachOnFieldersChoice {
+                batter: f(batter),
+                fielders: fielders.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                result: result.map_string(&mut f),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::DoublePlayGrounded {
+                batter,
+                fielders,
+                out_one,
+                out_two,
+                scores,
+                advances,
+                sacrifice,
+                ejection,
+            } => ParsedEventMessage::DoublePlayGrounded {
+                batter: f(batter),
+                fielders: fielders.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                out_one: out_one.map_string(&mut f),
+                out_two: out_two.map_string(&mut f),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                sacrifice,
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::DoublePlayCaught {
+                batter,
+                fair_ball_type,
+                fielders,
+                out_two,
+                scores,
+                advances,
+                ejection,
+            } => ParsedEventMessage::DoublePlayCaught {
+                batter: f(batter),
+                fair_ball_type,
+                fielders: fielders.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                out_two: out_two.map_string(&mut f),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::ReachOnFieldingError {
+                batter,
+                fielder,
+                error,
+                scores,
+                advances,
+                ejection,
+            } => ParsedEventMessage::ReachOnFieldingError {
+                batter: f(batter),
+                fielder: fielder.map_string(&mut f),
+                error,
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+                ejection: ejection.map(|x| x.map_string(&mut f)),
+            },
+            ParsedEventMessage::WeatherDelivery { delivery } => {
+                ParsedEventMessage::WeatherDelivery {
+                    delivery: delivery.map_string(&mut f),
+                }
             }
-        }
-    }
-}
-
-impl<S: AsRef<str>> WeatherConsumptionEvents<S> {
-    pub fn to_ref(&self) -> WeatherConsumptionEvents<&str> {
-        match &self {
-            WeatherConsumptionEvents::StartContest {
-                batting_team_player,
-                pitching_team_player,
-                emoji_food: food,
-            } => WeatherConsumptionEvents::StartContest {
-                batting_team_player: batting_team_player.as_ref(),
-                pitching_team_player: pitching_team_player.as_ref(),
-                emoji_food: food.as_ref(),
+            ParsedEventMessage::FallingStar { player_name } => ParsedEventMessage::FallingStar {
+                player_name: f(player_name),
             },
-            WeatherConsumptionEvents::Consumes {
-                batting_team_player,
-                batting_team_progress,
-                pitching_team_player,
-                pitching_team_progress,
-                food_emoji,
-                food,
-                batting_team_score,
-                pitching_team_score,
-            } => WeatherConsumptionEvents::Consumes {
-                batting_team_player: batting_team_player.as_ref(),
-                batting_team_progress: *batting_team_progress,
-                pitching_team_player: pitching_team_player.as_ref(),
-                pitching_team_progress: *pitching_team_progress,
-                food_emoji: food_emoji.as_ref().map(|s| s.as_ref()),
-                food: *food,
-                batting_team_score: *batting_team_score,
-                pitching_team_score: *pitching_team_score,
+            ParsedEventMessage::FallingStarOutcome {
+                deflection,
+                player_name,
+                outcome,
+            } => ParsedEventMessage::FallingStarOutcome {
+                deflection: deflection.map(&mut f),
+                player_name: f(player_name),
+                outcome: outcome.map_string(&mut f),
             },
-            WeatherConsumptionEvents::EndContest {
-                winning_score,
-                food_emoji,
-                food,
-                winning_player,
+            ParsedEventMessage::WeatherShipment { deliveries } => {
+                ParsedEventMessage::WeatherShipment {
+                    deliveries: deliveries
+                        .into_iter()
+                        .map(|x| x.map_string(&mut f))
+                        .collect(),
+                }
+            }
+            ParsedEventMessage::WeatherSpecialDelivery { delivery } => {
+                ParsedEventMessage::WeatherSpecialDelivery {
+                    delivery: delivery.map_string(&mut f),
+                }
+            }
+            ParsedEventMessage::Balk {
+                pitcher,
+                scores,
+                advances,
+            } => ParsedEventMessage::Balk {
+                pitcher: f(pitcher),
+                scores: scores.into_iter().map(&mut f).collect(),
+                advances: advances.into_iter().map(|x| x.map_string(&mut f)).collect(),
+            },
+            ParsedEventMessage::WeatherProsperity {
+                home_income,
+                away_income,
+            } => ParsedEventMessage::WeatherProsperity {
+                home_income,
+                away_income,
+            },
+            ParsedEventMessage::PhotoContest {
                 winning_team,
                 winning_tokens,
-                winning_prize,
+                winning_player,
+                winning_score,
                 losing_team,
                 losing_tokens,
-            } => WeatherConsumptionEvents::EndContest {
-                winning_score: *winning_score,
-                food_emoji: food_emoji.as_ref().map(|s| s.as_ref()),
-                food: *food,
-                winning_player: winning_player.as_ref(),
-                winning_team: winning_team.as_ref(),
-                winning_tokens: *winning_tokens,
-                winning_prize: winning_prize.to_ref(),
-                losing_team: losing_team.as_ref(),
-                losing_tokens: *losing_tokens,
+                losing_player,
+                losing_score,
+            } => ParsedEventMessage::PhotoContest {
+                winning_team: winning_team.map_string(&mut f),
+                winning_tokens,
+                winning_player: f(winning_player),
+                winning_score,
+                losing_team: losing_team.map_string(&mut f),
+                losing_tokens,
+                losing_player: f(losing_player),
+                losing_score,
             },
-            WeatherConsumptionEvents::EndContestTie {
-                final_score,
-                food_emoji,
-                food,
-                batting_team,
-                batting_team_tokens,
-                batting_team_prize,
-                pitching_team,
-                pitching_team_tokens,
-                pitching_team_prize,
-            } => WeatherConsumptionEvents::EndContestTie {
-                final_score: *final_score,
-                food_emoji: food_emoji.as_ref().map(|s| s.as_ref()),
-                food: *food,
-                batting_team: batting_team.as_ref(),
-                batting_team_tokens: *batting_team_tokens,
-                batting_team_prize: batting_team_prize.to_ref(),
-                pitching_team: pitching_team.as_ref(),
-                pitching_team_tokens: *pitching_team_tokens,
-                pitching_team_prize: pitching_team_prize.to_ref(),
+            ParsedEventMessage::Party {
+                pitcher_name,
+                pitcher_amount_gained,
+                pitcher_attribute,
+                batter_name,
+                batter_amount_gained,
+                batter_attribute,
+                durability_loss,
+            } => ParsedEventMessage::Party {
+                pitcher_name: f(pitcher_name),
+                pitcher_amount_gained,
+                pitcher_attribute,
+                batter_name: f(batter_name),
+                batter_amount_gained,
+                batter_attribute,
+                durability_loss: durability_loss.map_string(&mut f),
+            },
+            ParsedEventMessage::WeatherReflection { team } => {
+                ParsedEventMessage::WeatherReflection {
+                    team: team.map_string(&mut f),
+                }
+            }
+            ParsedEventMessage::WeatherWither {
+                team_emoji,
+                player,
+                corrupted,
+                contained,
+            } => ParsedEventMessage::WeatherWither {
+                team_emoji: f(team_emoji),
+                player: player.map_string(&mut f),
+                corrupted,
+                contained: contained.map_string(&mut f),
+            },
+            ParsedEventMessage::LinealBeltTransfer {
+                claimed_by,
+                claimed_from,
+            } => ParsedEventMessage::LinealBeltTransfer {
+                claimed_by: claimed_by.map_string(&mut f),
+                claimed_from: claimed_from.map_string(&mut f),
+            },
+            ParsedEventMessage::WeatherConsumption(x) => {
+                ParsedEventMessage::WeatherConsumption(x.map_string(&mut f))
+            }
+            ParsedEventMessage::WeatherSimulacrum {
+                real_team,
+                simulacrum_team,
+                tokens_earnt,
+            } => ParsedEventMessage::WeatherSimulacrum {
+                real_team: real_team.map_string(&mut f),
+                simulacrum_team: simulacrum_team.map_string(&mut f),
+                tokens_earnt,
             },
+            ParsedEventMessage::WeatherSimulacrumOffseason => {
+                ParsedEventMessage::WeatherSimulacrumOffseason
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod test {
-    use std::fs::File;
+    use std::{collections::HashSet, fs::File};
 
     use serde::Deserialize;
-
-    use crate::{parsed_event::Cheer, process_game, utils::no_tracing_errs, Game};
+    use strum::IntoEnumIterator;
+
+    use crate::{
+        enums::{
+            Attribute, Base, BaseNameVariant, CelestialEnergyTier, EventType, FairBallDestination,
+            FairBallType, FieldingErrorType, FoodName, ItemName, ItemPrefix, ItemSuffix, Place,
+        },
+        parsed_event::{
+            BaseRunningEvent, BaseSteal, Cheer, ContainResult, Delivery, Ejection, EjectionReason,
+            EmojiFood, EmojiPlayer, EmojiTeam, FallingStarOutcome, FieldingAttempt,
+            GameEventParseError, Item, ItemAffixes, ItemEquip, ItemPrize, KnownBug, MapString,
+            ParsedEventMessageDiscriminants, PartyDurabilityLoss, PlacedPlayer, Prize,
+            RunnerAdvance, RunnerOut, ViolationType, WeatherConsumptionEvents,
+        },
+        process_event, process_game,
+        utils::{no_tracing_errs, raw_test_data},
+        Game, MentionsPlayer, NotRecognized, ParsedEventMessage,
+    };
 
     //https://freecashe.ws/api/chron/v0/entities?kind=game&id=6851bb34f419fdc04f9d0ed5,685b744530d8d1ac659c30de,68611cb61e65f5fb52cb618f,68611cb61e65f5fb52cb61d6,68799d0621c82ae41451ca4f,68782f7d206bc4d2a2003b05,6879f14e21c82ae41451e785,6893c2899361d52a6890a9f0
     #[test]
@@ -2656,4 +5259,1031 @@ mod test {
     fn cheer_unknown() {
         assert_eq!(Cheer::Unknown("Example".to_string()).as_str(), "Example");
     }
+
+    #[test]
+    fn livingston_mentions() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let parsed = process_game(&game, "");
+        for (event, parsed) in game.event_log.iter().zip(&parsed) {
+            for name in parsed.player_names() {
+                assert!(event.message.contains(*name));
+                assert!(parsed.mentions(name));
+            }
+            assert!(!parsed.mentions(""));
+        }
+
+        let batter = parsed
+            .iter()
+            .find_map(|event| match event {
+                ParsedEventMessage::NowBatting { batter, .. } => Some(*batter),
+                _ => None,
+            })
+            .unwrap();
+        let mentions = parsed.iter().filter(|event| event.mentions(batter)).count();
+        let at_bats = parsed
+            .iter()
+            .filter(|event| matches!(event, ParsedEventMessage::NowBatting { batter: b, .. } if *b == batter))
+            .count();
+        assert!(mentions > at_bats);
+    }
+
+    #[test]
+    fn mentioned_players() {
+        let f = File::open("test_data/raw/game/690c0f340d411ebcb8dafe5f.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let parsed = process_game(&game, "");
+        // Craig Little grounds out to 1B Brett Shen. Tara Soriano scores! Christine Hammond to third base.
+        let grounded_out = &parsed[361];
+        assert!(matches!(
+            grounded_out,
+            ParsedEventMessage::GroundedOut { .. }
+        ));
+        assert_eq!(
+            grounded_out.mentioned_players().collect::<HashSet<_>>(),
+            HashSet::from([
+                &"Craig Little",
+                &"Brett Shen",
+                &"Tara Soriano",
+                &"Christine Hammond"
+            ])
+        );
+
+        for event in &parsed {
+            let players = event.mentioned_players().collect::<Vec<_>>();
+            let distinct = players.iter().collect::<HashSet<_>>();
+            assert_eq!(players.len(), distinct.len());
+        }
+    }
+
+    #[test]
+    fn livingston_outs_recorded() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let mut outs = 0;
+        let mut half_innings = 0;
+        for event in process_game(&game, "") {
+            match event {
+                ParsedEventMessage::InningStart { .. } => outs = 0,
+                ParsedEventMessage::InningEnd { .. } => {
+                    assert_eq!(outs, 3);
+                    half_innings += 1;
+                }
+                event => outs += event.outs_recorded(),
+            }
+        }
+        assert!(half_innings >= 17);
+    }
+
+    #[test]
+    fn event_type() {
+        // Has falling star and weather events as well as the usual ones
+        let f = File::open("test_data/raw/game/68f051cdd1004b0ad6d643e5.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let mut event_types = HashSet::new();
+        for (event, parsed) in game
+            .event_log
+            .iter()
+            .zip(process_game(&game, "68f051cdd1004b0ad6d643e5"))
+        {
+            assert_eq!(parsed.event_type(), event.event.as_ref().ok().copied());
+            event_types.insert(parsed.event_type().unwrap());
+        }
+        assert!(event_types.contains(&EventType::FallingStar));
+        assert!(event_types.contains(&EventType::Weather));
+        assert_eq!(event_types.len(), 14);
+    }
+
+    #[test]
+    fn livingston_batter() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let mut current_batter = None;
+        let mut at_bat_events = 0;
+        for event in process_game(&game, "") {
+            if let ParsedEventMessage::NowBatting { batter, .. } = event {
+                current_batter = Some(batter);
+            } else if let Some(batter) = event.batter() {
+                assert_eq!(Some(*batter), current_batter);
+                at_bat_events += 1;
+            }
+        }
+        assert!(at_bat_events > 0);
+    }
+
+    #[test]
+    fn livingston_scoring_runners() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let mut game: Game = serde_json::from_reader(f).unwrap();
+
+        let parsed = process_game(&game, "");
+        let sacrifice_fly = parsed
+            .iter()
+            .find(|event| {
+                matches!(
+                    event,
+                    ParsedEventMessage::CaughtOut {
+                        sacrifice: true,
+                        ..
+                    }
+                )
+            })
+            .unwrap();
+        assert_eq!(sacrifice_fly.scoring_runners(), ["Jacob Taş"]);
+        assert!(sacrifice_fly.advancing_runners().is_empty());
+
+        let (index, grand_slam) = parsed
+            .iter()
+            .enumerate()
+            .find_map(|(i, event)| match event {
+                ParsedEventMessage::HomeRun {
+                    batter,
+                    fair_ball_type,
+                    destination,
+                    ..
+                } => Some((
+                    i,
+                    ParsedEventMessage::HomeRun {
+                        batter: batter.to_string(),
+                        fair_ball_type: *fair_ball_type,
+                        destination: *destination,
+                        scores: vec![
+                            "Runner One".to_string(),
+                            "Runner Two".to_string(),
+                            "Runner Three".to_string(),
+                        ],
+                        grand_slam: true,
+                        ejection: None,
+                    },
+                )),
+                _ => None,
+            })
+            .unwrap();
+        drop(parsed);
+
+        let message = grand_slam.unparse(&game, game.event_log[index].index);
+        game.event_log[index].message = message;
+        let reparsed = process_event(&game.event_log[index], &game, "");
+        assert_eq!(
+            reparsed.scoring_runners(),
+            ["Runner One", "Runner Two", "Runner Three"]
+        );
+        assert!(reparsed.advancing_runners().is_empty());
+    }
+
+    #[test]
+    fn livingston_fielders() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let parsed = process_game(&game, "");
+        let double_play = parsed
+            .iter()
+            .find(|event| {
+                matches!(event, ParsedEventMessage::DoublePlayGrounded { batter, .. } if *batter == "Princess Injury")
+            })
+            .unwrap();
+        let places = double_play
+            .fielders()
+            .iter()
+            .map(|fielder| fielder.place)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            places,
+            [Place::ShortStop, Place::SecondBaseman, Place::FirstBaseman]
+        );
+        assert_eq!(double_play.fielders()[0].name, "Jacob Taş");
+
+        let caught_out = parsed
+            .iter()
+            .find(|event| matches!(event, ParsedEventMessage::CaughtOut { .. }))
+            .unwrap();
+        assert_eq!(caught_out.fielders().len(), 1);
+
+        assert!(parsed
+            .iter()
+            .filter(|event| matches!(event, ParsedEventMessage::Ball { .. }))
+            .all(|event| event.fielders().is_empty()));
+    }
+
+    #[test]
+    fn weather_reflection_round_trip() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let mut game: Game = serde_json::from_reader(f).unwrap();
+
+        // There's no reflection event in the test data yet, so dress up the last event as one.
+        let reflection = ParsedEventMessage::WeatherReflection {
+            team: EmojiTeam {
+                emoji: game.home_team_emoji.clone(),
+                name: game.home_team_name.clone(),
+            },
+        };
+        let index = game.event_log.len() - 1;
+        let message = reflection.unparse(&game, game.event_log[index].index);
+        assert_eq!(
+            message,
+            format!(
+                "🪞 The reflection shatters. {} {} received a Fragment of Reflection.",
+                game.home_team_emoji, game.home_team_name
+            )
+        );
+
+        game.event_log[index].event = Ok(EventType::WeatherReflection);
+        game.event_log[index].message = message;
+        let _guard = no_tracing_errs();
+        let reparsed = process_event(&game.event_log[index], &game, "");
+        assert_eq!(reparsed.to_owned(), reflection);
+    }
+
+    #[test]
+    fn all_cheers() {
+        let _guard = no_tracing_errs();
+        for cheer in Cheer::all() {
+            assert!(cheer.is_known());
+            assert_eq!(&Cheer::new(&cheer.to_string()), cheer);
+        }
+        assert!(!Cheer::Unknown("Nobody cheers".to_string()).is_known());
+    }
+
+    #[test]
+    fn all_ejection_reasons() {
+        let _guard = no_tracing_errs();
+        for reason in EjectionReason::all() {
+            assert_eq!(&EjectionReason::new(&reason.to_string()), reason);
+            assert!(reason.violation_type().is_some());
+        }
+        for violation in ViolationType::all() {
+            assert_eq!(&ViolationType::new(&violation.to_string()), violation);
+            assert!(EjectionReason::of(violation.clone()).count() > 0);
+        }
+
+        let grouped: usize = ViolationType::all()
+            .iter()
+            .map(|violation| EjectionReason::of(violation.clone()).count())
+            .sum();
+        assert_eq!(grouped, EjectionReason::all().len());
+        assert_eq!(EjectionReason::of(ViolationType::Uniform).count(), 4);
+        assert_eq!(
+            EjectionReason::of(ViolationType::Unknown("Fashion".to_string())).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn cheers_and_aurora_photos() {
+        let f = File::open("test_data/raw/game/69283d7858ef3a953b529432.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let parsed = process_game(&game, "");
+        let count_messages = |text: &str| {
+            game.event_log
+                .iter()
+                .filter(|event| event.message.contains(text))
+                .count()
+        };
+        assert_eq!(
+            parsed
+                .iter()
+                .filter(|event| event.cheer().is_some())
+                .count(),
+            count_messages("📣")
+        );
+        assert_eq!(
+            parsed
+                .iter()
+                .filter(|event| event.aurora_photos().is_some())
+                .count(),
+            count_messages("snapped photos of the aurora")
+        );
+        assert!(parsed
+            .iter()
+            .filter_map(|event| event.cheer())
+            .all(|cheer| cheer.is_known()));
+        assert!(parsed
+            .iter()
+            .filter(|event| matches!(event, ParsedEventMessage::InningStart { .. }))
+            .all(|event| event.cheer().is_none() && event.aurora_photos().is_none()));
+    }
+
+    #[test]
+    fn eq_play() {
+        let f = File::open("test_data/raw/game/69283d7858ef3a953b529432.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+        let parsed = process_game(&game, "");
+
+        let cheered = parsed.iter().find(|event| event.cheer().is_some()).unwrap();
+        let mut uncheered = cheered.clone();
+        let mut recheered = cheered.clone();
+        match (&mut uncheered, &mut recheered) {
+            (
+                ParsedEventMessage::Ball { cheer, .. }
+                | ParsedEventMessage::Strike { cheer, .. }
+                | ParsedEventMessage::Foul { cheer, .. }
+                | ParsedEventMessage::Walk { cheer, .. }
+                | ParsedEventMessage::HitByPitch { cheer, .. }
+                | ParsedEventMessage::FairBall { cheer, .. }
+                | ParsedEventMessage::StrikeOut { cheer, .. },
+                ParsedEventMessage::Ball { cheer: other, .. }
+                | ParsedEventMessage::Strike { cheer: other, .. }
+                | ParsedEventMessage::Foul { cheer: other, .. }
+                | ParsedEventMessage::Walk { cheer: other, .. }
+                | ParsedEventMessage::HitByPitch { cheer: other, .. }
+                | ParsedEventMessage::FairBall { cheer: other, .. }
+                | ParsedEventMessage::StrikeOut { cheer: other, .. },
+            ) => {
+                *cheer = None;
+                *other = Some(Cheer::Unknown("The crowd does the wave".to_string()));
+            }
+            _ => unreachable!("only these variants have cheers"),
+        }
+
+        for other in [&uncheered, &recheered] {
+            assert_ne!(cheered, other);
+            assert!(cheered.eq_play(other));
+            assert!(other.eq_play(cheered));
+        }
+
+        // Anything else still counts
+        let ball = |count, cheer| ParsedEventMessage::<&str>::Ball {
+            steals: Vec::new(),
+            count,
+            cheer,
+            aurora_photos: None,
+            ejection: None,
+            door_prizes: Vec::new(),
+            wither: None,
+            efflorescence: Vec::new(),
+        };
+        assert!(ball((1, 0), Some(Cheer::TheCrowdIsEcstatic)).eq_play(&ball((1, 0), None)));
+        assert!(!ball((1, 0), None).eq_play(&ball((2, 0), None)));
+        assert!(!cheered.eq_play(&ParsedEventMessage::PlayBall));
+    }
+
+    #[test]
+    fn ejections() {
+        for id in ["68e83eac53a747939a79ee8a", "69283d7858ef3a953b529432"] {
+            let f = File::open(format!("test_data/raw/game/{id}.json")).unwrap();
+            let game: Game = serde_json::from_reader(f).unwrap();
+
+            let parsed = process_game(&game, "");
+            let robo_ump_messages = game
+                .event_log
+                .iter()
+                .filter(|event| event.message.contains("ROBO-UMP"))
+                .count();
+            assert!(robo_ump_messages > 0);
+            assert_eq!(
+                parsed
+                    .iter()
+                    .filter(|event| event.ejection().is_some())
+                    .count(),
+                robo_ump_messages
+            );
+        }
+    }
+
+    #[test]
+    fn ejection_violation_types() {
+        let mut ejections = 0;
+        for id in ["68e83eac53a747939a79ee8a", "69283d7858ef3a953b529432"] {
+            let f = File::open(format!("test_data/raw/game/{id}.json")).unwrap();
+            let game: Game = serde_json::from_reader(f).unwrap();
+
+            for event in process_game(&game, "") {
+                if let Some(Ejection::Ejection {
+                    violation_type,
+                    reason,
+                    ..
+                }) = event.ejection()
+                {
+                    assert_eq!(reason.violation_type().as_ref(), Some(violation_type));
+                    ejections += 1;
+                }
+            }
+        }
+        assert_eq!(ejections, 3);
+        assert_eq!(
+            EjectionReason::Unknown("breathing".to_string()).violation_type(),
+            None
+        );
+    }
+
+    #[test]
+    fn into_owned_round_trip() {
+        for path in [
+            "test_data/livingston_game.json",
+            "test_data/s2_d240_game.json",
+        ] {
+            let f = File::open(path).unwrap();
+            let game: Game = serde_json::from_reader(f).unwrap();
+
+            for (event, parsed) in game.event_log.iter().zip(process_game(&game, "")) {
+                let owned = parsed.to_owned();
+                assert_eq!(owned, parsed.into_owned());
+                assert_eq!(owned.unparse(&game, event.index), event.message);
+            }
+        }
+    }
+
+    #[test]
+    fn unparse_into() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        // Reuses one buffer for the whole game, the way unparse_into is meant to be used
+        let mut message = String::new();
+        for (event, parsed) in game
+            .event_log
+            .iter()
+            .zip(process_game(&game, "68474b55452606ed6b72dbe8"))
+        {
+            message.clear();
+            parsed
+                .unparse_into(&mut message, &game, event.index)
+                .unwrap();
+            assert_eq!(message, event.message);
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_messages_unparse() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        // A cheap deterministic byte stream, so failures are reproducible without a fuzzer.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let bytes: Vec<u8> = std::iter::repeat_with(|| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .take(1 << 16)
+        .collect();
+
+        for start in (0..bytes.len()).step_by(64) {
+            let mut u = Unstructured::new(&bytes[start..]);
+            let Ok(message) = ParsedEventMessage::<String>::arbitrary(&mut u) else {
+                continue;
+            };
+            message.unparse(&game, None);
+        }
+    }
+
+    #[test]
+    fn map_string() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        for parsed in process_game(&game, "") {
+            let mut strings = Vec::new();
+            let lengths = parsed.clone().map_string(|s| {
+                strings.push(s);
+                s.len()
+            });
+
+            let mut owned = parsed.clone().map_string(str::to_string);
+            assert_eq!(owned, parsed.to_owned());
+
+            // The closure is applied to the strings in the same order each time
+            let mut i = 0;
+            owned = owned.map_string(|s| {
+                assert_eq!(s, strings[i]);
+                i += 1;
+                s
+            });
+            assert_eq!(i, strings.len());
+            assert_eq!(owned.map_string(|s| s.len()), lengths);
+
+            if let ParsedEventMessage::LiveNow { away_team, .. } = lengths {
+                assert_eq!(away_team.emoji, game.away_team_emoji.len());
+                assert_eq!(away_team.name, game.away_team_name.len());
+            }
+        }
+    }
+
+    fn assert_serde_round_trip(parsed: &ParsedEventMessage<String>) {
+        let json = serde_json::to_value(parsed).unwrap();
+        let round_tripped: ParsedEventMessage<String> = serde_json::from_value(json.clone())
+            .unwrap_or_else(|e| panic!("{e} deserializing {json}"));
+        assert_eq!(&round_tripped, parsed);
+        assert_eq!(serde_json::to_value(&round_tripped).unwrap(), json);
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let livingston: Game = serde_json::from_reader(f).unwrap();
+        let mut games = raw_test_data::<Game>("game");
+        games.push(("68474b55452606ed6b72dbe8".to_string(), livingston));
+
+        let mut variants = HashSet::new();
+        for (game_id, game) in &games {
+            for parsed in process_game(game, game_id) {
+                variants.insert(ParsedEventMessageDiscriminants::from(&parsed));
+                assert_serde_round_trip(&parsed.to_owned());
+            }
+        }
+
+        // Variants that don't appear in the test data
+        let team = |name: &str| EmojiTeam {
+            emoji: "🦀".to_string(),
+            name: name.to_string(),
+        };
+        let player = |name: &str| EmojiPlayer {
+            emoji: "🦀".to_string(),
+            name: name.to_string(),
+        };
+        let item = Item {
+            item_emoji: "🧢".to_string(),
+            item: ItemName::Cap,
+            affixes: ItemAffixes::PrefixSuffix(vec![ItemPrefix::Sharp], vec![ItemSuffix::Cat]),
+        };
+        let food = FoodName::GiantHotDogs;
+        let samples = [
+            ParsedEventMessage::ParseError {
+                error: GameEventParseError::FailedParsingMessage {
+                    event_type: EventType::Pitch,
+                    message: "Mystery.".to_string(),
+                    offset: Some(0),
+                    context: vec!["Pitch".to_string()],
+                },
+                message: "Mystery.".to_string(),
+            },
+            ParsedEventMessage::ParseError {
+                error: GameEventParseError::EventTypeNotRecognized(NotRecognized("Mystery".into())),
+                message: "Mystery.".to_string(),
+            },
+            ParsedEventMessage::KnownBug {
+                bug: KnownBug::FirstBasemanChoosesAGhost {
+                    batter: "Batter".to_string(),
+                    first_baseman: "First Baseman".to_string(),
+                },
+            },
+            ParsedEventMessage::KnownBug {
+                bug: KnownBug::NoOneProspers,
+            },
+            ParsedEventMessage::WeatherSpecialDelivery {
+                delivery: Delivery::Successful {
+                    team: team("Crabs"),
+                    player: Some("Player".to_string()),
+                    item: item.clone(),
+                    equipped: true,
+                    discarded: Some(Item {
+                        affixes: ItemAffixes::RareName("Rare".to_string()),
+                        ..item.clone()
+                    }),
+                },
+            },
+            ParsedEventMessage::WeatherSpecialDelivery {
+                delivery: Delivery::NoSpace {
+                    item: Item {
+                        affixes: ItemAffixes::None,
+                        ..item.clone()
+                    },
+                },
+            },
+            ParsedEventMessage::Balk {
+                pitcher: "Pitcher".to_string(),
+                scores: vec!["Runner".to_string()],
+                advances: vec![RunnerAdvance {
+                    runner: "Other Runner".to_string(),
+                    base: Base::Third,
+                }],
+            },
+            ParsedEventMessage::WeatherProsperity {
+                home_income: 10,
+                away_income: 5,
+            },
+            ParsedEventMessage::Party {
+                pitcher_name: "Pitcher".to_string(),
+                pitcher_amount_gained: 50,
+                pitcher_attribute: Attribute::Velocity,
+                batter_name: "Batter".to_string(),
+                batter_amount_gained: 50,
+                batter_attribute: Attribute::Contact,
+                durability_loss: PartyDurabilityLoss::Both(5),
+            },
+            ParsedEventMessage::Party {
+                pitcher_name: "Pitcher".to_string(),
+                pitcher_amount_gained: 50,
+                pitcher_attribute: Attribute::Velocity,
+                batter_name: "Batter".to_string(),
+                batter_amount_gained: 50,
+                batter_attribute: Attribute::Contact,
+                durability_loss: PartyDurabilityLoss::OneProtected {
+                    protected_player_name: "Pitcher".to_string(),
+                    unprotected_player_name: "Batter".to_string(),
+                    durability_loss: 5,
+                },
+            },
+            ParsedEventMessage::WeatherReflection {
+                team: team("Crabs"),
+            },
+            ParsedEventMessage::WeatherConsumption(WeatherConsumptionEvents::StartContest {
+                batting_team_player: player("Batter"),
+                pitching_team_player: player("Pitcher"),
+                emoji_food: EmojiFood {
+                    food_emoji: "🌭".to_string(),
+                    food,
+                },
+            }),
+            ParsedEventMessage::WeatherConsumption(WeatherConsumptionEvents::Consumes {
+                batting_team_player: player("Batter"),
+                batting_team_progress: 3,
+                pitching_team_player: player("Pitcher"),
+                pitching_team_progress: 2,
+                food_emoji: Some("🌭".to_string()),
+                food,
+                batting_team_score: 3,
+                pitching_team_score: 2,
+            }),
+            ParsedEventMessage::WeatherConsumption(WeatherConsumptionEvents::EndContest {
+                winning_score: 10,
+                food_emoji: None,
+                food,
+                winning_player: player("Batter"),
+                winning_team: team("Crabs"),
+                winning_tokens: 20,
+                winning_prize: item.clone(),
+                losing_team: team("Lobsters"),
+                losing_tokens: 10,
+            }),
+            ParsedEventMessage::WeatherConsumption(WeatherConsumptionEvents::EndContestTie {
+                final_score: 10,
+                food_emoji: None,
+                food,
+                batting_team: team("Crabs"),
+                batting_team_tokens: 15,
+                batting_team_prize: item.clone(),
+                pitching_team: team("Lobsters"),
+                pitching_team_tokens: 15,
+                pitching_team_prize: item,
+            }),
+            ParsedEventMessage::WeatherSimulacrum {
+                real_team: team("Crabs"),
+                simulacrum_team: team("Simulacrabs"),
+                tokens_earnt: 10,
+            },
+            ParsedEventMessage::WeatherSimulacrumOffseason,
+        ];
+        for sample in &samples {
+            variants.insert(ParsedEventMessageDiscriminants::from(sample));
+            assert_serde_round_trip(sample);
+        }
+
+        assert_eq!(
+            variants.len(),
+            ParsedEventMessageDiscriminants::iter().count()
+        );
+    }
+
+    #[test]
+    fn plate_appearances() {
+        // Extra innings with runners caught stealing, and a batter contained by the wither mid plate appearance
+        for game_id in ["68f4a8d04d9c8c3ffed1a951", "69045e99bc5a28e68e5387fd"] {
+            let f = File::open(format!("test_data/raw/game/{game_id}.json")).unwrap();
+            let game: Game = serde_json::from_reader(f).unwrap();
+
+            let mut batter = None;
+            let mut score = (0, 0);
+            for (event, parsed) in game.event_log.iter().zip(process_game(&game, game_id)) {
+                let next_score = (event.away_score, event.home_score);
+                assert_eq!(parsed.is_scoring_play(), next_score != score, "{parsed:?}");
+                score = next_score;
+
+                match &parsed {
+                    ParsedEventMessage::NowBatting { batter: next, .. } => batter = Some(*next),
+                    // A runner caught stealing can end the inning mid plate appearance
+                    ParsedEventMessage::InningEnd { .. } => batter = None,
+                    // A contained batter is replaced mid plate appearance
+                    ParsedEventMessage::WeatherWither {
+                        contained:
+                            ContainResult::SuccessfulContain {
+                                contained_player_name,
+                                replacement_player_name,
+                            },
+                        ..
+                    } if batter == Some(*contained_player_name) => {
+                        batter = Some(*replacement_player_name)
+                    }
+                    _ if parsed.is_plate_appearance_ending() => {
+                        assert!(batter.is_some(), "{parsed:?} outside a plate appearance");
+                        assert_eq!(parsed.batter().copied(), batter.take());
+                    }
+                    _ => assert!(parsed.batter().is_none() || batter.is_some()),
+                }
+            }
+        }
+
+        let home_run = ParsedEventMessage::HomeRun {
+            batter: "Batter",
+            fair_ball_type: FairBallType::FlyBall,
+            destination: FairBallDestination::LeftField,
+            scores: vec![],
+            grand_slam: false,
+            ejection: None,
+        };
+        assert!(home_run.is_scoring_play());
+        assert!(home_run.is_plate_appearance_ending());
+    }
+
+    #[test]
+    fn item_attribute_bonuses() {
+        let item = Item {
+            item_emoji: "🧤",
+            item: ItemName::Gloves,
+            affixes: ItemAffixes::PrefixSuffix(vec![ItemPrefix::Consistent], vec![ItemSuffix::Cat]),
+        };
+        assert_eq!(
+            item.attribute_bonuses(),
+            vec![Attribute::Contact, Attribute::Agility]
+        );
+
+        let rare = Item {
+            affixes: ItemAffixes::RareName("Gloves of the Gods"),
+            ..item.clone()
+        };
+        assert!(rare.attribute_bonuses().is_empty());
+
+        let plain = Item {
+            affixes: ItemAffixes::None,
+            ..item
+        };
+        assert!(plain.attribute_bonuses().is_empty());
+    }
+
+    #[test]
+    fn prize_accessors() {
+        let tokens: Prize<&str> = Prize::Tokens(25);
+        assert_eq!(tokens.total_tokens(), 25);
+        assert!(tokens.items().is_empty());
+        assert!(!tokens.is_empty());
+        assert!(Prize::<&str>::Tokens(0).is_empty());
+
+        let prize = ItemPrize {
+            item: Item {
+                item_emoji: "🧤",
+                item: ItemName::Gloves,
+                affixes: ItemAffixes::None,
+            },
+            equip: ItemEquip::Discarded,
+        };
+        let items = Prize::Items(vec![prize.clone()]);
+        assert_eq!(items.total_tokens(), 0);
+        assert_eq!(items.items(), [prize]);
+        assert!(!items.is_empty());
+        assert!(Prize::<&str>::Items(vec![]).is_empty());
+    }
+
+    #[test]
+    fn delivery_accessors() {
+        let item = |item: ItemName| Item {
+            item_emoji: "🧤",
+            item,
+            affixes: ItemAffixes::None,
+        };
+        let team = EmojiTeam {
+            emoji: "🧹",
+            name: "Boston Street Sweepers",
+        };
+
+        let to_player = Delivery::Successful {
+            team,
+            player: Some("Ivan Ivanov"),
+            item: item(ItemName::Gloves),
+            equipped: true,
+            discarded: Some(item(ItemName::Cap)),
+        };
+        assert_eq!(to_player.item(), &item(ItemName::Gloves));
+        assert_eq!(to_player.discarded(), Some(&item(ItemName::Cap)));
+        assert_eq!(to_player.recipient(), Some(&"Ivan Ivanov"));
+
+        let to_team = Delivery::Successful {
+            team,
+            player: None,
+            item: item(ItemName::Gloves),
+            equipped: false,
+            discarded: None,
+        };
+        assert_eq!(to_team.item(), &item(ItemName::Gloves));
+        assert_eq!(to_team.discarded(), None);
+        assert_eq!(to_team.recipient(), None);
+
+        let no_space = Delivery::NoSpace {
+            item: item(ItemName::Gloves),
+        };
+        assert_eq!(no_space.item(), &item(ItemName::Gloves));
+        assert_eq!(no_space.discarded(), None);
+        assert_eq!(no_space.recipient(), None);
+    }
+
+    #[test]
+    fn placed_player_from_str() {
+        assert_eq!(
+            PlacedPlayer::parse("1B Geo Kerr"),
+            Some(PlacedPlayer {
+                name: "Geo Kerr",
+                place: Place::FirstBaseman
+            })
+        );
+        assert_eq!(
+            "CF Kaj Statter Jr.".parse::<PlacedPlayer<String>>(),
+            Ok(PlacedPlayer {
+                name: "Kaj Statter Jr.".to_string(),
+                place: Place::CenterField
+            })
+        );
+        assert_eq!(
+            PlacedPlayer::parse("SP1 Bob E. Qurios"),
+            Some(PlacedPlayer {
+                name: "Bob E. Qurios",
+                place: Place::StartingPitcher(Some(1))
+            })
+        );
+        assert_eq!(
+            PlacedPlayer::parse("RP2 Geo Kerr"),
+            Some(PlacedPlayer {
+                name: "Geo Kerr",
+                place: Place::ReliefPitcher(Some(2))
+            })
+        );
+
+        assert_eq!(PlacedPlayer::parse("Geo Kerr"), None);
+        assert_eq!(PlacedPlayer::parse("1B Kaj Statter Jr"), None);
+        assert!("1B".parse::<PlacedPlayer<String>>().is_err());
+    }
+
+    #[test]
+    fn pitch_count() -> Result<(), Box<dyn std::error::Error>> {
+        let game: Game = serde_json::from_reader(File::open("test_data/s2_d240_game.json")?)?;
+        let parsed = process_game(&game, "s2_d240_game");
+        let counts = |plate_appearance: std::ops::Range<usize>| {
+            parsed[plate_appearance]
+                .iter()
+                .map(ParsedEventMessage::pitch_count)
+                .collect::<Vec<_>>()
+        };
+
+        // Ana Clara Arnaud walks
+        assert_eq!(
+            counts(7..12),
+            vec![Some((1, 0)), Some((1, 1)), Some((2, 1)), Some((3, 1)), None]
+        );
+        assert!(matches!(parsed[11], ParsedEventMessage::Walk { .. }));
+
+        // Delores Ashraf strikes out looking
+        assert_eq!(
+            counts(18..24),
+            vec![
+                Some((1, 0)),
+                Some((1, 1)),
+                Some((1, 2)),
+                Some((2, 2)),
+                Some((3, 2)),
+                None
+            ]
+        );
+        assert!(matches!(parsed[23], ParsedEventMessage::StrikeOut { .. }));
+
+        assert_eq!(parsed[0].pitch_count(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn base_running_events() -> Result<(), Box<dyn std::error::Error>> {
+        let game: Game = serde_json::from_reader(File::open("test_data/s2_d240_game.json")?)?;
+        let parsed = process_game(&game, "s2_d240_game");
+
+        // Each runner and the base they're on
+        let mut bases: Vec<(&str, Base)> = Vec::new();
+        for (event, raw_event) in parsed.iter().zip(&game.event_log) {
+            if matches!(
+                event,
+                ParsedEventMessage::InningStart { .. } | ParsedEventMessage::InningEnd { .. }
+            ) {
+                bases.clear();
+            }
+
+            for base_running_event in event.base_running_events() {
+                match base_running_event {
+                    BaseRunningEvent::Advance(RunnerAdvance { runner, base }) => {
+                        bases.retain(|(r, _)| r != runner);
+                        bases.push((runner, base));
+                    }
+                    BaseRunningEvent::Steal(BaseSteal {
+                        runner,
+                        base,
+                        caught,
+                    }) => {
+                        bases.retain(|(r, _)| r != runner);
+                        if !caught && base != Base::Home {
+                            bases.push((runner, base));
+                        }
+                    }
+                    BaseRunningEvent::Out(RunnerOut { runner, .. })
+                    | BaseRunningEvent::Score(runner) => bases.retain(|(r, _)| r != runner),
+                }
+            }
+
+            // The play that makes the third out leaves runners stranded, but the API clears the bases
+            if raw_event.outs.is_none() {
+                continue;
+            }
+            let occupied = |base| bases.iter().any(|(_, b)| *b == base);
+            assert_eq!(
+                (
+                    occupied(Base::First),
+                    occupied(Base::Second),
+                    occupied(Base::Third)
+                ),
+                (raw_event.on_1b, raw_event.on_2b, raw_event.on_3b),
+                "{}",
+                raw_event.message
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn fielding_attempt_accessors() {
+        let out = FieldingAttempt::Out {
+            out: RunnerOut {
+                runner: "Geo Kerr",
+                base: BaseNameVariant::basic_name(Base::Second),
+            },
+        };
+        assert_eq!(out.fielder(), None);
+        assert_eq!(out.player(), &"Geo Kerr");
+        assert!(!out.is_error());
+
+        let error = FieldingAttempt::Error {
+            fielder: "Kaj Statter Jr.",
+            error: FieldingErrorType::Throwing,
+        };
+        assert_eq!(error.fielder(), Some(&"Kaj Statter Jr."));
+        assert_eq!(error.player(), &"Kaj Statter Jr.");
+        assert!(error.is_error());
+    }
+
+    #[test]
+    fn emoji_team_matches() {
+        let team = EmojiTeam {
+            emoji: "🦜",
+            name: "Cabo Verde Parrots",
+        };
+        let unspaced = EmojiTeam {
+            emoji: "🦜".to_string(),
+            name: "CaboVerdeParrots".to_string(),
+        };
+
+        assert!(team.matches(&team));
+        assert!(team.matches(&unspaced));
+        assert!(unspaced.matches(&team));
+        assert!(!team.matches(&EmojiTeam {
+            emoji: "🐦",
+            name: "Cabo Verde Parrots",
+        }));
+        assert!(!team.matches(&EmojiTeam {
+            emoji: "🦜",
+            name: "Cabo Verde Parakeets",
+        }));
+    }
+
+    #[test]
+    fn celestial_energy_tier_round_trip() {
+        for tier in CelestialEnergyTier::iter() {
+            let outcome: FallingStarOutcome<&str> = tier.into();
+            assert_eq!(Option::<CelestialEnergyTier>::from(outcome), Some(tier));
+        }
+
+        let outcomes = [
+            FallingStarOutcome::<&str>::Injury,
+            FallingStarOutcome::Retired(Some("Ivan Ivanov")),
+            FallingStarOutcome::InfusionI,
+            FallingStarOutcome::InfusionII,
+            FallingStarOutcome::InfusionIII,
+            FallingStarOutcome::DeflectedHarmlessly,
+        ];
+        for outcome in outcomes {
+            match Option::<CelestialEnergyTier>::from(outcome) {
+                Some(tier) => assert_eq!(FallingStarOutcome::from(tier), outcome),
+                None => assert_eq!(outcome.infusion_tier(), None),
+            }
+        }
+
+        // "Infused with a glimmer" is the first tier, even though it's listed second
+        assert_eq!(
+            Option::<CelestialEnergyTier>::from(FallingStarOutcome::<&str>::InfusionI),
+            Some(CelestialEnergyTier::Infused)
+        );
+    }
 }
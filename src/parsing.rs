@@ -1,8 +1,16 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
 use crate::{
+    enums::Day,
     game::Event,
     nom_parsing::{parse_event, ParsingContext},
-    parsed_event::ParsedEventMessage,
-    Game,
+    parsed_event::{Cheer, Ejection, EjectionReason, EmojiTeam, ParsedEventMessage, ViolationType},
+    player_feed::{ParsedPlayerFeedEventText, PlayerFeed},
+    team_feed::{ParsedTeamFeedEventText, TeamFeed},
+    time::Breakpoints,
+    Game, NotRecognized,
 };
 
 /// Convenience method to call process_event for every event in a game
@@ -18,6 +26,25 @@ pub fn process_game<'output, 'parse: 'output>(
     result
 }
 
+/// Convenience method to call process_game for every (game id, game) pair
+pub fn process_games(games: &[(String, Game)]) -> Vec<Vec<ParsedEventMessage<&str>>> {
+    games
+        .iter()
+        .map(|(game_id, game)| process_game(game, game_id))
+        .collect()
+}
+
+/// Like [`process_games`], but parses games in parallel. Events within a game are still parsed in order.
+#[cfg(feature = "rayon")]
+pub fn process_games_par(games: &[(String, Game)]) -> Vec<Vec<ParsedEventMessage<&str>>> {
+    use rayon::prelude::*;
+
+    games
+        .par_iter()
+        .map(|(game_id, game)| process_game(game, game_id))
+        .collect()
+}
+
 /// Processes an event into a ParsedEventMessage. Zero-copy parsing, the strings in the returned ParsedEventMessage are references to the strings in event and game.
 pub fn process_event<'output, 'parse: 'output>(
     event: &'output Event,
@@ -29,11 +56,393 @@ pub fn process_event<'output, 'parse: 'output>(
     parsed_event_message
 }
 
+/// Parses a game's events one at a time as they arrive, e.g. from a live game feed.
+///
+/// Only the game's header (teams, season and day) is read from `game`, so its event log may be empty.
+/// Everything else a parse needs, including the inning and which team is pitching, comes from the
+/// event itself. This means events can be pushed in any order: a late-arriving event is parsed
+/// exactly as it would have been in order, because the breakpoints that depend on position use the
+/// event's own `index` rather than the order events were pushed in.
+///
+/// Pushing every event of a finished game gives the same result as [`process_game`].
+#[derive(Clone, Debug)]
+pub struct GameParser<'parse> {
+    context: ParsingContext<'parse>,
+}
+
+impl<'parse> GameParser<'parse> {
+    pub fn new(game: &'parse Game, game_id: &'parse str) -> Self {
+        let mut context = ParsingContext::new(game_id, game, None);
+        // Pushed events aren't necessarily in the game's event log
+        context.event_log = &[];
+        Self { context }
+    }
+
+    /// Parse the next event of the game.
+    pub fn push<'output>(&mut self, event: &'output Event) -> ParsedEventMessage<&'output str> {
+        self.context.event_index = event.index;
+        parse_event(event, &self.context)
+    }
+}
+
+/// Keeps a game's parsed events between polls of a live game, so only new or changed events are parsed again.
+///
+/// Events are matched up by their position in the event log. An event is reparsed when its message differs
+/// from the last time it was seen, since mmolb occasionally rewrites earlier events. If the game's teams,
+/// season or day change, everything is reparsed.
+///
+/// The result of [`ParsedGameCache::update`] is always the same as calling [`process_game`] on that game.
+#[derive(Clone, Debug, Default)]
+pub struct ParsedGameCache {
+    header: Option<CachedGameHeader>,
+    messages: Vec<String>,
+    parsed: Vec<ParsedEventMessage<String>>,
+}
+
+/// The parts of a game other than its event log that parsing depends on.
+#[derive(Clone, Debug, PartialEq)]
+struct CachedGameHeader {
+    season: u32,
+    day: Option<Day>,
+    away_team: EmojiTeam<String>,
+    home_team: EmojiTeam<String>,
+}
+
+impl CachedGameHeader {
+    fn new(game: &Game) -> Self {
+        Self {
+            season: game.season,
+            day: game.day.as_ref().ok().copied(),
+            away_team: EmojiTeam {
+                emoji: game.away_team_emoji.clone(),
+                name: game.away_team_name.clone(),
+            },
+            home_team: EmojiTeam {
+                emoji: game.home_team_emoji.clone(),
+                name: game.home_team_name.clone(),
+            },
+        }
+    }
+}
+
+impl ParsedGameCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse any events that are new or have changed since the last update, and return every parsed event in the game.
+    pub fn update(&mut self, game: &Game, game_id: &str) -> &[ParsedEventMessage<String>] {
+        let header = CachedGameHeader::new(game);
+        if self.header.as_ref() != Some(&header) {
+            self.messages.clear();
+            self.parsed.clear();
+            self.header = Some(header);
+        }
+
+        // The event log shrinking means it was rewritten, the remaining events are checked below.
+        self.messages.truncate(game.event_log.len());
+        self.parsed.truncate(game.event_log.len());
+
+        let mut parser = GameParser::new(game, game_id);
+        for (i, event) in game.event_log.iter().enumerate() {
+            match self.messages.get(i) {
+                Some(message) if *message == event.message => {}
+                Some(_) => {
+                    self.messages[i] = event.message.clone();
+                    self.parsed[i] = parser.push(event).to_owned();
+                }
+                None => {
+                    self.messages.push(event.message.clone());
+                    self.parsed.push(parser.push(event).to_owned());
+                }
+            }
+        }
+
+        &self.parsed
+    }
+
+    /// The parsed events as of the last update.
+    pub fn parsed(&self) -> &[ParsedEventMessage<String>] {
+        &self.parsed
+    }
+}
+
+/// An event whose unparsed message didn't match the original, found by [`process_game_checked`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RoundTripMismatch {
+    /// Position of the event in the game's event log.
+    pub event_index: usize,
+    /// Byte offset of the first difference between the two messages.
+    pub first_difference: usize,
+    pub original: String,
+    pub unparsed: String,
+}
+
+impl Display for RoundTripMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Event {} round trip failure at byte {} expected:\n'{}'\nGot:\n'{}'",
+            self.event_index, self.first_difference, self.original, self.unparsed
+        )
+    }
+}
+
+/// Like [`process_game`], but unparses every event and checks it against the original message.
+/// Useful for catching parser regressions across many games.
+pub fn process_game_checked(game: &Game, game_id: &str) -> Vec<RoundTripMismatch> {
+    game.event_log
+        .iter()
+        .enumerate()
+        .filter_map(|(event_index, event)| {
+            let unparsed = process_event(event, game, game_id).unparse(game, event.index);
+            (unparsed != event.message).then(|| RoundTripMismatch {
+                event_index,
+                first_difference: event
+                    .message
+                    .char_indices()
+                    .zip(unparsed.chars())
+                    .find(|((_, a), b)| a != b)
+                    .map(|((i, _), _)| i)
+                    .unwrap_or(event.message.len().min(unparsed.len())),
+                original: event.message.clone(),
+                unparsed,
+            })
+        })
+        .collect()
+}
+
+/// Convenience method to call parse_player_feed_event for every event in a player's feed
+pub fn process_player_feed(feed: &PlayerFeed) -> Vec<ParsedPlayerFeedEventText<&str>> {
+    feed.parsed().collect()
+}
+
+/// Convenience method to call parse_team_feed_event for every event in a team's feed
+pub fn process_team_feed(feed: &TeamFeed) -> Vec<ParsedTeamFeedEventText<&str>> {
+    feed.parsed().collect()
+}
+
+/// JSON schemas for the parser's output, named by type, for generating types in other languages.
+/// The `schema` binary writes these to files.
+#[cfg(feature = "schemars")]
+pub fn json_schemas() -> [(&'static str, schemars::Schema); 3] {
+    [
+        (
+            "ParsedEventMessage",
+            schemars::schema_for!(ParsedEventMessage<String>),
+        ),
+        (
+            "ParsedPlayerFeedEventText",
+            schemars::schema_for!(ParsedPlayerFeedEventText<String>),
+        ),
+        (
+            "ParsedTeamFeedEventText",
+            schemars::schema_for!(ParsedTeamFeedEventText<String>),
+        ),
+    ]
+}
+
+/// The era an event happened in, named after the latest [`Breakpoints`] it is at or after.
+///
+/// This is informational. Unparsing works out the same breakpoints from its
+/// [`UnparsingContext`](crate::UnparsingContext) and the event index, so it doesn't take an `Era`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Era {
+    /// `None` before the first breakpoint.
+    pub latest_breakpoint: Option<Breakpoints>,
+}
+
+impl Era {
+    pub fn new(season: u32, day: Option<Day>, event_index: Option<u16>) -> Self {
+        Self {
+            latest_breakpoint: Breakpoints::active_at(season, day, event_index).last(),
+        }
+    }
+
+    /// Whether the era is at or after the breakpoint.
+    pub fn after(&self, breakpoint: Breakpoints) -> bool {
+        self.latest_breakpoint >= Some(breakpoint)
+    }
+
+    /// Whether the era is before the breakpoint.
+    pub fn before(&self, breakpoint: Breakpoints) -> bool {
+        !self.after(breakpoint)
+    }
+}
+
+/// Like [`process_game`], but also returns the [`Era`] each event was parsed in.
+pub fn parse_game_with_era<'output>(
+    game: &'output Game,
+    game_id: &'output str,
+) -> Vec<(ParsedEventMessage<&'output str>, Era)> {
+    let day = game.day.as_ref().ok().copied();
+
+    game.event_log
+        .iter()
+        .map(|event| {
+            (
+                process_event(event, game, game_id),
+                Era::new(game.season, day, event.index),
+            )
+        })
+        .collect()
+}
+
+/// Everything in a game that mmolb_parsing didn't recognize. Useful for finding out what's new after an mmolb update.
+///
+/// Each list is deduplicated, and in the order the values were first encountered.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Unknowns {
+    pub cheers: Vec<String>,
+    pub ejection_reasons: Vec<String>,
+    pub violation_types: Vec<String>,
+    /// Values of enum fields on the game and its events that failed to deserialize.
+    pub not_recognized: Vec<NotRecognized>,
+}
+
+impl Unknowns {
+    pub fn is_empty(&self) -> bool {
+        self.cheers.is_empty()
+            && self.ejection_reasons.is_empty()
+            && self.violation_types.is_empty()
+            && self.not_recognized.is_empty()
+    }
+
+    fn add_not_recognized<T>(&mut self, value: &Result<T, NotRecognized>) {
+        if let Err(not_recognized) = value {
+            push_unique(&mut self.not_recognized, not_recognized.clone());
+        }
+    }
+
+    fn add_cheer(&mut self, cheer: &Option<Cheer>) {
+        if let Some(Cheer::Unknown(cheer)) = cheer {
+            push_unique(&mut self.cheers, cheer.clone());
+        }
+    }
+
+    fn add_ejection<S>(&mut self, ejection: &Option<Ejection<S>>) {
+        if let Some(Ejection::Ejection {
+            violation_type,
+            reason,
+            ..
+        }) = ejection
+        {
+            if let ViolationType::Unknown(violation_type) = violation_type {
+                push_unique(&mut self.violation_types, violation_type.clone());
+            }
+            if let EjectionReason::Unknown(reason) = reason {
+                push_unique(&mut self.ejection_reasons, reason.clone());
+            }
+        }
+    }
+}
+
+fn push_unique<T: PartialEq>(list: &mut Vec<T>, value: T) {
+    if !list.contains(&value) {
+        list.push(value);
+    }
+}
+
+/// Parses every event in a game, collecting every unrecognized cheer, ejection and enum value.
+pub fn collect_all_unknowns(game: &Game) -> Unknowns {
+    let mut unknowns = Unknowns::default();
+
+    unknowns.add_not_recognized(&game.day);
+    unknowns.add_not_recognized(&game.season_status);
+    unknowns.add_not_recognized(&game.league_scale);
+    for slot in game.away_lineup.iter().chain(game.home_lineup.iter()) {
+        unknowns.add_not_recognized(slot);
+    }
+    for stat in game
+        .stats
+        .values()
+        .flat_map(|players| players.values())
+        .flat_map(|stats| stats.keys())
+    {
+        unknowns.add_not_recognized(stat);
+    }
+
+    for event in &game.event_log {
+        unknowns.add_not_recognized(&event.event);
+        if let Some(pitch) = &event.pitch {
+            unknowns.add_not_recognized(&pitch.pitch_type);
+        }
+    }
+
+    // The game id is only used to look up overrides for known bugs, which never contain unknowns.
+    for parsed in process_game(game, "") {
+        match &parsed {
+            ParsedEventMessage::Ball {
+                cheer, ejection, ..
+            }
+            | ParsedEventMessage::Strike {
+                cheer, ejection, ..
+            }
+            | ParsedEventMessage::Walk {
+                cheer, ejection, ..
+            }
+            | ParsedEventMessage::HitByPitch {
+                cheer, ejection, ..
+            }
+            | ParsedEventMessage::StrikeOut {
+                cheer, ejection, ..
+            } => {
+                unknowns.add_cheer(cheer);
+                unknowns.add_ejection(ejection);
+            }
+            ParsedEventMessage::Foul { cheer, .. } | ParsedEventMessage::FairBall { cheer, .. } => {
+                unknowns.add_cheer(cheer)
+            }
+            ParsedEventMessage::BatterToBase { ejection, .. }
+            | ParsedEventMessage::HomeRun { ejection, .. }
+            | ParsedEventMessage::CaughtOut { ejection, .. }
+            | ParsedEventMessage::GroundedOut { ejection, .. }
+            | ParsedEventMessage::ForceOut { ejection, .. }
+            | ParsedEventMessage::ReachOnFieldersChoice { ejection, .. }
+            | ParsedEventMessage::DoublePlayGrounded { ejection, .. }
+            | ParsedEventMessage::DoublePlayCaught { ejection, .. }
+            | ParsedEventMessage::ReachOnFieldingError { ejection, .. } => {
+                unknowns.add_ejection(ejection)
+            }
+            _ => (),
+        }
+    }
+
+    unknowns
+}
+
 #[cfg(test)]
 mod test {
-    use std::{error::Error, fs::File, io::Read};
+    use std::{error::Error, fs::File};
 
-    use crate::{process_game, utils::no_tracing_errs, Game, ParsedEventMessage};
+    use strum::IntoEnumIterator;
+
+    use crate::{
+        parsed_event::GameEventParseError,
+        parsing::{collect_all_unknowns, parse_game_with_era, process_game_checked},
+        player_feed::{ParsedPlayerFeedEventText, PlayerFeed},
+        process_game, process_player_feed, process_team_feed,
+        team_feed::{ParsedTeamFeedEventText, TeamFeed},
+        utils::{no_tracing_errs, raw_test_data},
+        Breakpoints, Game, GameParser, ParsedEventMessage, ParsedGameCache,
+    };
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schemas() -> Result<(), Box<dyn Error>> {
+        use crate::parsed_event::ParsedEventMessageDiscriminants;
+
+        let [(_, game), (_, player_feed), (_, team_feed)] = crate::parsing::json_schemas();
+
+        // Every event type should be in the schema
+        let game = serde_json::to_string(&game)?;
+        for event_type in ParsedEventMessageDiscriminants::iter() {
+            assert!(game.contains(&format!("\"{event_type}\"")), "{event_type}");
+        }
+        assert!(serde_json::to_string(&player_feed)?.contains("PlayerGrow"));
+        assert!(serde_json::to_string(&team_feed)?.contains("GameResult"));
+        Ok(())
+    }
 
     #[test]
     fn livingston() -> Result<(), Box<dyn Error>> {
@@ -42,9 +451,7 @@ mod test {
         let f = File::open("test_data/livingston_game.json").unwrap();
         let game: Game = serde_json::from_reader(f).unwrap();
 
-        let mut buf = String::new();
-        let mut f = File::open("test_data/livingston_game_result.json").unwrap();
-        f.read_to_string(&mut buf).unwrap();
+        let buf = std::fs::read_to_string("test_data/livingston_game_result.json").unwrap();
 
         let actual_events: Vec<ParsedEventMessage<String>> = buf
             .lines()
@@ -71,4 +478,206 @@ mod test {
         drop(no_tracing_errors);
         Ok(())
     }
+
+    #[test]
+    fn livingston_unknowns() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let unknowns = collect_all_unknowns(&game);
+        assert!(unknowns.is_empty(), "{unknowns:?}");
+    }
+
+    #[test]
+    fn livingston_era() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+
+        let events = parse_game_with_era(&game, "68474b55452606ed6b72dbe8");
+        assert_eq!(events.len(), game.event_log.len());
+
+        // The livingston game is from season 0, before every breakpoint
+        let (_, era) = &events[0];
+        assert_eq!(era.latest_breakpoint, None);
+        assert!(era.before(Breakpoints::S2D169));
+    }
+
+    #[test]
+    fn era_matches_breakpoints() {
+        for (game_id, game) in raw_test_data::<Game>("game") {
+            let day = game.day.as_ref().ok().copied();
+
+            for (event, (_, era)) in game
+                .event_log
+                .iter()
+                .zip(parse_game_with_era(&game, &game_id))
+            {
+                for breakpoint in Breakpoints::iter() {
+                    assert_eq!(
+                        era.after(breakpoint),
+                        breakpoint.after(game.season, day, event.index),
+                        "{game_id} event {:?} {breakpoint:?}",
+                        event.index
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn feeds() -> Result<(), Box<dyn Error>> {
+        let f = File::open("test_data/raw/player_feed/6805db0cac48194de3cd4003.json")?;
+        let player_feed: PlayerFeed = serde_json::from_reader(f)?;
+        let f = File::open("test_data/raw/team_feed/685e20f5d7b446d87c10153d.json")?;
+        let team_feed: TeamFeed = serde_json::from_reader(f)?;
+
+        let no_tracing_errors = no_tracing_errs();
+
+        let player_events = process_player_feed(&player_feed);
+        assert_eq!(player_events.len(), player_feed.feed.len());
+        assert!(!player_events
+            .iter()
+            .any(|event| matches!(event, ParsedPlayerFeedEventText::ParseError { .. })));
+
+        let team_events = process_team_feed(&team_feed);
+        assert_eq!(team_events.len(), team_feed.feed.len());
+        assert!(!team_events
+            .iter()
+            .any(|event| matches!(event, ParsedTeamFeedEventText::ParseError { .. })));
+
+        drop(no_tracing_errors);
+        Ok(())
+    }
+
+    #[test]
+    fn checked_round_trip() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let mut game: Game = serde_json::from_reader(f).unwrap();
+
+        assert!(process_game_checked(&game, "68474b55452606ed6b72dbe8").is_empty());
+
+        // Before s2d169 pitch messages had a leading space, which the parser doesn't require
+        let event_index = 7;
+        game.event_log[event_index].message = "Ball. 1-0.".to_string();
+
+        let mismatches = process_game_checked(&game, "68474b55452606ed6b72dbe8");
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].event_index, event_index);
+        assert_eq!(mismatches[0].first_difference, 0);
+        assert_eq!(mismatches[0].unparsed, " Ball. 1-0.");
+    }
+
+    #[test]
+    fn parse_error_offset() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let mut game: Game = serde_json::from_reader(f).unwrap();
+
+        game.event_log[6].message = "Now batting: Graziela Flowers (1st XX of game)".to_string();
+
+        let events = process_game(&game, "68474b55452606ed6b72dbe8");
+        let ParsedEventMessage::ParseError {
+            error:
+                GameEventParseError::FailedParsingMessage {
+                    offset, context, ..
+                },
+            ..
+        } = &events[6]
+        else {
+            panic!("expected a parse error, got {:?}", events[6]);
+        };
+        // Both branches of the parser get past "Now batting: " before failing
+        assert_eq!(*offset, Some("Now batting: ".len()));
+        assert_eq!(context, &["Now Batting"]);
+    }
+
+    #[test]
+    fn game_parser() {
+        for (game_id, path) in [
+            ("68474b55452606ed6b72dbe8", "test_data/livingston_game.json"),
+            // Goes to 15 innings
+            (
+                "68f4a8d04d9c8c3ffed1a951",
+                "test_data/raw/game/68f4a8d04d9c8c3ffed1a951.json",
+            ),
+        ] {
+            let game: Game = serde_json::from_reader(File::open(path).unwrap()).unwrap();
+
+            let mut parser = GameParser::new(&game, game_id);
+            let pushed: Vec<_> = game
+                .event_log
+                .iter()
+                .map(|event| parser.push(event))
+                .collect();
+
+            assert_eq!(pushed, process_game(&game, game_id));
+        }
+    }
+
+    #[test]
+    fn parsed_game_cache_incremental() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let game: Game = serde_json::from_reader(f).unwrap();
+        let expected: Vec<_> = process_game(&game, "")
+            .iter()
+            .map(ParsedEventMessage::to_owned)
+            .collect();
+
+        let mut cache = ParsedGameCache::new();
+        let mut partial = game.clone();
+        for len in [0, 1, 10, 11, 100, game.event_log.len()] {
+            partial.event_log = game.event_log[..len].to_vec();
+            assert_eq!(cache.update(&partial, ""), &expected[..len]);
+        }
+        assert_eq!(cache.parsed(), expected);
+    }
+
+    #[test]
+    fn parsed_game_cache_rewrite() {
+        let f = File::open("test_data/livingston_game.json").unwrap();
+        let mut game: Game = serde_json::from_reader(f).unwrap();
+
+        let mut cache = ParsedGameCache::new();
+        let original = cache.update(&game, "").to_vec();
+
+        // Swap two events' messages, which should make both parse differently.
+        let (a, b) = (5, 6);
+        let (message_a, message_b) = (
+            game.event_log[a].message.clone(),
+            game.event_log[b].message.clone(),
+        );
+        assert_ne!(message_a, message_b);
+        game.event_log[a].message = message_b;
+        game.event_log[b].message = message_a;
+
+        let expected: Vec<_> = process_game(&game, "")
+            .iter()
+            .map(ParsedEventMessage::to_owned)
+            .collect();
+        assert_ne!(original, expected);
+        assert_eq!(cache.update(&game, ""), expected);
+
+        // Events removed from the end are dropped
+        game.event_log.truncate(20);
+        assert_eq!(cache.update(&game, "").len(), 20);
+
+        // A different home team invalidates everything
+        game.home_team_name = "Somewhere Else".to_string();
+        let expected: Vec<_> = process_game(&game, "")
+            .iter()
+            .map(ParsedEventMessage::to_owned)
+            .collect();
+        assert_ne!(cache.parsed(), expected);
+        assert_eq!(cache.update(&game, ""), expected);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn process_games_par() {
+        let games = crate::utils::raw_test_data::<Game>("game");
+
+        assert_eq!(
+            crate::process_games_par(&games),
+            crate::process_games(&games)
+        );
+    }
 }
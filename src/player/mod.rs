@@ -6,8 +6,9 @@ use crate::enums::{AttributeCategory, PitchCategory, PitchType};
 use crate::utils::{extra_fields_deserialize, MaybeRecognizedHelper, SometimesMissingHelper};
 use crate::{
     enums::{
-        Attribute, Day, EquipmentEffectType, EquipmentRarity, EquipmentSlot, GameStat, Handedness,
-        ItemName, ItemPrefix, ItemSuffix, Position, PositionType, SeasonStatus, SpecialItemType,
+        Attribute, Day, EquipmentEffectType, EquipmentRarity, EquipmentSlot, FeedEventType,
+        GameStat, Handedness, ItemName, ItemPrefix, ItemSuffix, Position, PositionType,
+        SeasonStatus, SpecialItemType,
     },
     feed_event::FeedEvent,
     utils::{
@@ -67,6 +68,7 @@ pub struct Player {
     #[serde_as(as = "EmptyArrayOr<HashMap<_, HashMap<MaybeRecognizedHelper<_>, _>>>")]
     pub season_stats:
         EmptyArrayOr<HashMap<String, HashMap<MaybeRecognizedResult<SeasonStatus>, String>>>,
+    /// TeamID -> Stat -> Value
     #[serde_as(as = "HashMap<_, HashMap<MaybeRecognizedHelper<_>, _>>")]
     pub stats: HashMap<String, HashMap<MaybeRecognizedResult<GameStat>, i32>>,
 
@@ -145,6 +147,44 @@ pub struct Player {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl Player {
+    /// The player's first and last name, as they appear in game events.
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+
+    /// The player's name preceded by an emoji (usually their team's), as they appear in feed events.
+    pub fn display_name(&self, emoji: &str) -> String {
+        format!("{emoji} {} {}", self.first_name, self.last_name)
+    }
+
+    /// The player's feed, or nothing for players from before the feed was added.
+    pub fn feed_events(&self) -> &[FeedEvent] {
+        self.feed.as_deref().unwrap_or_default()
+    }
+
+    /// The player's feed events of one type.
+    pub fn feed_events_of_type(&self, ty: FeedEventType) -> impl Iterator<Item = &FeedEvent> {
+        self.feed_events()
+            .iter()
+            .filter(move |event| event.event_type == Ok(ty))
+    }
+
+    /// One of the player's stats, from their time on the team with the given id.
+    pub fn stat(&self, team_id: &str, stat: GameStat) -> Option<i32> {
+        self.stats.get(team_id)?.get(&Ok(stat)).copied()
+    }
+
+    /// The player's recognized stats from their time on the team with the given id.
+    pub fn team_stats(&self, team_id: &str) -> impl Iterator<Item = (GameStat, i32)> + '_ {
+        self.stats
+            .get(team_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|(stat, value)| Some((*stat.as_ref().ok()?, *value)))
+    }
+}
+
 /// A player's equipment field can be described by `HashMap<Result<EquipmentSlot, NotRecognized>, Option<PlayerEquipment>>`
 ///
 /// This wrapper is accessed more like `HashMap<Result<EquipmentSlot, NotRecognized>, PlayerEquipment>`, and can be accessed through
@@ -186,6 +226,53 @@ impl PlayerEquipmentMap {
     {
         self._get_mut(index)
     }
+
+    /// The occupied slots and their equipment, in no particular order.
+    ///
+    /// ```
+    /// use mmolb_parsing::player::PlayerEquipmentMap;
+    /// use mmolb_parsing::enums::EquipmentSlot;
+    ///
+    /// let mut map: PlayerEquipmentMap = serde_json::from_value(serde_json::json!({
+    ///     "Head": {"Emoji": "🧢", "Name": "Cap"},
+    ///     "Body": null,
+    /// })).unwrap();
+    ///
+    /// let slots: Vec<_> = map.iter().map(|(slot, _)| slot.clone()).collect();
+    /// assert_eq!(slots, [Ok(EquipmentSlot::Head)]);
+    ///
+    /// for (_, equipment) in map.iter_mut() {
+    ///     equipment.emoji = "🎩".to_string();
+    /// }
+    /// assert_eq!(map.get(EquipmentSlot::Head).unwrap().emoji, "🎩");
+    /// ```
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&MaybeRecognizedResult<EquipmentSlot>, &PlayerEquipment)> {
+        self.inner
+            .iter()
+            .flat_map(|(slot, equipment)| equipment.as_ref().map(|e| (slot, e)))
+    }
+
+    /// The occupied slots and mutable references to their equipment, in no particular order.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&MaybeRecognizedResult<EquipmentSlot>, &mut PlayerEquipment)> {
+        self.inner
+            .iter_mut()
+            .flat_map(|(slot, equipment)| equipment.as_mut().map(|e| (slot, e)))
+    }
+
+    /// The flat attribute bonuses of all the equipped items, summed per attribute.
+    pub fn total_attribute_bonuses(&self) -> HashMap<Attribute, f64> {
+        let mut totals = HashMap::new();
+        for (_, equipment) in self.iter() {
+            for (attribute, value) in equipment.total_attribute_bonuses() {
+                *totals.entry(attribute).or_default() += value;
+            }
+        }
+        totals
+    }
 }
 
 impl From<PlayerEquipmentMap> for HashMap<MaybeRecognizedResult<EquipmentSlot>, PlayerEquipment> {
@@ -331,6 +418,30 @@ pub struct PlayerEquipment {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl PlayerEquipment {
+    /// The item's flat attribute bonuses, summed per attribute.
+    /// Effects with an unrecognized attribute or type are skipped.
+    pub fn total_attribute_bonuses(&self) -> HashMap<Attribute, f64> {
+        let mut totals = HashMap::new();
+        for effect in self.effects.iter().flatten() {
+            let Ok(effect) = effect else {
+                tracing::warn!("Skipping unrecognized effect on {}", self.emoji);
+                continue;
+            };
+            match (&effect.attribute, &effect.effect_type) {
+                (Ok(attribute), Ok(EquipmentEffectType::FlatBonus)) => {
+                    *totals.entry(*attribute).or_default() += effect.value
+                }
+                (Ok(_), Ok(EquipmentEffectType::Multiplier)) => (),
+                (attribute, effect_type) => tracing::warn!(
+                    "Skipping effect with attribute {attribute:?} and type {effect_type:?}"
+                ),
+            }
+        }
+        totals
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "PascalCase")]
@@ -595,10 +706,11 @@ pub struct BaseAttributes {
 #[cfg(test)]
 mod test {
     use crate::{
+        enums::{Attribute, EquipmentSlot, FeedEventType, GameStat},
         player::Player,
-        utils::{assert_round_trip, no_tracing_errs},
+        utils::{assert_round_trip, no_tracing_errs, AddedLater},
     };
-    use std::path::Path;
+    use std::{fs::File, path::Path};
 
     #[test]
     fn player_round_trip() -> Result<(), Box<dyn std::error::Error>> {
@@ -609,4 +721,86 @@ mod test {
         drop(no_tracing_errs);
         Ok(())
     }
+
+    #[test]
+    fn player_names() -> Result<(), Box<dyn std::error::Error>> {
+        let f = File::open("test_data/player.json")?;
+        let player: Player = serde_json::from_reader(f)?;
+        assert_eq!(player.full_name(), "Nora DiMaggio");
+        assert_eq!(player.display_name("🤖"), "🤖 Nora DiMaggio");
+
+        // Non-ascii characters come escaped in the API's json
+        let escaped = std::fs::read_to_string("test_data/player.json")?
+            .replace("\"DiMaggio\"", "\"\\u015een\"");
+        let player: Player = serde_json::from_str(&escaped)?;
+        assert_eq!(player.full_name(), "Nora Şen");
+        Ok(())
+    }
+
+    #[test]
+    fn feed_events() -> Result<(), Box<dyn std::error::Error>> {
+        let f = File::open("test_data/player.json")?;
+        let mut player: Player = serde_json::from_reader(f)?;
+
+        assert_eq!(player.feed_events().len(), 8);
+        let augments = player
+            .feed_events_of_type(FeedEventType::Augment)
+            .collect::<Vec<_>>();
+        assert_eq!(augments.len(), 2);
+        assert!(augments
+            .iter()
+            .all(|event| event.event_type == Ok(FeedEventType::Augment)));
+        assert_eq!(player.feed_events_of_type(FeedEventType::Game).count(), 6);
+        assert_eq!(
+            player.feed_events_of_type(FeedEventType::Release).count(),
+            0
+        );
+
+        player.feed = Err(AddedLater);
+        assert!(player.feed_events().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn stats() -> Result<(), Box<dyn std::error::Error>> {
+        let f = File::open("test_data/player.json")?;
+        let player: Player = serde_json::from_reader(f)?;
+
+        let team_id = "68065f7ef9b67839f528b7c6";
+        assert_eq!(player.stat(team_id, GameStat::Appearances), Some(8));
+        assert_eq!(player.stat(team_id, GameStat::BattersFaced), Some(230));
+        assert_eq!(
+            player.stat(team_id, GameStat::HomeRunChallengeHomeRuns),
+            None
+        );
+        assert_eq!(player.stat("not a team", GameStat::Appearances), None);
+
+        assert_eq!(
+            player.team_stats(team_id).count(),
+            player.stats[team_id].len()
+        );
+        assert!(player
+            .team_stats(team_id)
+            .any(|stat| stat == (GameStat::Assists, 5)));
+        Ok(())
+    }
+
+    #[test]
+    fn total_attribute_bonuses() -> Result<(), Box<dyn std::error::Error>> {
+        let f = File::open("test_data/player.json")?;
+        let player: Player = serde_json::from_reader(f)?;
+        let equipment = player.equipment.as_ref().unwrap();
+
+        let head = equipment.get(EquipmentSlot::Head).unwrap();
+        let head_bonuses = head.total_attribute_bonuses();
+        assert_eq!(head_bonuses.len(), 2);
+        assert!((head_bonuses[&Attribute::Luck] - 0.2).abs() < 1e-9);
+
+        let totals = equipment.total_attribute_bonuses();
+        assert!((totals[&Attribute::Luck] - 0.32).abs() < 1e-9);
+        assert!((totals[&Attribute::Guts] - 0.24).abs() < 1e-9);
+        assert!((totals[&Attribute::Arm] - 0.11).abs() < 1e-9);
+        assert_eq!(totals.len(), 6);
+        Ok(())
+    }
 }
@@ -2,18 +2,22 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use strum::{EnumDiscriminants, EnumIter};
 
 use crate::feed_event::PlayerGreaterAugment;
 pub use crate::nom_parsing::parse_player_feed_event::parse_player_feed_event;
-use crate::nom_parsing::shared::{FeedEventDoorPrize, FeedEventParty, Grow, PositionSwap};
+use crate::nom_parsing::shared::{
+    unparse_feed_event_contained, FeedEventDoorPrize, FeedEventParty, Grow, PositionSwap,
+};
 use crate::team_feed::PurifiedOutcome;
 use crate::{
     enums::{Attribute, FeedEventType, ModificationType},
     feed_event::{
-        EmojilessItem, FeedDelivery, FeedEvent, FeedEventParseError, FeedFallingStarOutcome,
+        self, EmojilessItem, FeedDelivery, FeedEvent, FeedEventParseError, FeedFallingStarOutcome,
     },
     time::{Breakpoints, Timestamp},
     utils::extra_fields_deserialize,
+    MentionsPlayer,
 };
 
 #[serde_as]
@@ -25,7 +29,26 @@ pub struct PlayerFeed {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+impl PlayerFeed {
+    /// Whether every event in the feed is at or after the event before it.
+    pub fn is_chronological(&self) -> bool {
+        feed_event::is_chronological(&self.feed)
+    }
+
+    /// Sorts the feed by timestamp. Events with the same timestamp keep their order.
+    pub fn sort_by_time(&mut self) {
+        feed_event::sort_by_time(&mut self.feed);
+    }
+
+    /// Lazily parses each event in the feed, in order.
+    pub fn parsed(&self) -> impl Iterator<Item = ParsedPlayerFeedEventText<&str>> {
+        self.feed.iter().map(parse_player_feed_event)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum_discriminants(derive(strum::Display, Hash, EnumIter))]
 pub enum ParsedPlayerFeedEventText<S> {
     ParseError {
         error: FeedEventParseError,
@@ -96,6 +119,7 @@ pub enum ParsedPlayerFeedEventText<S> {
         lost_modification: Option<ModificationType>,
         modification: ModificationType,
     },
+    /// Only seen on player feeds, in the preseason. Team feeds don't repeat it for their players.
     SeasonalDurabilityLoss {
         player_name: S,
         // None means that the Prolific boon resisted the durability loss
@@ -233,11 +257,7 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
                 format!("{party}")
             }
             ParsedPlayerFeedEventText::PlayerContained { contained_player_name, container_player_name } => {
-                // TODO Dedup with player feed
-                format!(
-                    "{contained_player_name} was contained by {container_player_name} during the \
-                    🥀 Wither.",
-                )
+                unparse_feed_event_contained(contained_player_name, container_player_name)
             }
             ParsedPlayerFeedEventText::PlayerPositionsSwapped { swap } => {
                 format!("{swap}")
@@ -249,16 +269,16 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
                 match greater_augment {
                     PlayerGreaterAugment::Headliners { attribute } => format!("{player_name} gained +75 {attribute}."),
                     PlayerGreaterAugment::StartSmall { attribute } => format!("{player_name} gained +50 {attribute}."),
-                    PlayerGreaterAugment::Plating => format!("{player_name} gained +10 to all Defense Attributes"),
-                    PlayerGreaterAugment::LuckyDelivery => format!("{player_name} gained +10 to all Defense Attributes"),
+                    PlayerGreaterAugment::Plating => format!("{player_name} gained +10 to all Defense Attributes."),
+                    PlayerGreaterAugment::LuckyDelivery => "TODO Insert the lucky delivery text here".to_string(),
                 }
             }
             ParsedPlayerFeedEventText::RetractedGreaterAugment { player_name, greater_augment } => {
                 match greater_augment {
                     PlayerGreaterAugment::Headliners { attribute } => format!("{player_name} lost 0.75 from {attribute}."),
                     PlayerGreaterAugment::StartSmall { attribute } => format!("{player_name} lost 0.5 from {attribute}."),
-                    PlayerGreaterAugment::Plating => format!("{player_name} lost 0.1 to all Defense Attributes"),
-                    PlayerGreaterAugment::LuckyDelivery => format!("{player_name} lost 0.1 to all Defense Attributes"),
+                    PlayerGreaterAugment::Plating => format!("{player_name} lost 0.1 from all Defense Attributes."),
+                    PlayerGreaterAugment::LuckyDelivery => "TODO Insert the lucky delivery text here".to_string(),
                 }
             }
             ParsedPlayerFeedEventText::RetroactiveGreaterAugment { player_name, greater_augment } => {
@@ -266,7 +286,7 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
                     PlayerGreaterAugment::Headliners { attribute } => format!("{player_name} gained +0.75 to {attribute}."),
                     PlayerGreaterAugment::StartSmall { attribute } => format!("{player_name} gained +0.5 to {attribute}."),
                     PlayerGreaterAugment::Plating => format!("{player_name} gained +0.1 to all Defense Attributes."),
-                    PlayerGreaterAugment::LuckyDelivery => format!("{player_name} gained +0.1 to all Defense Attributes."),
+                    PlayerGreaterAugment::LuckyDelivery => "TODO Insert the lucky delivery text here".to_string(),
                 }
             }
             ParsedPlayerFeedEventText::PlayerRelegated { player_name } => {
@@ -278,3 +298,260 @@ impl<S: Display> ParsedPlayerFeedEventText<S> {
         }
     }
 }
+
+impl<S> ParsedPlayerFeedEventText<S> {
+    /// Every player name in the event, in no particular order and possibly with repeats.
+    fn player_names(&self) -> Vec<&S> {
+        match self {
+            ParsedPlayerFeedEventText::ParseError { .. }
+            | ParsedPlayerFeedEventText::Released { .. } => Vec::new(),
+            ParsedPlayerFeedEventText::Delivery { delivery }
+            | ParsedPlayerFeedEventText::Shipment { delivery }
+            | ParsedPlayerFeedEventText::SpecialDelivery { delivery } => vec![&delivery.player],
+            ParsedPlayerFeedEventText::DoorPrize { prize } => prize.player_names().collect(),
+            ParsedPlayerFeedEventText::AttributeChanges { player_name, .. }
+            | ParsedPlayerFeedEventText::AttributeEquals { player_name, .. }
+            | ParsedPlayerFeedEventText::Enchantment { player_name, .. }
+            | ParsedPlayerFeedEventText::FallingStarOutcome { player_name, .. }
+            | ParsedPlayerFeedEventText::Modification { player_name, .. }
+            | ParsedPlayerFeedEventText::SeasonalDurabilityLoss { player_name, .. }
+            | ParsedPlayerFeedEventText::CorruptedByWither { player_name }
+            | ParsedPlayerFeedEventText::Purified { player_name, .. }
+            | ParsedPlayerFeedEventText::GreaterAugment { player_name, .. }
+            | ParsedPlayerFeedEventText::RetractedGreaterAugment { player_name, .. }
+            | ParsedPlayerFeedEventText::RetroactiveGreaterAugment { player_name, .. }
+            | ParsedPlayerFeedEventText::PlayerRelegated { player_name }
+            | ParsedPlayerFeedEventText::PlayerMoved { player_name, .. } => vec![player_name],
+            ParsedPlayerFeedEventText::TakeTheMound {
+                to_mound_player,
+                to_lineup_player,
+            } => vec![to_mound_player, to_lineup_player],
+            ParsedPlayerFeedEventText::TakeThePlate {
+                to_plate_player,
+                from_lineup_player,
+            } => vec![to_plate_player, from_lineup_player],
+            ParsedPlayerFeedEventText::SwapPlaces {
+                player_one,
+                player_two,
+            } => vec![player_one, player_two],
+            ParsedPlayerFeedEventText::Recomposed { previous, new } => vec![previous, new],
+            ParsedPlayerFeedEventText::Retirement { previous, new } => {
+                std::iter::once(previous).chain(new).collect()
+            }
+            ParsedPlayerFeedEventText::Party { party } => vec![&party.player_name],
+            ParsedPlayerFeedEventText::PlayerContained {
+                contained_player_name,
+                container_player_name,
+            } => vec![contained_player_name, container_player_name],
+            ParsedPlayerFeedEventText::PlayerPositionsSwapped { swap } => {
+                swap.player_names().to_vec()
+            }
+            ParsedPlayerFeedEventText::PlayerGrow { grow } => vec![grow.player_name()],
+        }
+    }
+}
+
+impl<S: AsRef<str>> MentionsPlayer for ParsedPlayerFeedEventText<S> {
+    fn mentions(&self, name: &str) -> bool {
+        self.player_names()
+            .into_iter()
+            .any(|player| player.as_ref() == name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashSet, fs::File};
+
+    use strum::IntoEnumIterator;
+
+    use crate::{
+        enums::{Attribute, FeedEventType, ItemName, ItemPrefix},
+        feed_event::EmojilessItem,
+        player_feed::{
+            parse_player_feed_event, ParsedPlayerFeedEventText,
+            ParsedPlayerFeedEventTextDiscriminants, PlayerFeed,
+        },
+        team_feed::{ParsedTeamFeedEventText, TeamFeed},
+        utils::{no_tracing_errs, raw_test_data},
+    };
+
+    /// Asserts that a parsed event survives serializing and deserializing, and returns its variant name.
+    fn assert_serde_round_trip<S: serde::Serialize>(
+        parsed: &ParsedPlayerFeedEventText<S>,
+    ) -> String {
+        let json = serde_json::to_value(parsed).unwrap();
+        let round_tripped: ParsedPlayerFeedEventText<String> = serde_json::from_value(json.clone())
+            .unwrap_or_else(|e| panic!("{e} deserializing {json}"));
+        assert_eq!(serde_json::to_value(&round_tripped).unwrap(), json);
+
+        let variant = match json {
+            serde_json::Value::Object(map) => map.keys().next().unwrap().clone(),
+            serde_json::Value::String(variant) => variant,
+            other => panic!("unexpected serialization {other}"),
+        };
+        assert_eq!(
+            ParsedPlayerFeedEventTextDiscriminants::from(parsed).to_string(),
+            variant
+        );
+        variant
+    }
+
+    #[test]
+    fn discriminants_are_unique() {
+        let names = ParsedPlayerFeedEventTextDiscriminants::iter()
+            .map(|discriminant| discriminant.to_string())
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            names.len(),
+            ParsedPlayerFeedEventTextDiscriminants::iter().count()
+        );
+        assert!(names.contains("Delivery"));
+        assert!(names.contains("Recomposed"));
+    }
+
+    #[test]
+    fn greater_augment_round_trip() {
+        let feeds = [
+            "6805db0cac48194de3cd4003",
+            "6805db0cac48194de3cd4011",
+            "6805db0cac48194de3cd4028",
+            "6805db0cac48194de3cd4086",
+            "6842fbaf8d67c531e89fe687",
+            "6845b71c54a7fbd413387a93",
+        ]
+        .map(|id| {
+            let f = File::open(format!("test_data/raw/player_feed/{id}.json")).unwrap();
+            serde_json::from_reader::<_, PlayerFeed>(f).unwrap()
+        });
+
+        let no_tracing_errs = no_tracing_errs();
+
+        let mut greater_augments = 0;
+        for event in feeds
+            .iter()
+            .flat_map(|feed| &feed.feed)
+            .filter(|event| event.event_type == Ok(FeedEventType::Election))
+            .filter(|event| event.text.contains(" gained ") || event.text.contains(" lost "))
+        {
+            let parsed = parse_player_feed_event(event);
+            assert!(matches!(
+                parsed,
+                ParsedPlayerFeedEventText::GreaterAugment { .. }
+                    | ParsedPlayerFeedEventText::RetractedGreaterAugment { .. }
+                    | ParsedPlayerFeedEventText::RetroactiveGreaterAugment { .. }
+            ));
+            assert_eq!(parsed.unparse(event), event.text);
+            greater_augments += 1;
+        }
+        assert_eq!(greater_augments, 10);
+
+        drop(no_tracing_errs);
+    }
+
+    #[test]
+    fn seasonal_durability_loss_round_trip() {
+        // A player with a Prolific Greater Boon, which resisted some seasons' durability loss
+        let f = File::open("test_data/raw/player_feed/684101e4295b2368c0ac756b.json").unwrap();
+        let feed: PlayerFeed = serde_json::from_reader(f).unwrap();
+
+        let (mut lost, mut resisted) = (0, 0);
+        for (parsed, event) in feed.parsed().zip(&feed.feed) {
+            if let ParsedPlayerFeedEventText::SeasonalDurabilityLoss {
+                durability_lost, ..
+            } = parsed
+            {
+                match durability_lost {
+                    Some(_) => lost += 1,
+                    None => resisted += 1,
+                }
+                assert_eq!(parsed.unparse(event), event.text);
+            }
+        }
+        assert_eq!(lost, 3);
+        assert_eq!(resisted, 3);
+    }
+
+    #[test]
+    fn parsed_round_trip() {
+        for id in ["6805db0cac48194de3cd4003", "684101e4295b2368c0ac756b"] {
+            let f = File::open(format!("test_data/raw/player_feed/{id}.json")).unwrap();
+            let feed: PlayerFeed = serde_json::from_reader(f).unwrap();
+
+            assert_eq!(feed.parsed().count(), feed.feed.len());
+            for (parsed, event) in feed.parsed().zip(&feed.feed) {
+                assert_eq!(parsed.unparse(event), event.text);
+            }
+        }
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let mut variants = HashSet::new();
+        for (_, feed) in raw_test_data::<PlayerFeed>("player_feed") {
+            for parsed in feed.parsed() {
+                variants.insert(assert_serde_round_trip(&parsed));
+            }
+        }
+
+        // No player feed in the test data has grown, but team feeds have
+        let f = File::open("test_data/raw/team_feed/6805f2d34277d0dcecdd3b2e.json").unwrap();
+        let team_feed: TeamFeed = serde_json::from_reader(f).unwrap();
+        let grow = team_feed
+            .parsed()
+            .find_map(|parsed| match parsed {
+                ParsedTeamFeedEventText::PlayerGrow { grow } => Some(grow),
+                _ => None,
+            })
+            .unwrap();
+
+        // Variants that don't appear in the test data
+        let item = EmojilessItem {
+            prefix: Some(ItemPrefix::Sharp),
+            item: ItemName::Cap,
+            suffix: None,
+        };
+        let samples = [
+            ParsedPlayerFeedEventText::AttributeEquals {
+                player_name: "Player",
+                changing_attribute: Attribute::Contact,
+                value_attribute: Attribute::Muscle,
+            },
+            ParsedPlayerFeedEventText::TakeTheMound {
+                to_mound_player: "Pitcher",
+                to_lineup_player: "Batter",
+            },
+            ParsedPlayerFeedEventText::TakeThePlate {
+                to_plate_player: "Batter",
+                from_lineup_player: "Pitcher",
+            },
+            ParsedPlayerFeedEventText::SwapPlaces {
+                player_one: "Player",
+                player_two: "Other Player",
+            },
+            ParsedPlayerFeedEventText::Enchantment {
+                player_name: "Player",
+                item,
+                amount: 5,
+                attribute: Attribute::Contact,
+                enchant_two: Some((3, Attribute::Muscle)),
+                compensatory: true,
+            },
+            ParsedPlayerFeedEventText::Released { team: "🦀 Crabs" },
+            ParsedPlayerFeedEventText::Retirement {
+                previous: "Old Player",
+                new: Some("New Player"),
+            },
+            ParsedPlayerFeedEventText::PlayerGrow { grow },
+            ParsedPlayerFeedEventText::PlayerRelegated {
+                player_name: "Player",
+            },
+        ];
+        for sample in &samples {
+            variants.insert(assert_serde_round_trip(sample));
+        }
+
+        // Every variant is covered
+        assert_eq!(variants.len(), 28);
+    }
+}
@@ -39,6 +39,57 @@ impl From<TeamPlayerCollection> for Vec<TeamPlayer> {
     }
 }
 
+impl TeamPlayerCollection {
+    /// Every player alongside their slot. For the map form the slot comes from the key.
+    fn slotted(&self) -> Box<dyn Iterator<Item = (Option<Slot>, &TeamPlayer)> + '_> {
+        match self {
+            TeamPlayerCollection::Vec(v) => Box::new(v.iter().map(|p| {
+                (
+                    p.slot.as_ref().ok().and_then(|s| s.as_ref().ok()).copied(),
+                    p,
+                )
+            })),
+            TeamPlayerCollection::Map(m) => {
+                Box::new(m.iter().map(|(k, p)| (k.parse::<Slot>().ok(), p)))
+            }
+        }
+    }
+
+    /// Position players in batting order, which is the order the API lists them in.
+    /// The designated hitter isn't moved anywhere special: it bats wherever it's listed,
+    /// which for every team seen so far is ninth, after the right fielder.
+    pub fn lineup(&self) -> impl Iterator<Item = &TeamPlayer> {
+        self.slotted()
+            .filter(|(slot, _)| {
+                slot.is_some_and(|slot| {
+                    !matches!(
+                        slot,
+                        Slot::StartingPitcher(_) | Slot::ReliefPitcher(_) | Slot::Closer
+                    )
+                })
+            })
+            .map(|(_, player)| player)
+    }
+
+    /// Pitchers in rotation order: starting pitchers by index, then relief pitchers by index, then the closer.
+    pub fn rotation(&self) -> impl Iterator<Item = &TeamPlayer> {
+        let mut pitchers = self
+            .slotted()
+            .filter_map(|(slot, player)| {
+                let key = match slot? {
+                    Slot::StartingPitcher(i) => (0, i),
+                    Slot::ReliefPitcher(i) => (1, i),
+                    Slot::Closer => (2, 0),
+                    _ => return None,
+                };
+                Some((key, player))
+            })
+            .collect::<Vec<_>>();
+        pitchers.sort_by_key(|(key, _)| *key);
+        pitchers.into_iter().map(|(_, player)| player)
+    }
+}
+
 impl From<Vec<TeamPlayer>> for TeamPlayerCollection {
     fn from(value: Vec<TeamPlayer>) -> Self {
         TeamPlayerCollection::Vec(value)
@@ -183,6 +234,13 @@ pub struct Team {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl Team {
+    /// The team's record of the given type, e.g. their regular season record.
+    pub fn record_for(&self, record_type: RecordType) -> Option<&TeamRecord> {
+        self.record.get(&Ok(record_type))
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug)]
 #[serde(rename_all = "PascalCase")]
 pub struct TeamRecord {
@@ -191,6 +249,20 @@ pub struct TeamRecord {
     pub wins: u16,
 }
 
+impl TeamRecord {
+    pub fn games_played(&self) -> u32 {
+        self.wins as u32 + self.losses as u32
+    }
+
+    /// The fraction of games won, between 0 and 1. 0 if no games have been played.
+    pub fn win_pct(&self) -> f64 {
+        match self.games_played() {
+            0 => 0.0,
+            games_played => self.wins as f64 / games_played as f64,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(from = "RawTeamPlayer", into = "RawTeamPlayer")]
 pub struct TeamPlayer {
@@ -216,10 +288,11 @@ pub struct TeamPlayer {
 
 #[cfg(test)]
 mod test {
-    use std::path::Path;
+    use std::{fs::File, path::Path};
 
     use crate::{
-        team::{Team, TeamPlayer},
+        enums::{RecordType, Slot},
+        team::{Team, TeamPlayer, TeamRecord},
         utils::{assert_round_trip, no_tracing_errs},
     };
 
@@ -233,4 +306,50 @@ mod test {
         drop(no_tracing_errs);
         Ok(())
     }
+
+    #[test]
+    fn record() -> Result<(), Box<dyn std::error::Error>> {
+        let team: Team = serde_json::from_reader(File::open("test_data/s2_team.json")?)?;
+
+        let record = team.record_for(RecordType::RegularSeason).unwrap();
+        assert_eq!(record.games_played(), 60);
+        assert_eq!(record.win_pct(), 28.0 / 60.0);
+        assert!(team.record_for(RecordType::Kumite).is_none());
+
+        let no_games = TeamRecord {
+            losses: 0,
+            run_differential: 0,
+            wins: 0,
+        };
+        assert_eq!(no_games.win_pct(), 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn lineup_and_rotation() -> Result<(), Box<dyn std::error::Error>> {
+        let team: Team = serde_json::from_reader(File::open("test_data/s2_team.json")?)?;
+        let slot = |p: &TeamPlayer| p.slot.clone().unwrap().unwrap();
+
+        let lineup = team.players.lineup().map(slot).collect::<Vec<_>>();
+        assert_eq!(lineup.len(), 9);
+        assert_eq!(lineup.first(), Some(&Slot::Catcher));
+        assert_eq!(lineup.last(), Some(&Slot::DesignatedHitter));
+
+        let rotation = team.players.rotation().map(slot).collect::<Vec<_>>();
+        assert_eq!(
+            rotation,
+            vec![
+                Slot::StartingPitcher(1),
+                Slot::StartingPitcher(2),
+                Slot::StartingPitcher(3),
+                Slot::StartingPitcher(4),
+                Slot::StartingPitcher(5),
+                Slot::ReliefPitcher(1),
+                Slot::ReliefPitcher(2),
+                Slot::ReliefPitcher(3),
+                Slot::Closer,
+            ]
+        );
+        Ok(())
+    }
 }
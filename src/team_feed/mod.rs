@@ -3,19 +3,23 @@ use std::fmt::Display;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
+use strum::{EnumDiscriminants, EnumIter};
 
 use crate::enums::Slot;
 use crate::feed_event::{AttributeChange, GreaterAugment};
 pub use crate::nom_parsing::parse_team_feed_event::parse_team_feed_event;
-use crate::nom_parsing::shared::{FeedEventDoorPrize, FeedEventParty, Grow, PositionSwap};
+use crate::nom_parsing::shared::{
+    unparse_feed_event_contained, FeedEventDoorPrize, FeedEventParty, Grow, PositionSwap,
+};
 use crate::parsed_event::{EmojiPlayer, EmojiTeam, GrowAttributeChange};
 use crate::{
     enums::{Attribute, FeedEventType, ModificationType},
     feed_event::{
-        EmojilessItem, FeedDelivery, FeedEvent, FeedEventParseError, FeedFallingStarOutcome,
+        self, EmojilessItem, FeedDelivery, FeedEvent, FeedEventParseError, FeedFallingStarOutcome,
     },
     time::{Breakpoints, Timestamp},
     utils::extra_fields_deserialize,
+    MentionsPlayer,
 };
 
 #[serde_as]
@@ -27,7 +31,25 @@ pub struct TeamFeed {
     pub extra_fields: serde_json::Map<String, serde_json::Value>,
 }
 
+impl TeamFeed {
+    /// Whether every event in the feed is at or after the event before it.
+    pub fn is_chronological(&self) -> bool {
+        feed_event::is_chronological(&self.feed)
+    }
+
+    /// Sorts the feed by timestamp. Events with the same timestamp keep their order.
+    pub fn sort_by_time(&mut self) {
+        feed_event::sort_by_time(&mut self.feed);
+    }
+
+    /// Lazily parses each event in the feed, in order.
+    pub fn parsed(&self) -> impl Iterator<Item = ParsedTeamFeedEventText<&str>> {
+        self.feed.iter().map(parse_team_feed_event)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum PurifiedOutcome {
     Payment(u32),
     PaymentAndImmunityRemoved(u32),
@@ -36,6 +58,15 @@ pub enum PurifiedOutcome {
 }
 
 impl PurifiedOutcome {
+    /// The tokens earned for being purified, if any.
+    pub fn payment(&self) -> Option<u32> {
+        match self {
+            PurifiedOutcome::Payment(payment)
+            | PurifiedOutcome::PaymentAndImmunityRemoved(payment) => Some(*payment),
+            PurifiedOutcome::NoCorruption | PurifiedOutcome::None => None,
+        }
+    }
+
     pub fn unparse<S: Display>(&self, player_name: S) -> String {
         match self {
             PurifiedOutcome::Payment(payment) => format!("{player_name} was Purified of 🫀 Corruption and earned {payment} 🪙."),
@@ -46,7 +77,9 @@ impl PurifiedOutcome {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, EnumDiscriminants)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[strum_discriminants(derive(strum::Display, Hash, EnumIter))]
 pub enum ParsedTeamFeedEventText<S> {
     ParseError {
         error: FeedEventParseError,
@@ -330,10 +363,7 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
                 format!("{swap}")
             },
             ParsedTeamFeedEventText::PlayerContained { contained_player_name, container_player_name } => {
-                format!(
-                    "{contained_player_name} was contained by {container_player_name} during the \
-                    🥀 Wither.",
-                )
+                unparse_feed_event_contained(contained_player_name, container_player_name)
             },
             ParsedTeamFeedEventText::PlayerGrow { grow } => {
                 format!("{grow}")
@@ -368,3 +398,166 @@ impl<S: Display> ParsedTeamFeedEventText<S> {
         }
     }
 }
+
+impl<S> ParsedTeamFeedEventText<S> {
+    /// Every player name in the event, in no particular order and possibly with repeats.
+    fn player_names(&self) -> Vec<&S> {
+        match self {
+            ParsedTeamFeedEventText::ParseError { .. }
+            | ParsedTeamFeedEventText::GameResult { .. }
+            | ParsedTeamFeedEventText::Prosperous { .. }
+            | ParsedTeamFeedEventText::DonatedToLottery { .. }
+            | ParsedTeamFeedEventText::WonLottery { .. }
+            | ParsedTeamFeedEventText::NameChanged
+            | ParsedTeamFeedEventText::GreaterAugment { .. }
+            | ParsedTeamFeedEventText::ClaimedLinealBelt { .. }
+            | ParsedTeamFeedEventText::LostLinealBelt { .. }
+            | ParsedTeamFeedEventText::Released { .. } => Vec::new(),
+            ParsedTeamFeedEventText::Delivery { delivery }
+            | ParsedTeamFeedEventText::Shipment { delivery }
+            | ParsedTeamFeedEventText::SpecialDelivery { delivery } => vec![&delivery.player],
+            ParsedTeamFeedEventText::PhotoContest { player, .. } => {
+                player.iter().map(|player| &player.name).collect()
+            }
+            ParsedTeamFeedEventText::Party { party } => vec![&party.player_name],
+            ParsedTeamFeedEventText::DoorPrize { prize } => prize.player_names().collect(),
+            // Despite the field names, these are the players who own the item and gain the modification.
+            ParsedTeamFeedEventText::Enchantment { team_name, .. }
+            | ParsedTeamFeedEventText::Modification { team_name, .. } => vec![team_name],
+            ParsedTeamFeedEventText::AttributeChanges { changes } => {
+                changes.iter().map(|change| &change.player_name).collect()
+            }
+            ParsedTeamFeedEventText::MassAttributeEquals { players, .. } => {
+                players.iter().map(|(_, player)| player).collect()
+            }
+            ParsedTeamFeedEventText::TakeTheMound {
+                to_mound_team,
+                to_lineup_team,
+            } => vec![to_mound_team, to_lineup_team],
+            ParsedTeamFeedEventText::TakeThePlate {
+                to_plate_team,
+                from_lineup_team,
+            } => vec![to_plate_team, from_lineup_team],
+            ParsedTeamFeedEventText::SwapPlaces { team_one, team_two } => vec![team_one, team_two],
+            ParsedTeamFeedEventText::Recomposed { previous, new } => vec![previous, new],
+            ParsedTeamFeedEventText::Retirement { previous, new } => {
+                std::iter::once(previous).chain(new).collect()
+            }
+            ParsedTeamFeedEventText::FallingStarOutcome { player_name, .. }
+            | ParsedTeamFeedEventText::CorruptedByWither { player_name }
+            | ParsedTeamFeedEventText::Purified { player_name, .. }
+            | ParsedTeamFeedEventText::PlayerMoved { player_name, .. }
+            | ParsedTeamFeedEventText::PlayerRelegated { player_name }
+            | ParsedTeamFeedEventText::PlayerGrewInEfflorescence { player_name, .. }
+            | ParsedTeamFeedEventText::PlayerEffloresce { player_name } => vec![player_name],
+            ParsedTeamFeedEventText::PlayerPositionsSwapped { swap } => {
+                swap.player_names().to_vec()
+            }
+            ParsedTeamFeedEventText::PlayerContained {
+                contained_player_name,
+                container_player_name,
+            } => vec![contained_player_name, container_player_name],
+            ParsedTeamFeedEventText::PlayerGrow { grow } => vec![grow.player_name()],
+            ParsedTeamFeedEventText::Callup {
+                promoted_player_name,
+                demoted_player_name,
+                ..
+            } => vec![promoted_player_name, demoted_player_name],
+        }
+    }
+}
+
+impl<S: AsRef<str>> MentionsPlayer for ParsedTeamFeedEventText<S> {
+    fn mentions(&self, name: &str) -> bool {
+        self.player_names()
+            .into_iter()
+            .any(|player| player.as_ref() == name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashSet, fs::File};
+
+    use strum::IntoEnumIterator;
+
+    use crate::{
+        team_feed::{ParsedTeamFeedEventText, ParsedTeamFeedEventTextDiscriminants, TeamFeed},
+        utils::raw_test_data,
+    };
+
+    /// Asserts that a parsed event survives serializing and deserializing, and returns its variant name.
+    fn assert_serde_round_trip<S: serde::Serialize>(parsed: &ParsedTeamFeedEventText<S>) -> String {
+        let json = serde_json::to_value(parsed).unwrap();
+        let round_tripped: ParsedTeamFeedEventText<String> = serde_json::from_value(json.clone())
+            .unwrap_or_else(|e| panic!("{e} deserializing {json}"));
+        assert_eq!(serde_json::to_value(&round_tripped).unwrap(), json);
+
+        let variant = match json {
+            serde_json::Value::Object(map) => map.keys().next().unwrap().clone(),
+            serde_json::Value::String(variant) => variant,
+            other => panic!("unexpected serialization {other}"),
+        };
+        assert_eq!(
+            ParsedTeamFeedEventTextDiscriminants::from(parsed).to_string(),
+            variant
+        );
+        variant
+    }
+
+    #[test]
+    fn discriminants_are_unique() {
+        let names = ParsedTeamFeedEventTextDiscriminants::iter()
+            .map(|discriminant| discriminant.to_string())
+            .collect::<HashSet<_>>();
+        assert_eq!(
+            names.len(),
+            ParsedTeamFeedEventTextDiscriminants::iter().count()
+        );
+        assert!(names.contains("Delivery"));
+        assert!(names.contains("Recomposed"));
+    }
+
+    #[test]
+    fn parsed_round_trip() {
+        for id in ["6805db0cac48194de3cd401d", "68506dce7f1bd7c6b71b605a"] {
+            let f = File::open(format!("test_data/raw/team_feed/{id}.json")).unwrap();
+            let feed: TeamFeed = serde_json::from_reader(f).unwrap();
+
+            assert_eq!(feed.parsed().count(), feed.feed.len());
+            for (parsed, event) in feed.parsed().zip(&feed.feed) {
+                assert_eq!(parsed.unparse(event), event.text);
+            }
+        }
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let mut variants = HashSet::new();
+        for (_, feed) in raw_test_data::<TeamFeed>("team_feed") {
+            for parsed in feed.parsed() {
+                variants.insert(assert_serde_round_trip(&parsed));
+            }
+        }
+
+        // Variants that don't appear in the test data
+        let samples = [
+            ParsedTeamFeedEventText::NameChanged,
+            ParsedTeamFeedEventText::Released { team: "🦀 Crabs" },
+            ParsedTeamFeedEventText::Retirement {
+                previous: "Old Player",
+                new: Some("New Player"),
+            },
+            ParsedTeamFeedEventText::Retirement {
+                previous: "Old Player",
+                new: None,
+            },
+        ];
+        for sample in &samples {
+            variants.insert(assert_serde_round_trip(sample));
+        }
+
+        // Every variant is covered
+        assert_eq!(variants.len(), 36);
+    }
+}
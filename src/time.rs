@@ -1,5 +1,7 @@
 use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use strum::{EnumIter, IntoEnumIterator};
 
 use crate::enums::Day;
 
@@ -7,7 +9,7 @@ use crate::enums::Day;
 pub struct Time {
     pub season: u32,
     /// Vec of (DayEquivalent, EventIndex), which is the first event after the breakpoint.
-    pub ascending_days: Vec<(DayEquivalent, u16)>,
+    pub ascending_days: &'static [(DayEquivalent, u16)],
 }
 
 impl Time {
@@ -23,7 +25,7 @@ impl Time {
                 None => false, // Assume unknown days are at end of season, and therefore after
                 Some(day) => {
                     // Because of overflow, transition happens on multiple days
-                    for (transition_day, transition_event_index) in &self.ascending_days {
+                    for (transition_day, transition_event_index) in self.ascending_days {
                         match day.cmp(transition_day) {
                             Ordering::Greater => (), // Move on to check the next day in the transition period
                             Ordering::Equal => match event_index.cmp(transition_event_index) {
@@ -130,20 +132,38 @@ impl Timestamp {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Points in time where mmolb changed something that affects parsing, e.g. the wording of a message.
+///
+/// Declared in chronological order, so a later breakpoint is always greater.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, EnumIter,
+)]
 pub enum Breakpoints {
+    /// Enchantments start with "The Item Enchantment was a success!".
     Season1EnchantmentChange,
+    /// Attributes "became equal to their current base" attribute.
     S1AttributeEqualChange,
+    /// A relief pitcher's position is sometimes missing from mound visits.
     S2D152,
+    /// Pitch messages no longer start with a space.
     S2D169,
+    /// The live now message includes the stadium, and attributes are "set to" another attribute.
     Season3,
+    /// Cheers start with a 📣.
     CheersGetEmoji,
+    /// Prosperity messages name the team with the highest income first.
     Season3PreSuperstarBreakUpdate,
+    /// Falling stars injure players "by the extreme force of the impact".
     EternalBattle,
+    /// Many messages are in the present tense.
     Season5TenseChange,
+    /// Wither messages are in the present tense.
     Season7WitherTenseChange,
+    /// Successful contains no longer have an extra period.
     Season7SuccessfulContainPeriodFix,
+    /// Discarded items are described with the newer message.
     Season8ItemDiscardedMessageChange,
+    /// The ➰ moved inside the lineal belt message.
     Season10,
 }
 impl From<Breakpoints> for Time {
@@ -152,17 +172,17 @@ impl From<Breakpoints> for Time {
     }
 }
 impl Breakpoints {
-    pub fn season(season: u32) -> Time {
+    pub(crate) fn season(season: u32) -> Time {
         Time {
             season,
-            ascending_days: vec![(DayEquivalent { day: 0, offset: 0 }, 0)],
+            ascending_days: &[(DayEquivalent { day: 0, offset: 0 }, 0)],
         }
     }
     fn ascending_transition_time(self) -> Time {
         match self {
             Breakpoints::Season1EnchantmentChange => Time {
                 season: 1,
-                ascending_days: vec![(
+                ascending_days: &[(
                     DayEquivalent {
                         day: 120,
                         offset: 255,
@@ -172,7 +192,7 @@ impl Breakpoints {
             },
             Breakpoints::S1AttributeEqualChange => Time {
                 season: 1,
-                ascending_days: vec![(
+                ascending_days: &[(
                     DayEquivalent {
                         day: 215,
                         offset: 0,
@@ -182,7 +202,7 @@ impl Breakpoints {
             },
             Breakpoints::S2D152 => Time {
                 season: 2,
-                ascending_days: vec![(
+                ascending_days: &[(
                     DayEquivalent {
                         day: 152,
                         offset: 0,
@@ -192,7 +212,7 @@ impl Breakpoints {
             },
             Breakpoints::S2D169 => Time {
                 season: 2,
-                ascending_days: vec![
+                ascending_days: &[
                     (
                         DayEquivalent {
                             day: 168,
@@ -211,15 +231,15 @@ impl Breakpoints {
             },
             Breakpoints::Season3 => Time {
                 season: 3,
-                ascending_days: vec![(DayEquivalent { day: 0, offset: 0 }, 0)],
+                ascending_days: &[(DayEquivalent { day: 0, offset: 0 }, 0)],
             },
             Breakpoints::CheersGetEmoji => Time {
                 season: 3,
-                ascending_days: vec![(DayEquivalent { day: 5, offset: 0 }, 330)],
+                ascending_days: &[(DayEquivalent { day: 5, offset: 0 }, 330)],
             },
             Breakpoints::Season3PreSuperstarBreakUpdate => Time {
                 season: 3,
-                ascending_days: vec![(
+                ascending_days: &[(
                     DayEquivalent {
                         day: 112,
                         offset: 0,
@@ -229,7 +249,7 @@ impl Breakpoints {
             },
             Breakpoints::EternalBattle => Time {
                 season: 2,
-                ascending_days: vec![(
+                ascending_days: &[(
                     DayEquivalent {
                         day: 255,
                         offset: 0,
@@ -239,7 +259,7 @@ impl Breakpoints {
             },
             Breakpoints::Season5TenseChange => Time {
                 season: 5,
-                ascending_days: vec![(
+                ascending_days: &[(
                     DayEquivalent {
                         day: 863,
                         offset: 0,
@@ -249,22 +269,45 @@ impl Breakpoints {
             },
             Breakpoints::Season7WitherTenseChange => Time {
                 season: 7,
-                ascending_days: vec![(DayEquivalent { day: 0, offset: 0 }, 0)],
+                ascending_days: &[(DayEquivalent { day: 0, offset: 0 }, 0)],
             },
             Breakpoints::Season7SuccessfulContainPeriodFix => Time {
                 season: 7,
-                ascending_days: vec![(DayEquivalent { day: 46, offset: 0 }, 24)],
+                ascending_days: &[(DayEquivalent { day: 46, offset: 0 }, 24)],
             },
             Breakpoints::Season8ItemDiscardedMessageChange => Time {
                 season: 8,
-                ascending_days: vec![(DayEquivalent { day: 0, offset: 0 }, 0)],
+                ascending_days: &[(DayEquivalent { day: 0, offset: 0 }, 0)],
             },
             Breakpoints::Season10 => Time {
                 season: 10,
-                ascending_days: vec![(DayEquivalent { day: 0, offset: 0 }, 0)],
+                ascending_days: &[(DayEquivalent { day: 0, offset: 0 }, 0)],
             },
         }
     }
+    /// Every breakpoint that the given time is at or after, in declaration order.
+    ///
+    /// ```
+    /// use mmolb_parsing::{enums::Day, Breakpoints};
+    ///
+    /// let active: Vec<_> = Breakpoints::active_at(2, Some(Day::Day(240)), Some(0)).collect();
+    /// assert_eq!(
+    ///     active,
+    ///     [
+    ///         Breakpoints::Season1EnchantmentChange,
+    ///         Breakpoints::S1AttributeEqualChange,
+    ///         Breakpoints::S2D152,
+    ///         Breakpoints::S2D169,
+    ///     ]
+    /// );
+    /// ```
+    pub fn active_at(
+        season: u32,
+        day: Option<Day>,
+        event_index: Option<u16>,
+    ) -> impl Iterator<Item = Breakpoints> {
+        Breakpoints::iter().filter(move |breakpoint| breakpoint.after(season, day, event_index))
+    }
     /// Is the time before the breakpoint
     pub fn before(&self, season: u32, day: Option<Day>, event_index: Option<u16>) -> bool {
         self.ascending_transition_time()
@@ -194,13 +194,84 @@ where
 }
 
 /// Couldn't parse this value, usually because it's a new mmolb feature we haven't handled yet.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Error)]
-#[serde(transparent)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(transparent))]
 #[error("failed to parse value: {}", .0)]
 pub struct NotRecognized(pub serde_json::Value);
 
+/// Transparent in human readable formats. Binary formats can't deserialize an arbitrary
+/// [`serde_json::Value`], so there it's stored as a JSON string instead.
+impl Serialize for NotRecognized {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            self.0.serialize(serializer)
+        } else {
+            serializer.serialize_str(&self.0.to_string())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NotRecognized {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            serde_json::Value::deserialize(deserializer).map(NotRecognized)
+        } else {
+            let raw = String::deserialize(deserializer)?;
+            serde_json::from_str(&raw)
+                .map(NotRecognized)
+                .map_err(D::Error::custom)
+        }
+    }
+}
+
+/// Unrecognized values in the api are almost always strings, so that's all this generates.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NotRecognized {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(NotRecognized(serde_json::Value::String(u.arbitrary()?)))
+    }
+}
+
 pub type MaybeRecognizedResult<T> = Result<T, NotRecognized>;
 
+/// Convenience methods for reading [`MaybeRecognizedResult`] fields.
+///
+/// ```
+/// use mmolb_parsing::{enums::EquipmentSlot, MaybeRecognizedExt, NotRecognized};
+///
+/// let slot: Result<EquipmentSlot, NotRecognized> = Ok(EquipmentSlot::Head);
+/// assert_eq!(slot.recognized(), Some(&EquipmentSlot::Head));
+/// assert_eq!(slot.raw_value(), None);
+///
+/// let slot: Result<EquipmentSlot, NotRecognized> = Err(NotRecognized("Tail".into()));
+/// assert_eq!(slot.recognized(), None);
+/// assert_eq!(slot.recognized_or(&EquipmentSlot::Body), &EquipmentSlot::Body);
+/// assert_eq!(slot.raw_value(), Some(&serde_json::Value::from("Tail")));
+/// ```
+pub trait MaybeRecognizedExt<T> {
+    /// The value, if it was recognized.
+    fn recognized(&self) -> Option<&T>;
+    /// The value, or `default` if it wasn't recognized.
+    fn recognized_or<'a>(&'a self, default: &'a T) -> &'a T;
+    /// The raw json of the value, if it wasn't recognized.
+    fn raw_value(&self) -> Option<&serde_json::Value>;
+}
+
+impl<T> MaybeRecognizedExt<T> for MaybeRecognizedResult<T> {
+    fn recognized(&self) -> Option<&T> {
+        self.as_ref().ok()
+    }
+
+    fn recognized_or<'a>(&'a self, default: &'a T) -> &'a T {
+        self.as_ref().unwrap_or(default)
+    }
+
+    fn raw_value(&self) -> Option<&serde_json::Value> {
+        self.as_ref().err().map(|NotRecognized(value)| value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct MaybeRecognizedHelper<T>(PhantomData<T>);
 
@@ -305,7 +376,7 @@ impl SerializeAs<u8> for StarHelper {
 }
 
 pub(crate) struct TimestampHelper;
-const FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.6f+00:00";
+pub(crate) const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.6f+00:00";
 
 impl<'de> DeserializeAs<'de, DateTime<Utc>> for TimestampHelper {
     fn deserialize_as<D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
@@ -313,7 +384,8 @@ impl<'de> DeserializeAs<'de, DateTime<Utc>> for TimestampHelper {
         D: Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        let dt = NaiveDateTime::parse_from_str(&s, FORMAT).map_err(serde::de::Error::custom)?;
+        let dt = NaiveDateTime::parse_from_str(&s, TIMESTAMP_FORMAT)
+            .map_err(serde::de::Error::custom)?;
         Ok(DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
     }
 }
@@ -323,7 +395,7 @@ impl SerializeAs<DateTime<Utc>> for TimestampHelper {
     where
         S: Serializer,
     {
-        let s = format!("{}", date.format(FORMAT));
+        let s = format!("{}", date.format(TIMESTAMP_FORMAT));
         serializer.serialize_str(&s)
     }
 }
@@ -509,4 +581,23 @@ mod test_utils {
         assert!(diff.is_none(), "{diff:?}");
         Ok(())
     }
+
+    /// Everything in a `test_data/raw` directory (e.g. "game" or "player_feed"), keyed by the id in its file name.
+    pub(crate) fn raw_test_data<T: DeserializeOwned>(kind: &str) -> Vec<(String, T)> {
+        let mut paths = std::fs::read_dir(Path::new("test_data/raw").join(kind))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| {
+                let id = path.file_stem().unwrap().to_str().unwrap().to_string();
+                let value = serde_json::from_reader(File::open(&path).unwrap())
+                    .unwrap_or_else(|e| panic!("{e} reading {}", path.display()));
+                (id, value)
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,69 @@
+//! Lives in its own test binary so the counting allocator doesn't replace the allocator for the lib tests.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use mmolb_parsing::{
+    enums::Place,
+    parsed_event::{
+        Ejection, EjectionReason, EjectionReplacement, EmojiTeam, PlacedPlayer, ViolationType,
+    },
+};
+
+/// Counts allocations made on the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn ejection(violation_type: ViolationType, reason: EjectionReason) -> Ejection<String> {
+    Ejection::Ejection {
+        team: EmojiTeam {
+            emoji: "🧹".to_string(),
+            name: "Boston Street Sweepers".to_string(),
+        },
+        ejected_player: PlacedPlayer {
+            name: "Geo Kerr".to_string(),
+            place: Place::FirstBaseman,
+        },
+        violation_type,
+        reason,
+        replacement: EjectionReplacement::BenchPlayer {
+            player_name: "Kaj Statter Jr.".to_string(),
+        },
+    }
+}
+
+#[test]
+fn ejection_as_ref_does_not_allocate() {
+    for ejection in [
+        ejection(ViolationType::Sportsmanship, EjectionReason::EatingAHotdog),
+        ejection(
+            ViolationType::Unknown("Fashion".to_string()),
+            EjectionReason::Unknown("wearing socks with sandals".to_string()),
+        ),
+    ] {
+        let before = ALLOCATIONS.with(Cell::get);
+        let borrowed = ejection.as_ref();
+        assert_eq!(ALLOCATIONS.with(Cell::get), before);
+
+        assert_eq!(borrowed.unparse(), ejection.unparse());
+    }
+}